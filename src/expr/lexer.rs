@@ -2,6 +2,8 @@ use std::ops::Range;
 
 use regex::Regex;
 
+use crate::stack::unit::Unit;
+
 use super::{
     reader::{InputReader, ReaderResult},
     ExprError, ExprResult,
@@ -13,11 +15,23 @@ pub enum ExprTokenType {
     EndOfInput,
     DecimalNumber,
     HexNumber,
+    RadixNumber,
+    Date,
+    Time,
+    Duration,
+    Dms,
+    Hms,
+    Quantity,
+    ImaginaryNumber,
     Operator,
     Identifier,
     String,
     LeftParen,
     RightParen,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
     Comma,
 }
 
@@ -43,9 +57,30 @@ impl ExprLexer {
             &mut tokens,
             &LexStrState {
                 hex_re: Regex::new(r"^0x([0-9a-fA-F]+)").unwrap(),
+                // 0b1010, 0o17 and any radix from 2 to 36 as in 36r1z
+                radix_re: Regex::new(r"^(0b[01]+|0o[0-7]+|[0-9]+r[0-9a-zA-Z]+)\b").unwrap(),
+                date_re: Regex::new(
+                    r"^[0-9]{4}-[0-9]{2}-[0-9]{2}(T[0-9]{2}:[0-9]{2}(:[0-9]{2}(\.[0-9]+)?)?)?\b",
+                )
+                .unwrap(),
+                hms_re: Regex::new(r"^[0-9]+:[0-9]{2}:[0-9]{2}(\.[0-9]+)?\b").unwrap(),
+                dms_re: Regex::new(
+                    r#"^[0-9]+(\.[0-9]+)?°([0-9]+(\.[0-9]+)?')?([0-9]+(\.[0-9]+)?")?"#,
+                )
+                .unwrap(),
+                time_re: Regex::new(r"^[0-9]{1,2}:[0-9]{2}\b").unwrap(),
+                // durations come before quantities, so 3m is 3 minutes; write 3 m for metres
+                duration_re: Regex::new(r"^([0-9]+(y|mo|w|d|h|m)|[0-9]+(\.[0-9]+)?s)+\b").unwrap(),
+                quantity_re: Regex::new(
+                    r"^([0-9]+)(\.[0-9]*)?[ \t]+[a-zA-ZµΩ]+(\^-?[0-9]+)?([*/][a-zA-ZµΩ]+(\^-?[0-9]+)?)*",
+                )
+                .unwrap(),
+                imaginary_re: Regex::new(r"^([0-9]+)(\.[0-9]*)?i\b").unwrap(),
                 decimal_re: Regex::new(r"^([0-9]+)(\.[0-9]*)?").unwrap(),
+                // conversions and operators named like ->dms, dms-> and dms+
+                function_re: Regex::new(r"^(->[a-zA-Z]+\b|[a-zA-Z]+->|(dms|hms)\+)").unwrap(),
                 identifier_re: Regex::new(r"^[a-zA-Z][a-zA-Z0-9_]*").unwrap(),
-                char_re: Regex::new(r"^[+-/*%()^]").unwrap(),
+                char_re: Regex::new(r"^(<<|>>|<=|>=|==|!=|[+-/*%()\[\]{}^∠&|~!<>])").unwrap(),
             },
         )?;
         Ok(Self { source, tokens })
@@ -112,7 +147,16 @@ impl ExprLexer {
 
 struct LexStrState {
     hex_re: Regex,
+    radix_re: Regex,
+    date_re: Regex,
+    hms_re: Regex,
+    dms_re: Regex,
+    time_re: Regex,
+    duration_re: Regex,
+    quantity_re: Regex,
+    imaginary_re: Regex,
     decimal_re: Regex,
+    function_re: Regex,
     identifier_re: Regex,
     char_re: Regex,
 }
@@ -137,8 +181,26 @@ fn lex_str(
             });
         } else if let Some(captures) = reader.try_take_re(&state.hex_re) {
             lex_hex_number(&captures, tokens)?;
+        } else if let Some(captures) = reader.try_take_re(&state.radix_re) {
+            lex_token(ExprTokenType::RadixNumber, &captures, tokens)?;
+        } else if let Some(captures) = reader.try_take_re(&state.date_re) {
+            lex_token(ExprTokenType::Date, &captures, tokens)?;
+        } else if let Some(captures) = reader.try_take_re(&state.hms_re) {
+            lex_token(ExprTokenType::Hms, &captures, tokens)?;
+        } else if let Some(captures) = reader.try_take_re(&state.dms_re) {
+            lex_token(ExprTokenType::Dms, &captures, tokens)?;
+        } else if let Some(captures) = reader.try_take_re(&state.time_re) {
+            lex_token(ExprTokenType::Time, &captures, tokens)?;
+        } else if let Some(captures) = reader.try_take_re(&state.duration_re) {
+            lex_token(ExprTokenType::Duration, &captures, tokens)?;
+        } else if let Some(captures) = reader.try_take_re_if(&state.quantity_re, is_quantity) {
+            lex_quantity(&captures, tokens)?;
+        } else if let Some(captures) = reader.try_take_re(&state.imaginary_re) {
+            lex_imaginary_number(&captures, tokens)?;
         } else if let Some(captures) = reader.try_take_re(&state.decimal_re) {
             lex_decimal_number(&captures, tokens)?;
+        } else if let Some(captures) = reader.try_take_re(&state.function_re) {
+            lex_identifier(&captures, tokens)?;
         } else if let Some(captures) = reader.try_take_re(&state.identifier_re) {
             lex_identifier(&captures, tokens)?;
         } else if let Some(captures) = reader.try_take_re(&state.char_re) {
@@ -186,6 +248,14 @@ fn lex_char(re_result: &ReaderResult, tokens: &mut Vec<ExprToken>) -> ExprResult
         ExprTokenType::LeftParen
     } else if re_result.text == ")" {
         ExprTokenType::RightParen
+    } else if re_result.text == "[" {
+        ExprTokenType::LeftBracket
+    } else if re_result.text == "]" {
+        ExprTokenType::RightBracket
+    } else if re_result.text == "{" {
+        ExprTokenType::LeftBrace
+    } else if re_result.text == "}" {
+        ExprTokenType::RightBrace
     } else {
         ExprTokenType::Operator
     };
@@ -206,6 +276,44 @@ fn lex_hex_number(re_result: &ReaderResult, tokens: &mut Vec<ExprToken>) -> Expr
     Ok(())
 }
 
+fn lex_token(
+    token_type: ExprTokenType,
+    re_result: &ReaderResult,
+    tokens: &mut Vec<ExprToken>,
+) -> ExprResult<()> {
+    tokens.push(ExprToken {
+        token_type,
+        location: re_result.location.clone(),
+        text: re_result.text.to_string(),
+    });
+    Ok(())
+}
+
+/// A number followed by a word is only a quantity when the word is a unit, so `{2 pi}`
+/// is a number and a constant.
+fn is_quantity(s: &str) -> bool {
+    s.split_once([' ', '\t'])
+        .is_some_and(|(_, unit)| Unit::parse(unit).is_ok())
+}
+
+fn lex_quantity(re_result: &ReaderResult, tokens: &mut Vec<ExprToken>) -> ExprResult<()> {
+    tokens.push(ExprToken {
+        token_type: ExprTokenType::Quantity,
+        location: re_result.location.clone(),
+        text: re_result.text.to_string(),
+    });
+    Ok(())
+}
+
+fn lex_imaginary_number(re_result: &ReaderResult, tokens: &mut Vec<ExprToken>) -> ExprResult<()> {
+    tokens.push(ExprToken {
+        token_type: ExprTokenType::ImaginaryNumber,
+        location: re_result.location.clone(),
+        text: re_result.text.to_string(),
+    });
+    Ok(())
+}
+
 fn lex_decimal_number(re_result: &ReaderResult, tokens: &mut Vec<ExprToken>) -> ExprResult<()> {
     tokens.push(ExprToken {
         token_type: ExprTokenType::DecimalNumber,
@@ -17,6 +17,8 @@ pub enum Expr {
     StackItem(StackItem),
     Identifier(String),
     FunctionCall(String, Vec<Expr>),
+    Vector(Vec<Expr>),
+    List(Vec<Expr>),
     UnaryOp {
         op: String,
         rhs: Box<Expr>,
@@ -90,7 +92,40 @@ mod test {
 
     #[test]
     pub fn test_parse_hex() {
-        test_expr!("-0x1f2e", StackItem::Number(-0x1f2e as f64, 16));
+        test_expr!("-0x1f2e", StackItem::Integer((-0x1f2e).into(), 16));
+    }
+
+    #[test]
+    pub fn test_parse_radix() {
+        test_expr!("0b1010", StackItem::Integer(10.into(), 2));
+        test_expr!("0o17", StackItem::Integer(15.into(), 8));
+        test_expr!("36r1z", StackItem::Integer(71.into(), 36));
+        test_expr!("-3r21", StackItem::Integer((-7).into(), 3));
+    }
+
+    #[test]
+    pub fn test_parse_radix_invalid() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("37r1").expect_err("radix too large");
+        state.push_str("1r0").expect_err("radix too small");
+        state.push_str("8r9").expect_err("digit out of range");
+    }
+
+    #[test]
+    pub fn test_parse_hex_wider_than_f64() {
+        test_expr!(
+            "0xffffffffffffffff + 1",
+            StackItem::Integer(num_bigint::BigInt::from(1) << 64, 10)
+        );
+    }
+
+    #[test]
+    pub fn test_parse_integer() {
+        test_expr!(
+            "9007199254740993",
+            StackItem::Integer(9007199254740993u64.into(), 10)
+        );
+        test_expr!("42.", StackItem::Number(42.0, 10));
     }
 
     #[test]
@@ -105,19 +140,19 @@ mod test {
 
     #[test]
     pub fn test_parse_simple_expr() {
-        test_expr!("1+2", StackItem::Number(1.0 + 2.0, 10));
+        test_expr!("1+2", StackItem::Integer((1 + 2).into(), 10));
     }
 
     #[test]
     pub fn test_order_of_operation() {
-        test_expr!("2 + 3 * 4", StackItem::Number(2.0 + 3.0 * 4.0, 10));
-        test_expr!("3 * 4 + 2", StackItem::Number(3.0 * 4.0 + 2.0, 10));
-        test_expr!("(2 + 3) * 4", StackItem::Number((2.0 + 3.0) * 4.0, 10))
+        test_expr!("2 + 3 * 4", StackItem::Integer((2 + 3 * 4).into(), 10));
+        test_expr!("3 * 4 + 2", StackItem::Integer((3 * 4 + 2).into(), 10));
+        test_expr!("(2 + 3) * 4", StackItem::Integer(((2 + 3) * 4).into(), 10))
     }
 
     #[test]
     pub fn test_unary_expr() {
-        test_expr!("+30", StackItem::Number(30.0, 10));
-        test_expr!("-30", StackItem::Number(-30.0, 10));
+        test_expr!("+30", StackItem::Integer(30.into(), 10));
+        test_expr!("-30", StackItem::Integer((-30).into(), 10));
     }
 }
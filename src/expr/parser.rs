@@ -1,4 +1,15 @@
-use crate::stack::item::StackItem;
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+use num_complex::Complex64;
+
+use crate::stack::{
+    datetime::{parse_date_time, CalendarDuration},
+    item::StackItem,
+    sexagesimal,
+    unit::Unit,
+};
 
 use super::{
     lexer::{ExprLexer, ExprToken, ExprTokenType},
@@ -8,17 +19,42 @@ use crate::parse_binary_expression;
 
 pub(super) fn parse_expression_from_tokenizer(mut tokenizer: ExprLexer) -> ExprResult<Expr> {
     tokenizer.skip_start_of_input()?;
-    let expr = parse_additive(&mut tokenizer)?;
+    let expr = parse_bitwise_or(&mut tokenizer)?;
     tokenizer.skip_end_of_input()?;
     Ok(expr)
 }
 
+// C-like precedence, lowest first; "^" is exponentiation so xor is only available as a function
+fn parse_bitwise_or(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
+    parse_binary_expression!(tokenizer, ["|"], parse_bitwise_and)
+}
+
+fn parse_bitwise_and(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
+    parse_binary_expression!(tokenizer, ["&"], parse_equality)
+}
+
+fn parse_equality(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
+    parse_binary_expression!(tokenizer, ["==", "!="], parse_relational)
+}
+
+fn parse_relational(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
+    parse_binary_expression!(tokenizer, ["<", "<=", ">", ">="], parse_shift)
+}
+
+fn parse_shift(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
+    parse_binary_expression!(tokenizer, ["<<", ">>"], parse_additive)
+}
+
 fn parse_additive(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
     parse_binary_expression!(tokenizer, ["+", "-"], parse_multiplicative)
 }
 
 fn parse_multiplicative(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
-    parse_binary_expression!(tokenizer, ["*", "/", "%"], parse_exponentiation)
+    parse_binary_expression!(tokenizer, ["*", "/", "%"], parse_polar)
+}
+
+fn parse_polar(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
+    parse_binary_expression!(tokenizer, ["∠"], parse_exponentiation)
 }
 
 fn parse_exponentiation(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
@@ -64,11 +100,33 @@ fn parse_unary(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
                     op: "neg".to_string(),
                     rhs: Box::new(rhs),
                 });
+            } else if t.text == "~" {
+                tokenizer.take(); // skip
+                let rhs = parse_unary(tokenizer)?;
+                return Ok(Expr::UnaryOp {
+                    op: "not".to_string(),
+                    rhs: Box::new(rhs),
+                });
             }
         }
     }
 
-    parse_function_invocation(tokenizer)
+    parse_postfix(tokenizer)
+}
+
+fn parse_postfix(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
+    let mut expr = parse_function_invocation(tokenizer)?;
+    while tokenizer
+        .peek(0)
+        .is_some_and(|t| t.token_type == ExprTokenType::Operator && t.text == "!")
+    {
+        tokenizer.take_token(ExprTokenType::Operator)?;
+        expr = Expr::UnaryOp {
+            op: "fact".to_string(),
+            rhs: Box::new(expr),
+        };
+    }
+    Ok(expr)
 }
 
 fn parse_function_invocation(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
@@ -88,7 +146,7 @@ fn parse_function_invocation(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
             if !first {
                 tokenizer.take_token(ExprTokenType::Comma)?;
             }
-            args.push(parse_additive(tokenizer)?);
+            args.push(parse_bitwise_or(tokenizer)?);
             first = false;
         }
         tokenizer.take_token(ExprTokenType::RightParen)?;
@@ -101,19 +159,90 @@ fn parse_function_invocation(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
 fn parse_primary_paren(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
     if tokenizer.len() >= 1 && *tokenizer.peek_token_type(0).unwrap() == ExprTokenType::LeftParen {
         tokenizer.take_token(ExprTokenType::LeftParen)?;
-        let result = parse_additive(tokenizer)?;
+        let result = parse_bitwise_or(tokenizer)?;
         tokenizer.take_token(ExprTokenType::RightParen)?;
         Ok(result)
+    } else if tokenizer.is_next_token(ExprTokenType::LeftBracket) {
+        parse_vector(tokenizer)
+    } else if tokenizer.is_next_token(ExprTokenType::LeftBrace) {
+        parse_list(tokenizer)
     } else {
         parse_primary(tokenizer)
     }
 }
 
+fn parse_vector(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
+    let start = tokenizer.take_token(ExprTokenType::LeftBracket)?;
+    let mut elements = vec![];
+    while !tokenizer.is_next_token(ExprTokenType::RightBracket) {
+        if !elements.is_empty() {
+            tokenizer.take_token(ExprTokenType::Comma)?;
+        }
+        elements.push(parse_bitwise_or(tokenizer)?);
+    }
+    let end = tokenizer.take_token(ExprTokenType::RightBracket)?;
+    if elements.is_empty() {
+        return Err(ExprError::new(
+            tokenizer.get_source(),
+            Some(start.location.start..end.location.end),
+            "vectors must have at least one element",
+        ));
+    }
+    Ok(Expr::Vector(elements))
+}
+
+// elements are separated either by commas or by whitespace; whitespace separated elements
+// are unary operands, so "{1 -2}" is two elements and sums need parentheses, "{(1 - 2) 3}"
+fn parse_list(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
+    tokenizer.take_token(ExprTokenType::LeftBrace)?;
+    let comma_separated = list_has_comma(tokenizer);
+    let mut elements = vec![];
+    while !tokenizer.is_next_token(ExprTokenType::RightBrace) {
+        if comma_separated {
+            if !elements.is_empty() {
+                tokenizer.take_token(ExprTokenType::Comma)?;
+            }
+            elements.push(parse_bitwise_or(tokenizer)?);
+        } else {
+            elements.push(parse_unary(tokenizer)?);
+        }
+    }
+    tokenizer.take_token(ExprTokenType::RightBrace)?;
+    Ok(Expr::List(elements))
+}
+
+/// Looks ahead to the end of the list being parsed for a comma outside any nested group.
+fn list_has_comma(tokenizer: &ExprLexer) -> bool {
+    let mut depth = 0;
+    for n in 0..tokenizer.len() {
+        match tokenizer.peek_token_type(n) {
+            Some(
+                ExprTokenType::LeftParen | ExprTokenType::LeftBracket | ExprTokenType::LeftBrace,
+            ) => depth += 1,
+            Some(ExprTokenType::RightBrace) if depth == 0 => return false,
+            Some(
+                ExprTokenType::RightParen | ExprTokenType::RightBracket | ExprTokenType::RightBrace,
+            ) => depth -= 1,
+            Some(ExprTokenType::Comma) if depth == 0 => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
 fn parse_primary(tokenizer: &mut ExprLexer) -> ExprResult<Expr> {
     if let Some(t) = tokenizer.take() {
         match t.token_type {
             ExprTokenType::DecimalNumber => parse_decimal_number(tokenizer, t),
             ExprTokenType::HexNumber => parse_hex_number(tokenizer, t),
+            ExprTokenType::RadixNumber => parse_radix_number(tokenizer, t),
+            ExprTokenType::ImaginaryNumber => parse_imaginary_number(tokenizer, t),
+            ExprTokenType::Quantity => parse_quantity(tokenizer, t),
+            ExprTokenType::Date => parse_date(tokenizer, t),
+            ExprTokenType::Time => parse_time(tokenizer, t),
+            ExprTokenType::Duration => parse_duration(tokenizer, t),
+            ExprTokenType::Dms => parse_dms(tokenizer, t),
+            ExprTokenType::Hms => parse_hms(tokenizer, t),
             ExprTokenType::Identifier => Ok(Expr::Identifier(t.text)),
             ExprTokenType::String => parse_string(tokenizer, t),
             _ => Err(ExprError::new(
@@ -143,12 +272,40 @@ fn parse_hex_number(tokenizer: &mut ExprLexer, t: ExprToken) -> ExprResult<Expr>
 
     let s = s.trim_start_matches("0x");
 
-    match i128::from_str_radix(s, 16) {
-        Ok(v) => Ok(Expr::StackItem(StackItem::Number((neg * v) as f64, 16))),
-        Err(e) => Err(ExprError::new(
+    match BigInt::parse_bytes(s.as_bytes(), 16) {
+        Some(v) => Ok(Expr::StackItem(StackItem::Integer(neg * v, 16))),
+        None => Err(ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            "parse hexadecimal; invalid digit found in string",
+        )),
+    }
+}
+
+fn parse_radix_number(tokenizer: &mut ExprLexer, t: ExprToken) -> ExprResult<Expr> {
+    let s = t.text.as_str();
+    let (radix, digits) = if let Some(digits) = s.strip_prefix("0b") {
+        (Some(2), digits)
+    } else if let Some(digits) = s.strip_prefix("0o") {
+        (Some(8), digits)
+    } else {
+        let (radix, digits) = s.split_once('r').unwrap_or((s, ""));
+        (radix.parse::<u8>().ok(), digits)
+    };
+
+    let Some(radix) = radix.filter(|radix| (2..=36).contains(radix)) else {
+        return Err(ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            "parse radix; radix must be from 2 to 36",
+        ));
+    };
+    match BigInt::parse_bytes(digits.as_bytes(), radix as u32) {
+        Some(v) => Ok(Expr::StackItem(StackItem::Integer(v, radix))),
+        None => Err(ExprError::new(
             tokenizer.get_source(),
             Some(t.location.clone()),
-            &format!("parse hexadecimal; error = {e}"),
+            &format!("parse radix {radix}; invalid digit found in string"),
         )),
     }
 }
@@ -192,13 +349,109 @@ fn parse_string(tokenizer: &mut ExprLexer, t: ExprToken) -> ExprResult<Expr> {
     Ok(Expr::StackItem(StackItem::String(value)))
 }
 
+fn parse_date(tokenizer: &ExprLexer, t: ExprToken) -> ExprResult<Expr> {
+    let v = parse_date_time(&t.text).map_err(|e| {
+        ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            &format!("parse date; error = {e}"),
+        )
+    })?;
+    Ok(Expr::StackItem(StackItem::DateTime(v)))
+}
+
+fn parse_time(tokenizer: &ExprLexer, t: ExprToken) -> ExprResult<Expr> {
+    let v = CalendarDuration::parse_time_of_day(&t.text).map_err(|e| {
+        ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            &format!("parse time; error = {e}"),
+        )
+    })?;
+    Ok(Expr::StackItem(StackItem::Duration(v)))
+}
+
+fn parse_duration(tokenizer: &ExprLexer, t: ExprToken) -> ExprResult<Expr> {
+    let v = CalendarDuration::parse(&t.text).map_err(|e| {
+        ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            &format!("parse duration; error = {e}"),
+        )
+    })?;
+    Ok(Expr::StackItem(StackItem::Duration(v)))
+}
+
+fn parse_dms(tokenizer: &ExprLexer, t: ExprToken) -> ExprResult<Expr> {
+    let v = sexagesimal::parse_dms(&t.text).map_err(|e| {
+        ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            &format!("parse degrees; error = {e}"),
+        )
+    })?;
+    Ok(Expr::StackItem(StackItem::Dms(v)))
+}
+
+fn parse_hms(tokenizer: &ExprLexer, t: ExprToken) -> ExprResult<Expr> {
+    let v = sexagesimal::parse_hms(&t.text).map_err(|e| {
+        ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            &format!("parse hours; error = {e}"),
+        )
+    })?;
+    Ok(Expr::StackItem(StackItem::Hms(v)))
+}
+
+fn parse_quantity(tokenizer: &ExprLexer, t: ExprToken) -> ExprResult<Expr> {
+    let (value, unit) = t.text.split_once([' ', '\t']).unwrap_or((&t.text, ""));
+    let v = value.parse::<f64>().map_err(|e| {
+        ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            &format!("parse quantity; error = {e}"),
+        )
+    })?;
+    let unit = Unit::parse(unit).map_err(|e| {
+        ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            &format!("parse quantity; error = {e}"),
+        )
+    })?;
+    Ok(Expr::StackItem(StackItem::from_quantity(v, unit)))
+}
+
+fn parse_imaginary_number(tokenizer: &ExprLexer, t: ExprToken) -> ExprResult<Expr> {
+    let v = t.text.trim_end_matches('i').parse::<f64>().map_err(|e| {
+        ExprError::new(
+            tokenizer.get_source(),
+            Some(t.location.clone()),
+            &format!("parse imaginary; error = {e}"),
+        )
+    })?;
+    Ok(Expr::StackItem(StackItem::from_complex(Complex64::new(
+        0.0, v,
+    ))))
+}
+
 fn parse_decimal_number(tokenizer: &ExprLexer, t: ExprToken) -> ExprResult<Expr> {
-    let v = t.text.parse::<f64>().map_err(|e| {
+    // literals without a decimal point are exact, "1." or "1.0" is floating point
+    if !t.text.contains('.') {
+        if let Some(v) = BigInt::parse_bytes(t.text.as_bytes(), 10) {
+            return Ok(Expr::StackItem(StackItem::Integer(v, 10)));
+        }
+    }
+
+    // kept as an exact decimal so decimal mode sees every digit typed, floating point mode
+    // converts it when it is pushed
+    let v = BigDecimal::from_str(t.text.trim_end_matches('.')).map_err(|e| {
         ExprError::new(
             tokenizer.get_source(),
             Some(t.location.clone()),
             &format!("parse decimal; error = {e}"),
         )
     })?;
-    Ok(Expr::StackItem(StackItem::Number(v, 10)))
+    Ok(Expr::StackItem(StackItem::Decimal(v)))
 }
@@ -37,7 +37,18 @@ impl<'a> InputReader<'a> {
     }
 
     pub fn try_take_re(&mut self, re: &regex::Regex) -> Option<ReaderResult> {
-        if let Some(captures) = re.captures(self.s) {
+        self.try_take_re_if(re, |_| true)
+    }
+
+    /// Like `try_take_re`, but only takes the match when `accept` allows its text.
+    pub fn try_take_re_if<F>(&mut self, re: &regex::Regex, accept: F) -> Option<ReaderResult<'_>>
+    where
+        F: FnOnce(&str) -> bool,
+    {
+        if let Some(captures) = re
+            .captures(self.s)
+            .filter(|c| accept(c.get(0).unwrap().as_str()))
+        {
             let len = captures.get(0).unwrap().len();
             let location = self.offset..self.offset + len;
             self.s = &self.s[len..];
@@ -70,6 +81,7 @@ impl<'a> InputReader<'a> {
                 self.s = &self.s[1..];
                 self.offset += 1;
                 let location = start..self.offset;
+                self.skip_whitespace();
                 return Ok(Some(ReaderResult {
                     location: location.clone(),
                     text: &self.source[location.clone()],
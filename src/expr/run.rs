@@ -1,9 +1,11 @@
 use anyhow::{anyhow, Context, Result};
 
 use crate::{
-    stack::item::StackItem,
+    stack::{item::StackItem, matrix, Stack},
     state::RpnState,
-    undo_action::{multi::MultiUndoEvent, push::PushUndoEvent, UndoEvent},
+    undo_action::{
+        multi::MultiUndoEvent, push::PushUndoEvent, replace::ReplaceUndoEvent, UndoEvent,
+    },
 };
 
 use super::{lexer::ExprLexer, parser::parse_expression_from_tokenizer, Expr};
@@ -36,17 +38,121 @@ pub fn run_expression(s: &str, state: &mut RpnState) -> Result<()> {
 fn run_expr(expr: &Expr, state: &mut RpnState, undos: &mut Vec<Box<dyn UndoEvent>>) -> Result<()> {
     match expr {
         Expr::StackItem(stack_item) => {
+            let stack_item = state.apply_modes(stack_item.clone());
             state.stack.push(stack_item.clone());
-            undos.push(Box::new(PushUndoEvent::new(stack_item.clone())));
+            undos.push(Box::new(PushUndoEvent::new(stack_item)));
             Ok(())
         }
         Expr::Identifier(ident) => run_ident(ident, state, undos),
         Expr::UnaryOp { op, rhs } => run_unary_op(op, rhs, state, undos),
         Expr::BinaryOp { lhs, op, rhs } => run_binary_op(lhs, op, rhs, state, undos),
         Expr::FunctionCall(ident, args) => run_function_call(ident, args, state, undos),
+        Expr::Vector(elements) => run_vector(elements, state, undos),
+        Expr::List(elements) => run_list(elements, state, undos),
     }
 }
 
+/// Evaluates `s`, either a function name or an expression using the variables in
+/// `bindings`, on a scratch stack holding `args` and returns the value left on top.
+/// Nothing is recorded for undo and the stack and variables are restored afterwards.
+pub fn evaluate(
+    s: &str,
+    args: &[StackItem],
+    bindings: &[(&str, &StackItem)],
+    state: &mut RpnState,
+) -> Result<StackItem> {
+    evaluate_expr(&parse(s)?, args, bindings, state)?
+        .ok_or_else(|| anyhow!("{s} did not leave a value on the stack"))
+}
+
+/// Parses `s` once for callers that evaluate the same expression many times.
+pub fn parse(s: &str) -> Result<Expr> {
+    let tokenizer = ExprLexer::new(s).context("tokenizing failed")?;
+    parse_expression_from_tokenizer(tokenizer).context("parse failed")
+}
+
+/// Like `evaluate` for an already parsed expression, returning `None` if nothing is
+/// left on the stack.
+pub fn evaluate_expr(
+    expr: &Expr,
+    args: &[StackItem],
+    bindings: &[(&str, &StackItem)],
+    state: &mut RpnState,
+) -> Result<Option<StackItem>> {
+    let stack = std::mem::replace(&mut state.stack, Stack::new());
+    let previous_values = bindings
+        .iter()
+        .map(|(name, value)| {
+            (
+                *name,
+                state.variables.insert(name.to_string(), (*value).clone()),
+            )
+        })
+        .collect::<Vec<_>>();
+    for arg in args {
+        state.stack.push(arg.clone());
+    }
+
+    let result = run_expr(expr, state, &mut vec![]);
+    let top = state.stack.pop();
+
+    state.stack = stack;
+    for (name, value) in previous_values {
+        match value {
+            Some(value) => state.variables.insert(name.to_string(), value),
+            None => state.variables.remove(name),
+        };
+    }
+    result?;
+    Ok(top)
+}
+
+fn run_vector(
+    elements: &[Expr],
+    state: &mut RpnState,
+    undos: &mut Vec<Box<dyn UndoEvent>>,
+) -> Result<()> {
+    let items = run_elements(elements, state, undos)?;
+    let vector = match matrix::build(items.clone()) {
+        Ok(vector) => vector,
+        Err(e) => {
+            for item in items {
+                state.stack.push(item);
+            }
+            return Err(e);
+        }
+    };
+    state.stack.push(vector.clone());
+    undos.push(Box::new(ReplaceUndoEvent::new(items, vec![vector])));
+    Ok(())
+}
+
+fn run_list(
+    elements: &[Expr],
+    state: &mut RpnState,
+    undos: &mut Vec<Box<dyn UndoEvent>>,
+) -> Result<()> {
+    let items = run_elements(elements, state, undos)?;
+    let list = StackItem::List(items.clone());
+    state.stack.push(list.clone());
+    undos.push(Box::new(ReplaceUndoEvent::new(items, vec![list])));
+    Ok(())
+}
+
+/// Runs each element and takes the results back off the stack, bottom first.
+fn run_elements(
+    elements: &[Expr],
+    state: &mut RpnState,
+    undos: &mut Vec<Box<dyn UndoEvent>>,
+) -> Result<Vec<StackItem>> {
+    for element in elements {
+        run_expr(element, state, undos)?;
+    }
+    let mut items = state.stack.pop_n(elements.len())?;
+    items.reverse();
+    Ok(items)
+}
+
 fn run_function_call(
     fn_name: &str,
     args: &Vec<Expr>,
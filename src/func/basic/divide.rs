@@ -34,30 +34,54 @@ impl Func for DivideFunc {
 
 #[cfg(test)]
 mod test {
+    use num_rational::BigRational;
+
     use crate::{test_binary_func, test_expr};
 
     #[test]
     fn test_divide() {
         test_binary_func!(
-            StackItem::Number(1.0, 10),
-            StackItem::Number(2.0, 10),
+            StackItem::Integer(1.into(), 10),
+            StackItem::Integer(2.into(), 10),
             "divide",
-            StackItem::Number(1.0 / 2.0, 10)
+            StackItem::Rational(BigRational::new(1.into(), 2.into()))
         );
     }
 
     #[test]
     fn test_divide_by_zero() {
         test_binary_func!(
-            StackItem::Number(1.0, 10),
-            StackItem::Number(0.0, 10),
+            StackItem::Integer(1.into(), 10),
+            StackItem::Integer(0.into(), 10),
             "divide",
             StackItem::Undefined
         );
     }
 
+    #[test]
+    fn test_divide_float() {
+        test_binary_func!(
+            StackItem::Number(1.5, 10),
+            StackItem::Integer(2.into(), 10),
+            "divide",
+            StackItem::Number(1.5 / 2.0, 10)
+        );
+    }
+
     #[test]
     fn test_divide_expr() {
-        test_expr!("1 / 2", StackItem::Number(1.0 / 2.0, 10));
+        test_expr!(
+            "1 / 2",
+            StackItem::Rational(BigRational::new(1.into(), 2.into()))
+        );
+    }
+
+    #[test]
+    fn test_divide_exact_expr() {
+        test_expr!(
+            "2 / 6",
+            StackItem::Rational(BigRational::new(1.into(), 3.into()))
+        );
+        test_expr!("1 / 3 * 3", StackItem::Integer(1.into(), 10));
     }
 }
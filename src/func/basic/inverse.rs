@@ -17,7 +17,7 @@ impl InverseFunc {
 
 impl Func for InverseFunc {
     fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
-        execute_unary(state, |a| StackItem::Number(1.0, 10).divide(a))
+        execute_unary(state, |a| StackItem::Integer(1.into(), 10).divide(a))
     }
 
     fn name(&self) -> &str {
@@ -29,30 +29,48 @@ impl Func for InverseFunc {
     }
 
     fn description(&self) -> &str {
-        "The inverse operator produces the inverse (1/x) of its operand."
+        "The inverse operator produces the inverse (1/x) of its operand, or the inverse of a square matrix."
     }
 }
 
 #[cfg(test)]
 mod test {
+    use num_rational::BigRational;
+
     use crate::{test_expr, test_unary_func};
 
     #[test]
     fn test_inverse() {
         test_unary_func!(
-            StackItem::Number(5.0, 10),
+            StackItem::Integer(5.into(), 10),
             "inv",
-            StackItem::Number(1.0 / 5.0, 10)
+            StackItem::Rational(BigRational::new(1.into(), 5.into()))
+        );
+    }
+
+    #[test]
+    fn test_inverse_float() {
+        test_unary_func!(
+            StackItem::Number(0.5, 10),
+            "inv",
+            StackItem::Number(1.0 / 0.5, 10)
         );
     }
 
     #[test]
     fn test_inverse_by_zero() {
-        test_unary_func!(StackItem::Number(0.0, 10), "inv", StackItem::Undefined);
+        test_unary_func!(
+            StackItem::Integer(0.into(), 10),
+            "inv",
+            StackItem::Undefined
+        );
     }
 
     #[test]
     fn test_inverse_expr() {
-        test_expr!("inv(5)", StackItem::Number(1.0 / 5.0, 10));
+        test_expr!(
+            "inv(5)",
+            StackItem::Rational(BigRational::new(1.into(), 5.into()))
+        );
     }
 }
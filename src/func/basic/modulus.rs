@@ -39,18 +39,18 @@ mod test {
     #[test]
     fn test_modulus() {
         test_binary_func!(
-            StackItem::Number(42.0, 10),
-            StackItem::Number(8.0, 10),
+            StackItem::Integer(42.into(), 10),
+            StackItem::Integer(8.into(), 10),
             "mod",
-            StackItem::Number(42.0 % 8.0, 10)
+            StackItem::Integer(2.into(), 10)
         );
     }
 
     #[test]
     fn test_modulus_by_zero() {
         test_binary_func!(
-            StackItem::Number(1.0, 10),
-            StackItem::Number(0.0, 10),
+            StackItem::Integer(1.into(), 10),
+            StackItem::Integer(0.into(), 10),
             "mod",
             StackItem::Undefined
         );
@@ -58,6 +58,6 @@ mod test {
 
     #[test]
     fn test_modulus_expr() {
-        test_expr!("42 % 8", StackItem::Number(42.0 % 8.0, 10));
+        test_expr!("42 % 8", StackItem::Integer(2.into(), 10));
     }
 }
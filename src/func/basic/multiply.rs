@@ -39,15 +39,15 @@ mod test {
     #[test]
     fn test_multiply() {
         test_binary_func!(
-            StackItem::Number(2.0, 10),
-            StackItem::Number(3.0, 10),
+            StackItem::Integer(2.into(), 10),
+            StackItem::Integer(3.into(), 10),
             "multiply",
-            StackItem::Number(2.0 * 3.0, 10)
+            StackItem::Integer(6.into(), 10)
         );
     }
 
     #[test]
     fn test_multiply_expr() {
-        test_expr!("2 * 3", StackItem::Number(2.0 * 3.0, 10));
+        test_expr!("2 * 3", StackItem::Integer(6.into(), 10));
     }
 }
@@ -38,6 +38,6 @@ mod test {
 
     #[test]
     fn test_negate_expr() {
-        test_expr!("-30", StackItem::Number(-30.0, 10));
+        test_expr!("-30", StackItem::Integer((-30).into(), 10));
     }
 }
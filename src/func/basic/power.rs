@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::{
     func::{execute_binary, Func},
-    state::RpnState,
+    state::{number_mode::NumberMode, RpnState},
     undo_action::UndoEvent,
 };
 
@@ -16,7 +16,12 @@ impl PowerFunc {
 
 impl Func for PowerFunc {
     fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
-        execute_binary(state, |a, b| a.pow(b))
+        let number_mode = state.number_mode;
+        let precision = state.precision;
+        execute_binary(state, |a, b| match number_mode {
+            NumberMode::Float => a.pow(b),
+            NumberMode::Decimal => a.decimal_pow(b, precision),
+        })
     }
 
     fn name(&self) -> &str {
@@ -34,20 +39,35 @@ impl Func for PowerFunc {
 
 #[cfg(test)]
 mod test {
+    use num_complex::Complex64;
+
     use crate::{test_binary_func, test_expr};
 
     #[test]
     fn test_subtract() {
         test_binary_func!(
-            StackItem::Number(2.0, 10),
-            StackItem::Number(3.0, 10),
+            StackItem::Integer(2.into(), 10),
+            StackItem::Integer(3.into(), 10),
             "pow",
-            StackItem::Number(2.0_f64.powf(3.0), 10)
+            StackItem::Integer(8.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_power_negative_base_fractional_exponent() {
+        test_expr!(
+            "-4^0.5",
+            StackItem::Complex(Complex64::new(-4.0, 0.0).powf(0.5))
         );
     }
 
+    #[test]
+    fn test_power_complex() {
+        test_expr!("(1i)^2", StackItem::Number(-1.0, 10));
+    }
+
     #[test]
     fn test_subtract_expr() {
-        test_expr!("2^3", StackItem::Number(2.0_f64.powf(3.0), 10));
+        test_expr!("2^3", StackItem::Integer(8.into(), 10));
     }
 }
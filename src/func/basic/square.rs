@@ -17,7 +17,7 @@ impl SquareFunc {
 
 impl Func for SquareFunc {
     fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
-        execute_unary(state, |a| a.pow(&StackItem::Number(2.0, 10)))
+        execute_unary(state, |a| a.pow(&StackItem::Integer(2.into(), 10)))
     }
 
     fn name(&self) -> &str {
@@ -40,14 +40,14 @@ mod test {
     #[test]
     fn test_square() {
         test_unary_func!(
-            StackItem::Number(5.0, 10),
+            StackItem::Integer(5.into(), 10),
             "sq",
-            StackItem::Number(5.0_f64.powf(2.0), 10)
+            StackItem::Integer(25.into(), 10)
         );
     }
 
     #[test]
     fn test_square_expr() {
-        test_expr!("sq(5)", StackItem::Number(5.0_f64.powf(2.0), 10));
+        test_expr!("sq(5)", StackItem::Integer(25.into(), 10));
     }
 }
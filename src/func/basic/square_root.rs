@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::{
     func::{execute_unary, Func},
-    state::RpnState,
+    state::{number_mode::NumberMode, RpnState},
     undo_action::UndoEvent,
 };
 
@@ -16,7 +16,12 @@ impl SquareRootFunc {
 
 impl Func for SquareRootFunc {
     fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
-        execute_unary(state, |a| a.sqrt())
+        let number_mode = state.number_mode;
+        let precision = state.precision;
+        execute_unary(state, |a| match number_mode {
+            NumberMode::Float => a.sqrt(),
+            NumberMode::Decimal => a.decimal_sqrt(precision),
+        })
     }
 
     fn name(&self) -> &str {
@@ -34,12 +39,14 @@ impl Func for SquareRootFunc {
 
 #[cfg(test)]
 mod test {
-    use crate::{state::RpnState, test_expr, test_unary_func};
+    use num_complex::Complex64;
+
+    use crate::{stack::item::StackItem, state::RpnState, test_expr, test_unary_func};
 
     #[test]
     fn test_square_root() {
         test_unary_func!(
-            StackItem::Number(5.0, 10),
+            StackItem::Integer(5.into(), 10),
             "sqrt",
             StackItem::Number(5.0_f64.sqrt(), 10)
         );
@@ -47,9 +54,19 @@ mod test {
 
     #[test]
     fn test_square_root_negative() {
+        test_expr!(
+            "sqrt(-5)",
+            StackItem::Complex(Complex64::new(0.0, 5.0_f64.sqrt()))
+        );
+        test_expr!("sqrt(-4)", StackItem::Complex(Complex64::new(0.0, 2.0)));
+    }
+
+    #[test]
+    fn test_square_root_string() {
         let mut state = RpnState::new().unwrap();
-        state.push_str("-5").unwrap();
-        state.push_str("sqrt").expect_err("expected input error");
+        state.push_str("'a'").unwrap();
+        state.push_str("sqrt").unwrap();
+        assert_eq!(StackItem::Undefined, *state.stack.peek(0).unwrap());
     }
 
     #[test]
@@ -39,15 +39,15 @@ mod test {
     #[test]
     fn test_subtract() {
         test_binary_func!(
-            StackItem::Number(1.0, 10),
-            StackItem::Number(2.0, 10),
+            StackItem::Integer(1.into(), 10),
+            StackItem::Integer(2.into(), 10),
             "subtract",
-            StackItem::Number(1.0 - 2.0, 10)
+            StackItem::Integer((-1).into(), 10)
         );
     }
 
     #[test]
     fn test_subtract_expr() {
-        test_expr!("1 - 2", StackItem::Number(1.0 - 2.0, 10));
+        test_expr!("1 - 2", StackItem::Integer((-1).into(), 10));
     }
 }
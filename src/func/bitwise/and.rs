@@ -0,0 +1,63 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::bitwise_op;
+
+pub struct AndFunc {}
+
+impl AndFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for AndFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| bitwise_op(a, b, |a, b| Ok(a & b)))
+    }
+
+    fn name(&self) -> &str {
+        "and"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["&"]
+    }
+
+    fn description(&self) -> &str {
+        "The bitwise AND (&) operator returns a 1 in each bit position for which the corresponding bits of both operands are 1."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_and() {
+        test_binary_func!(
+            StackItem::Integer(BigInt::from(0xf0f0), 16),
+            StackItem::Integer(BigInt::from(0xff00), 16),
+            "and",
+            StackItem::Integer(BigInt::from(0xf000), 16)
+        );
+    }
+
+    #[test]
+    fn test_and_expr() {
+        test_expr!("0xff & 0x0f", StackItem::Integer(BigInt::from(0x0f), 16));
+    }
+
+    #[test]
+    fn test_and_float() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("1.5 & 1").is_err());
+    }
+}
@@ -0,0 +1,60 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{bitwise_op, shift_amount};
+
+pub struct ArithmeticShiftRightFunc {}
+
+impl ArithmeticShiftRightFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ArithmeticShiftRightFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let word_size = state.integer_mode.word_size;
+        execute_binary(state, |a, b| {
+            bitwise_op(a, b, |a, b| Ok(a >> shift_amount(b, word_size)?))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "sar"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The arithmetic right shift operator shifts the first operand the specified number of bits to the right, keeping the sign of the operand."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_arithmetic_shift_right() {
+        test_binary_func!(
+            StackItem::Integer(BigInt::from(-8), 10),
+            StackItem::Integer(BigInt::from(1), 10),
+            "sar",
+            StackItem::Integer(BigInt::from(-4), 10)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_shift_right_expr() {
+        test_expr!("sar(0xf0, 4)", StackItem::Integer(BigInt::from(0x0f), 16));
+    }
+}
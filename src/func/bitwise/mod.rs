@@ -0,0 +1,89 @@
+use and::AndFunc;
+use anyhow::{anyhow, Result};
+use arithmetic_shift_right::ArithmeticShiftRightFunc;
+use not::NotFunc;
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive};
+use or::OrFunc;
+use rotate_left::RotateLeftFunc;
+use rotate_right::RotateRightFunc;
+use shift_left::ShiftLeftFunc;
+use shift_right::ShiftRightFunc;
+use xor::XorFunc;
+
+use crate::{
+    stack::item::{StackItem, MAX_EXACT_BITS},
+    state::RpnState,
+};
+
+pub mod and;
+pub mod arithmetic_shift_right;
+pub mod not;
+pub mod or;
+pub mod rotate_left;
+pub mod rotate_right;
+pub mod shift_left;
+pub mod shift_right;
+pub mod xor;
+
+pub fn bitwise_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(AndFunc::new()));
+    state.register_function(Box::new(ArithmeticShiftRightFunc::new()));
+    state.register_function(Box::new(NotFunc::new()));
+    state.register_function(Box::new(OrFunc::new()));
+    state.register_function(Box::new(RotateLeftFunc::new()));
+    state.register_function(Box::new(RotateRightFunc::new()));
+    state.register_function(Box::new(ShiftLeftFunc::new()));
+    state.register_function(Box::new(ShiftRightFunc::new()));
+    state.register_function(Box::new(XorFunc::new()));
+}
+
+/// Returns the value and display base of an integer operand.
+fn integer_operand(item: &StackItem) -> Result<(&BigInt, u8)> {
+    match item {
+        StackItem::Integer(v, display_base) => Ok((v, *display_base)),
+        _ => Err(anyhow!(
+            "bitwise operations require integer operands, found {item}"
+        )),
+    }
+}
+
+/// Applies a bitwise operation to two integer operands, keeping the display base of the first.
+fn bitwise_op<F>(a: &StackItem, b: &StackItem, op: F) -> Result<StackItem>
+where
+    F: FnOnce(&BigInt, &BigInt) -> Result<BigInt>,
+{
+    let (a, display_base) = integer_operand(a)?;
+    let (b, _) = integer_operand(b)?;
+    Ok(StackItem::Integer(op(a, b)?, display_base))
+}
+
+/// Returns the number of bits to shift by. With a word size, shifting by the word size
+/// already clears every bit, so larger amounts are reduced to it; without one the amount
+/// is limited like other exact results.
+fn shift_amount(v: &BigInt, word_size: Option<u32>) -> Result<usize> {
+    if v.is_negative() {
+        return Err(anyhow!(
+            "shift amount must be a non-negative integer, found {v}"
+        ));
+    }
+    if let Some(word_size) = word_size {
+        return Ok(v.min(&BigInt::from(word_size)).to_usize().unwrap());
+    }
+    v.to_u64()
+        .filter(|v| *v <= MAX_EXACT_BITS)
+        .map(|v| v as usize)
+        .ok_or_else(|| {
+            anyhow!("shift amount {v} is larger than the limit of {MAX_EXACT_BITS} bits")
+        })
+}
+
+/// Returns the number of bits to rotate by, reduced to less than the word size.
+fn rotate_amount(v: &BigInt, word_size: u32) -> Result<usize> {
+    if v.is_negative() {
+        return Err(anyhow!(
+            "rotate amount must be a non-negative integer, found {v}"
+        ));
+    }
+    Ok((v % word_size).to_usize().unwrap())
+}
@@ -0,0 +1,72 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::integer_operand;
+
+pub struct NotFunc {}
+
+impl NotFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for NotFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let (a, display_base) = integer_operand(a)?;
+            Ok(StackItem::Integer(!a, display_base))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "not"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["~"]
+    }
+
+    fn description(&self) -> &str {
+        "The bitwise NOT (~) operator inverts the bits of its operand. Without a word size this is the same as -x - 1."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{stack::item::StackItem, state::RpnState, test_expr, test_unary_func};
+
+    #[test]
+    fn test_not() {
+        test_unary_func!(
+            StackItem::Integer(BigInt::from(5), 10),
+            "not",
+            StackItem::Integer(BigInt::from(-6), 10)
+        );
+    }
+
+    #[test]
+    fn test_not_expr() {
+        test_expr!("~0x0", StackItem::Integer(BigInt::from(-1), 16));
+    }
+
+    #[test]
+    fn test_not_word_size() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws8").unwrap();
+        state.push_str("unsigned").unwrap();
+        state.push_str("~0x0f").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0xf0), 16),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::bitwise_op;
+
+pub struct OrFunc {}
+
+impl OrFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for OrFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| bitwise_op(a, b, |a, b| Ok(a | b)))
+    }
+
+    fn name(&self) -> &str {
+        "or"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["|"]
+    }
+
+    fn description(&self) -> &str {
+        "The bitwise OR (|) operator returns a 1 in each bit position for which the corresponding bits of either or both operands are 1."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_or() {
+        test_binary_func!(
+            StackItem::Integer(BigInt::from(0xf0), 16),
+            StackItem::Integer(BigInt::from(0x0f), 16),
+            "or",
+            StackItem::Integer(BigInt::from(0xff), 16)
+        );
+    }
+
+    #[test]
+    fn test_or_precedence_expr() {
+        test_expr!("1 | 2 & 3", StackItem::Integer(BigInt::from(3), 10));
+        test_expr!("1 | 1 << 4", StackItem::Integer(BigInt::from(17), 10));
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{bitwise_op, rotate_amount};
+
+pub struct RotateLeftFunc {}
+
+impl RotateLeftFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for RotateLeftFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let integer_mode = state.integer_mode;
+        execute_binary(state, |a, b| {
+            bitwise_op(a, b, |a, b| {
+                let (Some(word_size), Some(u)) =
+                    (integer_mode.word_size, integer_mode.unsigned_value(a))
+                else {
+                    return Err(anyhow!(
+                        "rotate requires a word size, set one with ws8 to ws128"
+                    ));
+                };
+                let n = rotate_amount(b, word_size)?;
+                let word_size = word_size as usize;
+                // bits past the word size are discarded when the result is wrapped
+                Ok((&u << n) | (&u >> (word_size - n)))
+            })
+        })
+    }
+
+    fn name(&self) -> &str {
+        "rotl"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "Rotates the bits of the first operand the specified number of bits to the left, bits shifted out of the word are shifted back in on the other side."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_rotate_left() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws8").unwrap();
+        state.push_str("unsigned").unwrap();
+        state.push_str("0x81").unwrap();
+        state.push_str("1").unwrap();
+        state.push_str("rotl").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0x03), 16),
+            *state.stack.peek(0).unwrap()
+        );
+
+        state.undo().unwrap();
+        assert_eq!(2, state.stack.len());
+    }
+
+    #[test]
+    fn test_rotate_left_huge_amount() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws128").unwrap();
+        state.push_str("unsigned").unwrap();
+        // 128000000001 is 1 more than a multiple of the word size
+        state.push_str("rotl(0x81, 128000000001)").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0x102), 16),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rotate_left_without_word_size() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("rotl(1, 1)").is_err());
+    }
+}
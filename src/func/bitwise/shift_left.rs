@@ -0,0 +1,100 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{bitwise_op, shift_amount};
+
+pub struct ShiftLeftFunc {}
+
+impl ShiftLeftFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ShiftLeftFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let word_size = state.integer_mode.word_size;
+        execute_binary(state, |a, b| {
+            bitwise_op(a, b, |a, b| Ok(a << shift_amount(b, word_size)?))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "shl"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["<<"]
+    }
+
+    fn description(&self) -> &str {
+        "The left shift (<<) operator shifts the first operand the specified number of bits to the left. Bits shifted past the word size are discarded."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{stack::item::StackItem, state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_shift_left() {
+        test_binary_func!(
+            StackItem::Integer(BigInt::from(0x1), 16),
+            StackItem::Integer(BigInt::from(4), 10),
+            "shl",
+            StackItem::Integer(BigInt::from(0x10), 16)
+        );
+    }
+
+    #[test]
+    fn test_shift_left_expr() {
+        test_expr!("1 << 2 + 1", StackItem::Integer(BigInt::from(8), 10));
+    }
+
+    #[test]
+    fn test_shift_left_negative_amount() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("1 << -1").is_err());
+    }
+
+    #[test]
+    fn test_shift_left_huge_amount() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("1 << 100000000000").is_err());
+        assert!(state.push_str("sar(-1, 100000000000)").is_err());
+        assert!(state.push_str("1 >> 100000000000").is_err());
+
+        // a word size bounds the shift, so any amount is fine
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws128").unwrap();
+        state.push_str("1 << 100000000000").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0), 10),
+            *state.stack.peek(0).unwrap()
+        );
+        state.push_str("sar(-1, 100000000000)").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(-1), 10),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_shift_left_word_size() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws8").unwrap();
+        state.push_str("unsigned").unwrap();
+        state.push_str("0x81 << 1").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0x02), 16),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::{anyhow, Result};
+use num_traits::Signed;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{bitwise_op, shift_amount};
+
+pub struct ShiftRightFunc {}
+
+impl ShiftRightFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ShiftRightFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let integer_mode = state.integer_mode;
+        execute_binary(state, |a, b| {
+            bitwise_op(a, b, |a, b| {
+                // a logical shift works on the bit pattern, which needs a word size for negative values
+                let a = match integer_mode.unsigned_value(a) {
+                    Some(a) => a,
+                    None if a.is_negative() => {
+                        return Err(anyhow!(
+                            "logical shift right of a negative value requires a word size, use sar instead"
+                        ))
+                    }
+                    None => a.clone(),
+                };
+                Ok(a >> shift_amount(b, integer_mode.word_size)?)
+            })
+        })
+    }
+
+    fn name(&self) -> &str {
+        "shr"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![">>"]
+    }
+
+    fn description(&self) -> &str {
+        "The logical right shift (>>) operator shifts the first operand the specified number of bits to the right, shifting in zeros from the left."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{stack::item::StackItem, state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_shift_right() {
+        test_binary_func!(
+            StackItem::Integer(BigInt::from(0xf0), 16),
+            StackItem::Integer(BigInt::from(4), 10),
+            "shr",
+            StackItem::Integer(BigInt::from(0x0f), 16)
+        );
+    }
+
+    #[test]
+    fn test_shift_right_expr() {
+        test_expr!("0x100 >> 8", StackItem::Integer(BigInt::from(1), 16));
+    }
+
+    #[test]
+    fn test_shift_right_negative() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("-8 >> 1").is_err());
+
+        state.push_str("ws8").unwrap();
+        state.push_str("-8 >> 1").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0x7c), 10),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::bitwise_op;
+
+pub struct XorFunc {}
+
+impl XorFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for XorFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| bitwise_op(a, b, |a, b| Ok(a ^ b)))
+    }
+
+    fn name(&self) -> &str {
+        "xor"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The bitwise XOR operator returns a 1 in each bit position for which the corresponding bits of either but not both operands are 1."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_xor() {
+        test_binary_func!(
+            StackItem::Integer(BigInt::from(0xff), 16),
+            StackItem::Integer(BigInt::from(0x0f), 16),
+            "xor",
+            StackItem::Integer(BigInt::from(0xf0), 16)
+        );
+    }
+
+    #[test]
+    fn test_xor_expr() {
+        test_expr!("xor(6, 3)", StackItem::Integer(BigInt::from(5), 10));
+    }
+}
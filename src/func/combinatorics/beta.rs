@@ -0,0 +1,84 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::gamma;
+
+pub struct BetaFunc {}
+
+impl BetaFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for BetaFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, beta)
+    }
+
+    fn name(&self) -> &str {
+        "beta"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The beta function returns gamma(a) gamma(b) / gamma(a + b), exactly when both operands are positive whole numbers."
+    }
+}
+
+fn beta(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    let (Some(x), Some(y)) = (a.to_f64(), b.to_f64()) else {
+        return Err(anyhow!("beta requires real numbers but found {a} and {b}"));
+    };
+    let is_pole = |v: f64| v <= 0.0 && v.fract() == 0.0;
+    if is_pole(x) || is_pole(y) {
+        return Ok(StackItem::Undefined);
+    }
+    if a.is_integer() && b.is_integer() {
+        return gamma(a)?.multiply(&gamma(b)?)?.divide(&gamma(&a.add(b)?)?);
+    }
+    if is_pole(x + y) {
+        return Ok(StackItem::Integer(0.into(), 10));
+    }
+    // logarithms keep the intermediate gammas from overflowing
+    let (lx, sx) = libm::lgamma_r(x);
+    let (ly, sy) = libm::lgamma_r(y);
+    let (lxy, sxy) = libm::lgamma_r(x + y);
+    let sign = (sx * sy * sxy) as f64;
+    Ok(StackItem::Number(sign * (lx + ly - lxy).exp(), 10))
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_beta_exact() {
+        test_binary_func!(
+            StackItem::Integer(2.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "beta",
+            StackItem::Rational(BigRational::new(1.into(), 12.into()))
+        );
+    }
+
+    #[test]
+    fn test_beta() {
+        test_expr!(
+            "beta(0.5, 0.5)",
+            StackItem::Number(std::f64::consts::PI, 10)
+        );
+        test_expr!("beta(0, 1)", StackItem::Undefined);
+    }
+}
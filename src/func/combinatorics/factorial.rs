@@ -0,0 +1,69 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::factorial;
+
+pub struct FactorialFunc {}
+
+impl FactorialFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for FactorialFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, factorial)
+    }
+
+    fn name(&self) -> &str {
+        "fact"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["factorial", "!"]
+    }
+
+    fn description(&self) -> &str {
+        "The factorial (!) function returns the product of the whole numbers from 1 to n, exactly for whole numbers and through gamma(x + 1) for other real numbers."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_factorial() {
+        test_unary_func!(
+            StackItem::Integer(20.into(), 10),
+            "fact",
+            StackItem::Integer(2432902008176640000_u64.into(), 10)
+        );
+        test_expr!("fact(0)", StackItem::Integer(1.into(), 10));
+    }
+
+    #[test]
+    fn test_factorial_exact_beyond_float() {
+        test_expr!(
+            "fact(25)",
+            StackItem::Integer(
+                "15511210043330985984000000"
+                    .parse::<num_bigint::BigInt>()
+                    .unwrap(),
+                10
+            )
+        );
+    }
+
+    #[test]
+    fn test_factorial_non_integer() {
+        test_expr!("fact(0.5)", StackItem::Number(libm::tgamma(1.5), 10));
+        test_expr!("fact(-1)", StackItem::Undefined);
+    }
+}
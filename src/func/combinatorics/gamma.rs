@@ -0,0 +1,65 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::gamma;
+
+pub struct GammaFunc {}
+
+impl GammaFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for GammaFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, gamma)
+    }
+
+    fn name(&self) -> &str {
+        "gamma"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["tgamma"]
+    }
+
+    fn description(&self) -> &str {
+        "The gamma function extends the factorial to real numbers, gamma(n) = (n - 1)! for whole numbers."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr, test_unary_func};
+
+    #[test]
+    fn test_gamma() {
+        test_unary_func!(
+            StackItem::Integer(5.into(), 10),
+            "gamma",
+            StackItem::Integer(24.into(), 10)
+        );
+        test_expr!(
+            "gamma(0.5)",
+            StackItem::Number(std::f64::consts::PI.sqrt(), 10)
+        );
+    }
+
+    #[test]
+    fn test_gamma_poles() {
+        test_expr!("gamma(0)", StackItem::Undefined);
+        test_expr!("gamma(-2)", StackItem::Undefined);
+    }
+
+    #[test]
+    fn test_gamma_overflow() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("gamma(200.5)").is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct LGammaFunc {}
+
+impl LGammaFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for LGammaFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| match a.to_f64() {
+            Some(x) if x <= 0.0 && x.fract() == 0.0 => Ok(StackItem::Undefined),
+            Some(x) => Ok(StackItem::Number(libm::lgamma(x), 10)),
+            None => Err(anyhow!("lgamma requires a real number but found {a}")),
+        })
+    }
+
+    fn name(&self) -> &str {
+        "lgamma"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["lngamma"]
+    }
+
+    fn description(&self) -> &str {
+        "The lgamma function returns the natural logarithm of the absolute value of gamma, which stays finite where gamma overflows."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_lgamma() {
+        test_unary_func!(
+            StackItem::Integer(1000.into(), 10),
+            "lgamma",
+            StackItem::Number(libm::lgamma(1000.0), 10)
+        );
+        test_expr!("lgamma(0)", StackItem::Undefined);
+    }
+}
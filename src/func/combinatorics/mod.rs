@@ -0,0 +1,141 @@
+use std::f64::consts::LN_2;
+
+use anyhow::{anyhow, Result};
+use beta::BetaFunc;
+use factorial::FactorialFunc;
+use gamma::GammaFunc;
+use lgamma::LGammaFunc;
+use multinomial::MultinomialFunc;
+use ncr::NCrFunc;
+use npr::NPrFunc;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, ToPrimitive};
+
+use crate::{
+    stack::item::{StackItem, MAX_EXACT_BITS},
+    state::RpnState,
+};
+
+pub mod beta;
+pub mod factorial;
+pub mod gamma;
+pub mod lgamma;
+pub mod multinomial;
+pub mod ncr;
+pub mod npr;
+
+pub fn combinatorics_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(BetaFunc::new()));
+    state.register_function(Box::new(FactorialFunc::new()));
+    state.register_function(Box::new(GammaFunc::new()));
+    state.register_function(Box::new(LGammaFunc::new()));
+    state.register_function(Box::new(MultinomialFunc::new()));
+    state.register_function(Box::new(NCrFunc::new()));
+    state.register_function(Box::new(NPrFunc::new()));
+}
+
+/// Returns a count operand, which must be a non-negative whole number.
+fn count_operand(item: &StackItem) -> Result<u64> {
+    let count = match item {
+        StackItem::Integer(v, _) => v.to_u64(),
+        _ if item.is_integer() => item.to_f64().filter(|v| *v >= 0.0).map(|v| v as u64),
+        _ => None,
+    };
+    count.ok_or_else(|| anyhow!("expected a non-negative integer but found {item}"))
+}
+
+/// Returns x!, exact for whole numbers and through gamma(x + 1) for other real numbers.
+fn factorial(a: &StackItem) -> Result<StackItem> {
+    if a.is_integer() {
+        if is_negative(a) {
+            return Ok(StackItem::Undefined);
+        }
+        let n = count_operand(a)?;
+        return Ok(StackItem::Integer(factorial_exact(n)?, 10));
+    }
+    match a.to_f64() {
+        Some(x) => gamma_float(x + 1.0),
+        None => Err(anyhow!("factorial requires a real number but found {a}")),
+    }
+}
+
+/// Returns gamma(x), exact for positive whole numbers and undefined at the poles.
+fn gamma(a: &StackItem) -> Result<StackItem> {
+    if a.is_integer() {
+        if is_negative(a) || a.is_zero() {
+            return Ok(StackItem::Undefined);
+        }
+        let n = count_operand(a)?;
+        return Ok(StackItem::Integer(factorial_exact(n - 1)?, 10));
+    }
+    match a.to_f64() {
+        Some(x) => gamma_float(x),
+        None => Err(anyhow!("gamma requires a real number but found {a}")),
+    }
+}
+
+fn gamma_float(x: f64) -> Result<StackItem> {
+    if x <= 0.0 && x.fract() == 0.0 {
+        return Ok(StackItem::Undefined);
+    }
+    let v = libm::tgamma(x);
+    if v.is_infinite() {
+        return Err(anyhow!(
+            "gamma of {x} is too large for floating point, use a whole number for an exact result"
+        ));
+    }
+    Ok(StackItem::Number(v, 10))
+}
+
+fn is_negative(a: &StackItem) -> bool {
+    match a {
+        StackItem::Integer(v, _) => v.is_negative(),
+        _ => a.to_f64().is_some_and(|v| v < 0.0),
+    }
+}
+
+fn factorial_exact(n: u64) -> Result<BigInt> {
+    check_exact_size(libm::lgamma(n as f64 + 1.0), &format!("{n}!"))?;
+    Ok(product(1, n))
+}
+
+/// Fails when a result whose natural logarithm is `ln_value` has more bits than exact
+/// integers are allowed.
+fn check_exact_size(ln_value: f64, what: &str) -> Result<()> {
+    if ln_value / LN_2 > MAX_EXACT_BITS as f64 {
+        return Err(anyhow!("{what} is too large to compute exactly"));
+    }
+    Ok(())
+}
+
+/// Returns the product of lo..=hi, splitting the range so the multiplications stay balanced.
+fn product(lo: u64, hi: u64) -> BigInt {
+    if lo > hi {
+        return BigInt::one();
+    }
+    if hi - lo < 16 {
+        return (lo..=hi).map(BigInt::from).product();
+    }
+    let mid = lo + (hi - lo) / 2;
+    product(lo, mid) * product(mid + 1, hi)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_postfix_factorial() {
+        test_expr!("5!", StackItem::Integer(120.into(), 10));
+        test_expr!("-3!", StackItem::Integer((-6).into(), 10));
+        test_expr!("2 * 3!", StackItem::Integer(12.into(), 10));
+        test_expr!("3!!", StackItem::Integer(720.into(), 10));
+        test_expr!("(1 + 2)!", StackItem::Integer(6.into(), 10));
+    }
+
+    #[test]
+    fn test_factorial_too_large() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("1000000!").is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{count_operand, factorial_exact};
+
+pub struct MultinomialFunc {}
+
+impl MultinomialFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for MultinomialFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let counts = match a {
+                StackItem::List(items) | StackItem::Vector(items) => items
+                    .iter()
+                    .map(count_operand)
+                    .collect::<Result<Vec<_>>>()?,
+                _ => return Err(anyhow!("expected a list of counts but found {a}")),
+            };
+            let n = counts.iter().sum();
+            let mut result = factorial_exact(n)?;
+            for k in counts {
+                result /= factorial_exact(k)?;
+            }
+            Ok(StackItem::Integer(result, 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "multinomial"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The multinomial function returns the number of ways to split (k1 + k2 + ...) items into groups of sizes {k1 k2 ...}, (k1 + k2 + ...)! / (k1! k2! ...)."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_multinomial() {
+        test_expr!("multinomial({2 3 4})", StackItem::Integer(1260.into(), 10));
+        test_expr!("multinomial([1, 1])", StackItem::Integer(2.into(), 10));
+    }
+
+    #[test]
+    fn test_multinomial_invalid() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("multinomial({2 0.5})").is_err());
+    }
+}
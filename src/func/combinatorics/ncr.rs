@@ -0,0 +1,85 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{check_exact_size, count_operand, product};
+
+pub struct NCrFunc {}
+
+impl NCrFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for NCrFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            let n = count_operand(a)?;
+            let r = count_operand(b)?;
+            if r > n {
+                return Ok(StackItem::Integer(0.into(), 10));
+            }
+            let r = r.min(n - r);
+            check_exact_size(
+                libm::lgamma(n as f64 + 1.0)
+                    - libm::lgamma(r as f64 + 1.0)
+                    - libm::lgamma((n - r) as f64 + 1.0),
+                &format!("nCr({n}, {r})"),
+            )?;
+            Ok(StackItem::Integer(
+                product(n - r + 1, n) / product(1, r),
+                10,
+            ))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "nCr"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["ncr", "comb"]
+    }
+
+    fn description(&self) -> &str {
+        "The nCr function returns the number of ways to choose r items from n without regard to order, n! / (r! (n - r)!)."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_ncr() {
+        test_binary_func!(
+            StackItem::Integer(52.into(), 10),
+            StackItem::Integer(5.into(), 10),
+            "nCr",
+            StackItem::Integer(2598960.into(), 10)
+        );
+        test_expr!("comb(5, 6)", StackItem::Integer(0.into(), 10));
+        test_expr!(
+            "ncr(100, 50)",
+            StackItem::Integer(
+                "100891344545564193334812497256"
+                    .parse::<num_bigint::BigInt>()
+                    .unwrap(),
+                10
+            )
+        );
+    }
+
+    #[test]
+    fn test_ncr_invalid() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("nCr(5.5, 2)").is_err());
+        assert!(state.push_str("nCr(5, -1)").is_err());
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{check_exact_size, count_operand, product};
+
+pub struct NPrFunc {}
+
+impl NPrFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for NPrFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            let n = count_operand(a)?;
+            let r = count_operand(b)?;
+            if r > n {
+                return Ok(StackItem::Integer(0.into(), 10));
+            }
+            check_exact_size(
+                libm::lgamma(n as f64 + 1.0) - libm::lgamma((n - r) as f64 + 1.0),
+                &format!("nPr({n}, {r})"),
+            )?;
+            Ok(StackItem::Integer(product(n - r + 1, n), 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "nPr"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["npr", "perm"]
+    }
+
+    fn description(&self) -> &str {
+        "The nPr function returns the number of ordered arrangements of r items chosen from n, n! / (n - r)!."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_npr() {
+        test_binary_func!(
+            StackItem::Integer(10.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "nPr",
+            StackItem::Integer(720.into(), 10)
+        );
+        test_expr!("perm(3, 0)", StackItem::Integer(1.into(), 10));
+    }
+}
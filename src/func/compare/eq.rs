@@ -0,0 +1,70 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::truth;
+
+pub struct EqualFunc {}
+
+impl EqualFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for EqualFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            Ok(truth(a.compare(b).map_or(a == b, |o| o == Ordering::Equal)))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "eq"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["=="]
+    }
+
+    fn description(&self) -> &str {
+        "The equal (==) operator returns 1 when its operands are equal and 0 otherwise."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_eq() {
+        test_binary_func!(
+            StackItem::Integer(2.into(), 10),
+            StackItem::Integer(2.into(), 10),
+            "eq",
+            StackItem::Integer(1.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_eq_false() {
+        test_binary_func!(
+            StackItem::Integer(2.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "eq",
+            StackItem::Integer(0.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_eq_expr() {
+        test_expr!("1/2 == 0.5", StackItem::Integer(1.into(), 10));
+        test_expr!("'a' == 'b'", StackItem::Integer(0.into(), 10));
+    }
+}
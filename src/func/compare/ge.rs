@@ -0,0 +1,68 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::compare_op;
+
+pub struct GreaterOrEqualFunc {}
+
+impl GreaterOrEqualFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for GreaterOrEqualFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| compare_op(a, b, |o| o != Ordering::Less))
+    }
+
+    fn name(&self) -> &str {
+        "ge"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![">="]
+    }
+
+    fn description(&self) -> &str {
+        "The greater than or equal (>=) operator returns 1 when the first operand is greater than or equal to the second and 0 otherwise."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_ge() {
+        test_binary_func!(
+            StackItem::Integer(3.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "ge",
+            StackItem::Integer(1.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_ge_false() {
+        test_binary_func!(
+            StackItem::Integer(2.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "ge",
+            StackItem::Integer(0.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_ge_expr() {
+        test_expr!("3 >= 2.5", StackItem::Integer(1.into(), 10));
+        test_expr!("1/3 >= 0.5", StackItem::Integer(0.into(), 10));
+    }
+}
@@ -0,0 +1,68 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::compare_op;
+
+pub struct LessOrEqualFunc {}
+
+impl LessOrEqualFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for LessOrEqualFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| compare_op(a, b, |o| o != Ordering::Greater))
+    }
+
+    fn name(&self) -> &str {
+        "le"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["<="]
+    }
+
+    fn description(&self) -> &str {
+        "The less than or equal (<=) operator returns 1 when the first operand is less than or equal to the second and 0 otherwise."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_le() {
+        test_binary_func!(
+            StackItem::Integer(3.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "le",
+            StackItem::Integer(1.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_le_false() {
+        test_binary_func!(
+            StackItem::Integer(4.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "le",
+            StackItem::Integer(0.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_le_expr() {
+        test_expr!("2.5 <= 3", StackItem::Integer(1.into(), 10));
+        test_expr!("0.5 <= 1/3", StackItem::Integer(0.into(), 10));
+    }
+}
@@ -0,0 +1,68 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::compare_op;
+
+pub struct LessFunc {}
+
+impl LessFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for LessFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| compare_op(a, b, |o| o == Ordering::Less))
+    }
+
+    fn name(&self) -> &str {
+        "lt"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["<"]
+    }
+
+    fn description(&self) -> &str {
+        "The less than (<) operator returns 1 when the first operand is less than the second and 0 otherwise."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_lt() {
+        test_binary_func!(
+            StackItem::Integer(2.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "lt",
+            StackItem::Integer(1.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_lt_false() {
+        test_binary_func!(
+            StackItem::Integer(3.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "lt",
+            StackItem::Integer(0.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_lt_expr() {
+        test_expr!("1 << 2 < 5", StackItem::Integer(1.into(), 10));
+        test_expr!("-1 < -2", StackItem::Integer(0.into(), 10));
+    }
+}
@@ -0,0 +1,57 @@
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, Result};
+use eq::EqualFunc;
+use ge::GreaterOrEqualFunc;
+use gt::GreaterFunc;
+use le::LessOrEqualFunc;
+use lt::LessFunc;
+use ne::NotEqualFunc;
+
+use crate::{stack::item::StackItem, state::RpnState};
+
+pub mod eq;
+pub mod ge;
+pub mod gt;
+pub mod le;
+pub mod lt;
+pub mod ne;
+
+pub fn compare_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(EqualFunc::new()));
+    state.register_function(Box::new(GreaterOrEqualFunc::new()));
+    state.register_function(Box::new(GreaterFunc::new()));
+    state.register_function(Box::new(LessOrEqualFunc::new()));
+    state.register_function(Box::new(LessFunc::new()));
+    state.register_function(Box::new(NotEqualFunc::new()));
+}
+
+/// Returns 1 for true and 0 for false, so results can be used as `filter` tests.
+fn truth(v: bool) -> StackItem {
+    StackItem::Integer(u8::from(v).into(), 10)
+}
+
+/// Orders two real numbers or strings and returns whether `test` accepts the ordering.
+fn compare_op<F>(a: &StackItem, b: &StackItem, test: F) -> Result<StackItem>
+where
+    F: FnOnce(Ordering) -> bool,
+{
+    match a.compare(b) {
+        Some(o) => Ok(truth(test(o))),
+        None => Err(anyhow!("cannot compare {a} and {b}")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::RpnState;
+
+    #[test]
+    fn test_compare_incomparable() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("1i").unwrap();
+        state.push_str("2").unwrap();
+        assert!(state.push_str("<").is_err());
+        assert_eq!(2, state.stack.len());
+    }
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ArgFunc {}
+
+impl ArgFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ArgFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let angle_mode = state.angle_mode;
+        execute_unary(state, |a| a.arg(angle_mode))
+    }
+
+    fn name(&self) -> &str {
+        "arg"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The arg function returns the angle (in the current angle mode) of a complex number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_expr;
+
+    #[test]
+    fn test_arg_expr() {
+        test_expr!("arg(1 + 1i)", StackItem::Number(45.0, 10));
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ConjFunc {}
+
+impl ConjFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ConjFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.conj())
+    }
+
+    fn name(&self) -> &str {
+        "conj"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The conj function returns the complex conjugate of a number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+
+    use crate::test_expr;
+
+    #[test]
+    fn test_conj_expr() {
+        test_expr!(
+            "conj(3 + 4i)",
+            StackItem::Complex(Complex64::new(3.0, -4.0))
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ImaginaryFunc {}
+
+impl ImaginaryFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ImaginaryFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.imaginary_part())
+    }
+
+    fn name(&self) -> &str {
+        "im"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The im function returns the imaginary part of a complex number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_expr;
+
+    #[test]
+    fn test_imaginary_expr() {
+        test_expr!("im(3 + 4i)", StackItem::Number(4.0, 10));
+        test_expr!("im(3)", StackItem::Integer(0.into(), 10));
+    }
+}
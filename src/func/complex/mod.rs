@@ -0,0 +1,63 @@
+use anyhow::{anyhow, Result};
+use arg::ArgFunc;
+use conj::ConjFunc;
+use imaginary::ImaginaryFunc;
+use polar::PolarFunc;
+use real::RealFunc;
+use show_polar::ShowPolarFunc;
+use show_rectangular::ShowRectangularFunc;
+
+use crate::{
+    state::{complex_display::ComplexDisplay, RpnState},
+    undo_action::UndoEvent,
+};
+
+pub mod arg;
+pub mod conj;
+pub mod imaginary;
+pub mod polar;
+pub mod real;
+pub mod show_polar;
+pub mod show_rectangular;
+
+pub fn complex_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(ArgFunc::new()));
+    state.register_function(Box::new(ConjFunc::new()));
+    state.register_function(Box::new(ImaginaryFunc::new()));
+    state.register_function(Box::new(PolarFunc::new()));
+    state.register_function(Box::new(RealFunc::new()));
+    state.register_function(Box::new(ShowPolarFunc::new()));
+    state.register_function(Box::new(ShowRectangularFunc::new()));
+}
+
+#[derive(Debug)]
+pub struct ComplexDisplayUndoEvent {
+    previous_display: ComplexDisplay,
+    new_display: ComplexDisplay,
+}
+
+impl UndoEvent for ComplexDisplayUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.complex_display != self.new_display {
+            return Err(anyhow!(
+                "expected current display to be {:?} but was {:?}",
+                self.new_display,
+                state.complex_display
+            ));
+        }
+        state.complex_display = self.previous_display;
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.complex_display != self.previous_display {
+            return Err(anyhow!(
+                "expected current display to be {:?} but was {:?}",
+                self.previous_display,
+                state.complex_display
+            ));
+        }
+        state.complex_display = self.new_display;
+        Ok(())
+    }
+}
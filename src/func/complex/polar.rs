@@ -0,0 +1,60 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct PolarFunc {}
+
+impl PolarFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PolarFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let angle_mode = state.angle_mode;
+        execute_binary(state, |a, b| a.polar(b, angle_mode))
+    }
+
+    fn name(&self) -> &str {
+        "polar"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["∠"]
+    }
+
+    fn description(&self) -> &str {
+        "The polar (∠) operator creates a complex number from a magnitude and an angle in the current angle mode."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+
+    use crate::{state::angle_mode::AngleMode, test_binary_angle_func, test_expr};
+
+    #[test]
+    fn test_polar() {
+        test_binary_angle_func!(
+            AngleMode::Radians,
+            StackItem::Integer(2.into(), 10),
+            StackItem::Integer(1.into(), 10),
+            "polar",
+            StackItem::Complex(Complex64::from_polar(2.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_polar_expr() {
+        test_expr!(
+            "2∠90",
+            StackItem::Complex(Complex64::from_polar(2.0, std::f64::consts::FRAC_PI_2))
+        );
+    }
+}
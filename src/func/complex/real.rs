@@ -0,0 +1,44 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct RealFunc {}
+
+impl RealFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for RealFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.real_part())
+    }
+
+    fn name(&self) -> &str {
+        "re"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The re function returns the real part of a complex number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_expr;
+
+    #[test]
+    fn test_real_expr() {
+        test_expr!("re(3 + 4i)", StackItem::Number(3.0, 10));
+        test_expr!("re(3)", StackItem::Integer(3.into(), 10));
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{complex_display::ComplexDisplay, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::ComplexDisplayUndoEvent;
+
+pub struct ShowPolarFunc {}
+
+impl ShowPolarFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ShowPolarFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous_display = state.complex_display;
+        state.complex_display = ComplexDisplay::Polar;
+        Ok(Box::new(ComplexDisplayUndoEvent {
+            previous_display,
+            new_display: ComplexDisplay::Polar,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "showpolar"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The showpolar function displays complex numbers in polar form (r∠θ)"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::{complex_display::ComplexDisplay, RpnState};
+
+    #[test]
+    fn test_show_polar() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("showpolar").unwrap();
+        assert_eq!(ComplexDisplay::Polar, state.complex_display);
+
+        state.undo().unwrap();
+        assert_eq!(ComplexDisplay::Rectangular, state.complex_display);
+
+        state.redo().unwrap();
+        assert_eq!(ComplexDisplay::Polar, state.complex_display);
+    }
+}
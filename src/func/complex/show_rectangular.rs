@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{complex_display::ComplexDisplay, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::ComplexDisplayUndoEvent;
+
+pub struct ShowRectangularFunc {}
+
+impl ShowRectangularFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ShowRectangularFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous_display = state.complex_display;
+        state.complex_display = ComplexDisplay::Rectangular;
+        Ok(Box::new(ComplexDisplayUndoEvent {
+            previous_display,
+            new_display: ComplexDisplay::Rectangular,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "showrect"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The showrect function displays complex numbers in rectangular form (a+bi)"
+    }
+}
@@ -0,0 +1,59 @@
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Weekday};
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct DayOfWeekFunc {}
+
+impl DayOfWeekFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for DayOfWeekFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| match a {
+            StackItem::DateTime(v) => {
+                let name = match v.weekday() {
+                    Weekday::Mon => "Monday",
+                    Weekday::Tue => "Tuesday",
+                    Weekday::Wed => "Wednesday",
+                    Weekday::Thu => "Thursday",
+                    Weekday::Fri => "Friday",
+                    Weekday::Sat => "Saturday",
+                    Weekday::Sun => "Sunday",
+                };
+                Ok(StackItem::String(name.to_string()))
+            }
+            _ => Err(anyhow!("expected a date but found {a}")),
+        })
+    }
+
+    fn name(&self) -> &str {
+        "dow"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The dow function returns the day of the week of a date"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_expr;
+
+    #[test]
+    fn test_day_of_week_expr() {
+        test_expr!("dow(2026-10-18)", StackItem::String("Sunday".to_string()));
+    }
+}
@@ -0,0 +1,89 @@
+use anyhow::{anyhow, Result};
+use chrono::DateTime;
+use num_traits::ToPrimitive;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct FromEpochFunc {
+    name: String,
+    millis: bool,
+}
+
+impl FromEpochFunc {
+    pub fn new(name: &str, millis: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            millis,
+        }
+    }
+}
+
+impl Func for FromEpochFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let millis = self.millis;
+        execute_unary(state, |a| {
+            let Some(v) = a.to_f64() else {
+                return Err(anyhow!("expected a number but found {a}"));
+            };
+            let v = if millis { v } else { v * 1000.0 };
+            v.round()
+                .to_i64()
+                .and_then(DateTime::from_timestamp_millis)
+                .map(|v| StackItem::DateTime(v.naive_utc()))
+                .ok_or_else(|| anyhow!("{a} is out of range for a date"))
+        })
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.millis {
+            "Converts milliseconds since the Unix epoch to a date (UTC)"
+        } else {
+            "Converts seconds since the Unix epoch to a date (UTC)"
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_from_epoch() {
+        test_unary_func!(
+            StackItem::Integer(1_792_333_800.into(), 10),
+            "fromepoch",
+            StackItem::DateTime(
+                chrono::NaiveDate::from_ymd_opt(2026, 10, 18)
+                    .unwrap()
+                    .and_hms_opt(14, 30, 0)
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_epoch_millis_expr() {
+        test_expr!(
+            "fromepochms(86400000)",
+            StackItem::DateTime(
+                chrono::NaiveDate::from_ymd_opt(1970, 1, 2)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+            )
+        );
+    }
+}
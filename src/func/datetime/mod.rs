@@ -0,0 +1,86 @@
+use day_of_week::DayOfWeekFunc;
+use from_epoch::FromEpochFunc;
+use to_epoch::ToEpochFunc;
+
+use crate::state::RpnState;
+
+pub mod day_of_week;
+pub mod from_epoch;
+pub mod to_epoch;
+
+pub fn datetime_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(DayOfWeekFunc::new()));
+    state.register_function(Box::new(FromEpochFunc::new("fromepoch", false)));
+    state.register_function(Box::new(FromEpochFunc::new("fromepochms", true)));
+    state.register_function(Box::new(ToEpochFunc::new("toepoch", false)));
+    state.register_function(Box::new(ToEpochFunc::new("toepochms", true)));
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, TimeDelta};
+
+    use crate::{stack::datetime::CalendarDuration, state::RpnState, test_expr};
+
+    fn date(y: i32, m: u32, d: u32, h: u32, min: u32) -> chrono::NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_date_plus_days() {
+        test_expr!(
+            "2026-10-18 + 90d",
+            StackItem::DateTime(date(2027, 1, 16, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_date_plus_time() {
+        test_expr!(
+            "2026-10-18 + 14:30",
+            StackItem::DateTime(date(2026, 10, 18, 14, 30))
+        );
+    }
+
+    #[test]
+    fn test_date_plus_months() {
+        test_expr!(
+            "2024-01-31T08:00 + 1mo",
+            StackItem::DateTime(date(2024, 2, 29, 8, 0))
+        );
+    }
+
+    #[test]
+    fn test_date_difference() {
+        test_expr!(
+            "2026-10-18T14:30 - 2026-10-15",
+            StackItem::Duration(CalendarDuration::new(
+                0,
+                TimeDelta::days(3) + TimeDelta::hours(14) + TimeDelta::minutes(30)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_duration_arithmetic() {
+        test_expr!(
+            "3d4h * 2",
+            StackItem::Duration(CalendarDuration::new(
+                0,
+                TimeDelta::days(6) + TimeDelta::hours(8)
+            ))
+        );
+        test_expr!("1d / 6h", StackItem::Number(4.0, 10));
+    }
+
+    #[test]
+    fn test_date_invalid_operations() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("2026-10-18 + 2026-10-18").is_err());
+        assert!(state.push_str("2026-10-18 * 2").is_err());
+        assert!(state.push_str("2026-02-30").is_err());
+    }
+}
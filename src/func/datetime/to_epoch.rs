@@ -0,0 +1,86 @@
+use anyhow::{anyhow, Result};
+use num_bigint::BigInt;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ToEpochFunc {
+    name: String,
+    millis: bool,
+}
+
+impl ToEpochFunc {
+    pub fn new(name: &str, millis: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            millis,
+        }
+    }
+}
+
+impl Func for ToEpochFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let millis = self.millis;
+        execute_unary(state, |a| match a {
+            StackItem::DateTime(v) => {
+                let v = v.and_utc();
+                let v = if millis {
+                    v.timestamp_millis()
+                } else {
+                    v.timestamp()
+                };
+                Ok(StackItem::Integer(BigInt::from(v), 10))
+            }
+            _ => Err(anyhow!("expected a date but found {a}")),
+        })
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.millis {
+            "Converts a date (UTC) to the number of milliseconds since the Unix epoch"
+        } else {
+            "Converts a date (UTC) to the number of seconds since the Unix epoch"
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_to_epoch() {
+        test_unary_func!(
+            StackItem::DateTime(
+                chrono::NaiveDate::from_ymd_opt(2026, 10, 18)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+            ),
+            "toepoch",
+            StackItem::Integer(BigInt::from(1_792_281_600), 10)
+        );
+    }
+
+    #[test]
+    fn test_to_epoch_millis_expr() {
+        test_expr!(
+            "toepochms(1970-01-01T00:00:01.5)",
+            StackItem::Integer(BigInt::from(1500), 10)
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{number_mode::NumberMode, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::NumberModeUndoEvent;
+
+pub struct DecimalModeFunc {}
+
+impl DecimalModeFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for DecimalModeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous_mode = state.number_mode;
+        state.number_mode = NumberMode::Decimal;
+        Ok(Box::new(NumberModeUndoEvent {
+            previous_mode,
+            new_mode: NumberMode::Decimal,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "decimal"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The decimal function switches to decimal arithmetic, numbers with a decimal point are exact decimals and results are rounded to the working precision (prec)"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use crate::{
+        stack::item::StackItem,
+        state::{number_mode::NumberMode, RpnState},
+    };
+
+    fn decimal(s: &str) -> StackItem {
+        StackItem::Decimal(BigDecimal::from_str(s).unwrap())
+    }
+
+    #[test]
+    fn test_decimal_mode() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("decimal").unwrap();
+        assert_eq!(NumberMode::Decimal, state.number_mode);
+
+        state.undo().unwrap();
+        assert_eq!(NumberMode::Float, state.number_mode);
+
+        state.redo().unwrap();
+        assert_eq!(NumberMode::Decimal, state.number_mode);
+    }
+
+    #[test]
+    fn test_decimal_add() {
+        let mut state = RpnState::new().unwrap();
+        state.precision = 20;
+        state.push_str("decimal").unwrap();
+        state.push_str("0.1 + 0.2").unwrap();
+        assert_eq!(decimal("0.3"), *state.stack.peek(0).unwrap());
+    }
+
+    #[test]
+    fn test_decimal_divide() {
+        let mut state = RpnState::new().unwrap();
+        state.precision = 20;
+        state.push_str("decimal").unwrap();
+        state.push_str("2.0 / 3").unwrap();
+        assert_eq!(
+            decimal("0.66666666666666666667"),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decimal_sqrt() {
+        let mut state = RpnState::new().unwrap();
+        state.precision = 25;
+        state.push_str("decimal").unwrap();
+        state.push_str("sqrt(2)").unwrap();
+        assert_eq!(
+            decimal("1.414213562373095048801689"),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decimal_pow() {
+        let mut state = RpnState::new().unwrap();
+        state.precision = 20;
+        state.push_str("decimal").unwrap();
+        state.push_str("1.05 ^ 12").unwrap();
+        assert_eq!(
+            decimal("1.7958563260221291504"),
+            *state.stack.peek(0).unwrap()
+        );
+
+        state.push_str("2 ^ 0.5").unwrap();
+        assert_eq!(
+            decimal("1.4142135623730950488"),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decimal_not_padded() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("decimal").unwrap();
+        for (expr, expected) in [
+            ("sqrt(1.21)", "1.1"),
+            ("round(0.7, 0)", "1"),
+            ("0.7", "0.7"),
+            ("1199.5 + 0.5", "1200"),
+        ] {
+            state.push_str(expr).unwrap();
+            assert_eq!(expected, state.stack.peek(0).unwrap().to_string(), "{expr}");
+        }
+    }
+
+    #[test]
+    fn test_float_mode_literal() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("0.1").unwrap();
+        assert_eq!(StackItem::Number(0.1, 10), *state.stack.peek(0).unwrap());
+    }
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{number_mode::NumberMode, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::NumberModeUndoEvent;
+
+pub struct FloatModeFunc {}
+
+impl FloatModeFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for FloatModeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous_mode = state.number_mode;
+        state.number_mode = NumberMode::Float;
+        Ok(Box::new(NumberModeUndoEvent {
+            previous_mode,
+            new_mode: NumberMode::Float,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "float"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The float function switches back to binary floating point arithmetic"
+    }
+}
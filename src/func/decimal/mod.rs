@@ -0,0 +1,51 @@
+use anyhow::{anyhow, Result};
+use decimal_mode::DecimalModeFunc;
+use float_mode::FloatModeFunc;
+use precision::PrecisionFunc;
+
+use crate::{
+    state::{number_mode::NumberMode, RpnState},
+    undo_action::UndoEvent,
+};
+
+pub mod decimal_mode;
+pub mod float_mode;
+pub mod precision;
+
+pub fn decimal_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(DecimalModeFunc::new()));
+    state.register_function(Box::new(FloatModeFunc::new()));
+    state.register_function(Box::new(PrecisionFunc::new()));
+}
+
+#[derive(Debug)]
+pub struct NumberModeUndoEvent {
+    previous_mode: NumberMode,
+    new_mode: NumberMode,
+}
+
+impl UndoEvent for NumberModeUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.number_mode != self.new_mode {
+            return Err(anyhow!(
+                "expected current mode to be {:?} but was {:?}",
+                self.new_mode,
+                state.number_mode
+            ));
+        }
+        state.number_mode = self.previous_mode;
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.number_mode != self.previous_mode {
+            return Err(anyhow!(
+                "expected current mode to be {:?} but was {:?}",
+                self.previous_mode,
+                state.number_mode
+            ));
+        }
+        state.number_mode = self.new_mode;
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::{anyhow, Result};
+use num_traits::ToPrimitive;
+
+use crate::{func::Func, stack::item::StackItem, state::RpnState, undo_action::UndoEvent};
+
+pub struct PrecisionFunc {}
+
+impl PrecisionFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PrecisionFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let Some(a) = state.stack.peek(0).cloned() else {
+            return Err(anyhow!("Not enough arguments"));
+        };
+        let new_precision = match &a {
+            StackItem::Integer(v, _) => v.to_usize().filter(|v| *v > 0),
+            _ => None,
+        }
+        .ok_or_else(|| anyhow!("precision must be a positive integer, found {a}"))?;
+
+        state.stack.pop();
+        let previous_precision = state.precision;
+        state.precision = new_precision;
+        Ok(Box::new(PrecisionUndoEvent {
+            a,
+            previous_precision,
+            new_precision,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "prec"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The prec function sets the number of digits shown after the decimal point, and in decimal mode the number of significant digits results are computed to"
+    }
+}
+
+#[derive(Debug)]
+pub struct PrecisionUndoEvent {
+    a: StackItem,
+    previous_precision: usize,
+    new_precision: usize,
+}
+
+impl UndoEvent for PrecisionUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.precision != self.new_precision {
+            return Err(anyhow!(
+                "expected current precision to be {} but was {}",
+                self.new_precision,
+                state.precision
+            ));
+        }
+        state.precision = self.previous_precision;
+        state.stack.push(self.a.clone());
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.precision != self.previous_precision {
+            return Err(anyhow!(
+                "expected current precision to be {} but was {}",
+                self.previous_precision,
+                state.precision
+            ));
+        }
+        if state.stack.peek(0) != Some(&self.a) {
+            return Err(anyhow!(
+                "unexpected state for redo, expected item on the stack to be the same as argument 0"
+            ));
+        }
+        state.stack.pop();
+        state.precision = self.new_precision;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::RpnState;
+
+    #[test]
+    fn test_precision() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("30").unwrap();
+        state.push_str("prec").unwrap();
+        assert_eq!(30, state.precision);
+        assert_eq!(0, state.stack.len());
+
+        state.undo().unwrap();
+        assert_eq!(10, state.precision);
+        assert_eq!(1, state.stack.len());
+
+        state.redo().unwrap();
+        assert_eq!(30, state.precision);
+        assert_eq!(0, state.stack.len());
+    }
+
+    #[test]
+    fn test_precision_invalid() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("1.5").unwrap();
+        assert!(state.push_str("prec").is_err());
+        state.push_str("0").unwrap();
+        assert!(state.push_str("prec").is_err());
+        assert_eq!(2, state.stack.len());
+    }
+}
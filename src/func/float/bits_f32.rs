@@ -0,0 +1,57 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::bits_operand;
+
+pub struct BitsF32Func {}
+
+impl BitsF32Func {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for BitsF32Func {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let v = f32::from_bits(bits_operand(a, 32)? as u32);
+            Ok(StackItem::Number(v as f64, 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "bitsf32"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The bitsf32 function reads a 32-bit pattern, such as 0x3f800000, as an IEEE-754 float."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_bits_f32() {
+        test_expr!("bitsf32(0x3fc00000)", StackItem::Number(1.5, 10));
+        test_expr!("bitsf32(0x3dcccccd)", StackItem::Number(0.1f32 as f64, 10));
+    }
+
+    #[test]
+    fn test_bits_f32_too_wide() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("bitsf32(0x100000000)").is_err());
+        assert!(state.push_str("bitsf32(-1)").is_err());
+    }
+}
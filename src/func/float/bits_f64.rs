@@ -0,0 +1,69 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::bits_operand;
+
+pub struct BitsF64Func {}
+
+impl BitsF64Func {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for BitsF64Func {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            Ok(StackItem::Number(f64::from_bits(bits_operand(a, 64)?), 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "bitsf64"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The bitsf64 function reads a 64-bit pattern, such as 0x3ff0000000000000, as an IEEE-754 double."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState, test_expr};
+
+    #[test]
+    fn test_bits_f64() {
+        test_expr!("bitsf64(0x3ff8000000000000)", StackItem::Number(1.5, 10));
+        test_expr!(
+            "bitsf64(0xfff0000000000000)",
+            StackItem::Number(f64::NEG_INFINITY, 10)
+        );
+    }
+
+    #[test]
+    fn test_bits_f64_round_trip() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("bitsf64(f64bits(0.1))").unwrap();
+        assert_eq!(StackItem::Number(0.1, 10), *state.stack.peek(0).unwrap());
+        assert!(state.push_str("bitsf64(0x10000000000000000)").is_err());
+    }
+
+    #[test]
+    fn test_bits_f64_negative() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("-1").unwrap();
+        let err = state.push_str("bitsf64").unwrap_err();
+        assert_eq!("-1 does not fit in 64 bits", err.to_string());
+        assert_eq!(1, state.stack.len());
+    }
+}
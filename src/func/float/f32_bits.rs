@@ -0,0 +1,54 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::float_operand;
+
+pub struct F32BitsFunc {}
+
+impl F32BitsFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for F32BitsFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let v = float_operand(a)? as f32;
+            Ok(StackItem::Integer(v.to_bits().into(), 16))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "f32bits"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The f32bits function rounds a number to a 32-bit IEEE-754 float and returns its bit pattern, in hex."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_f32_bits() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("f32bits(0.1)").unwrap();
+        assert_eq!(
+            StackItem::Integer(0x3dcccccdu32.into(), 16),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+}
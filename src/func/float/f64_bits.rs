@@ -0,0 +1,55 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::float_operand;
+
+pub struct F64BitsFunc {}
+
+impl F64BitsFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for F64BitsFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            Ok(StackItem::Integer(float_operand(a)?.to_bits().into(), 16))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "f64bits"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The f64bits function returns the bit pattern of a number as a 64-bit IEEE-754 double, in hex."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_f64_bits() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("f64bits(-2.5)").unwrap();
+        assert_eq!(
+            StackItem::Integer(0xc004000000000000u64.into(), 16),
+            *state.stack.peek(0).unwrap()
+        );
+        state.undo().unwrap();
+        assert_eq!(0, state.stack.len());
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::{anyhow, Result};
+use bits_f32::BitsF32Func;
+use bits_f64::BitsF64Func;
+use f32_bits::F32BitsFunc;
+use f64_bits::F64BitsFunc;
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive};
+
+use crate::{stack::item::StackItem, state::RpnState};
+
+pub mod bits_f32;
+pub mod bits_f64;
+pub mod f32_bits;
+pub mod f64_bits;
+
+pub fn float_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(BitsF32Func::new()));
+    state.register_function(Box::new(BitsF64Func::new()));
+    state.register_function(Box::new(F32BitsFunc::new()));
+    state.register_function(Box::new(F64BitsFunc::new()));
+}
+
+fn float_operand(item: &StackItem) -> Result<f64> {
+    match item {
+        StackItem::Complex(_) => Err(anyhow!("expected a real number but found {item}")),
+        _ => item
+            .to_f64()
+            .ok_or_else(|| anyhow!("expected a real number but found {item}")),
+    }
+}
+
+/// Returns a bit pattern `width` bits wide. Patterns are unsigned, so negative values
+/// are rejected like values that are too wide.
+fn bits_operand(item: &StackItem, width: u32) -> Result<u64> {
+    let v = match item {
+        StackItem::Integer(v, _) => Some(v.clone()),
+        StackItem::Number(v, _) if item.is_integer() => BigInt::from_f64(*v),
+        _ => None,
+    }
+    .ok_or_else(|| anyhow!("expected a bit pattern such as 0x3ff0000000000000 but found {item}"))?;
+    if v.is_negative() || v >= BigInt::one() << width {
+        return Err(anyhow!("{v} does not fit in {width} bits"));
+    }
+    Ok(v.to_u64().unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::bits_operand;
+    use crate::stack::item::StackItem;
+
+    #[test]
+    fn test_bits_operand() {
+        let int = |v: i64| StackItem::Integer(v.into(), 16);
+        assert_eq!(0xff, bits_operand(&int(0xff), 8).unwrap());
+        assert!(bits_operand(&int(256), 8).is_err());
+        assert_eq!(
+            "-1 does not fit in 8 bits",
+            bits_operand(&int(-1), 8).unwrap_err().to_string()
+        );
+        assert!(bits_operand(&StackItem::Number(1.5, 10), 8).is_err());
+    }
+}
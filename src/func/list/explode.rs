@@ -0,0 +1,73 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::Func,
+    state::RpnState,
+    undo_action::{replace::ReplaceUndoEvent, UndoEvent},
+};
+
+use super::list_items;
+
+pub struct ExplodeFunc {}
+
+impl ExplodeFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ExplodeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let list = state
+            .stack
+            .peek(0)
+            .cloned()
+            .ok_or_else(|| anyhow!("Not enough arguments"))?;
+        let items = list_items(&list)?.clone();
+
+        state.stack.pop();
+        for item in &items {
+            state.stack.push(item.clone());
+        }
+        Ok(Box::new(ReplaceUndoEvent::new(vec![list], items)))
+    }
+
+    fn name(&self) -> &str {
+        "explode"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The explode function replaces a list with its items, pushed in order."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_explode() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("{1 'a' 3}").unwrap();
+        state.push_str("explode").unwrap();
+        assert_eq!(3, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(3.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+        assert_eq!(
+            &StackItem::String("a".to_string()),
+            state.stack.peek(1).unwrap()
+        );
+
+        state.undo().unwrap();
+        assert_eq!(1, state.stack.len());
+
+        state.redo().unwrap();
+        assert_eq!(3, state.stack.len());
+    }
+}
@@ -0,0 +1,85 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    expr::run::evaluate,
+    func::{execute_binary_with_state, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{expression, list_items};
+
+pub struct FilterFunc {}
+
+impl FilterFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for FilterFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary_with_state(state, |list, f, state| {
+            let f = expression(f)?;
+            let mut items = vec![];
+            for x in list_items(list)? {
+                let keep = evaluate(f, std::slice::from_ref(x), &[("x", x)], state)?;
+                if keep.to_f64().is_none() {
+                    return Err(anyhow!("filter expects a number from {f} but found {keep}"));
+                }
+                if !keep.is_zero() {
+                    items.push(x.clone());
+                }
+            }
+            Ok(StackItem::List(items))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "filter"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The filter function keeps the items of a list for which a function name or an expression of x ('x % 2') is not zero."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_filter() {
+        test_expr!(
+            "filter({1 2 3 4 5}, 'x % 2')",
+            StackItem::List(vec![
+                StackItem::Integer(1.into(), 10),
+                StackItem::Integer(3.into(), 10),
+                StackItem::Integer(5.into(), 10)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_filter_comparison() {
+        test_expr!(
+            "filter({5 -2 8 3}, 'x >= 3')",
+            StackItem::List(vec![
+                StackItem::Integer(5.into(), 10),
+                StackItem::Integer(8.into(), 10),
+                StackItem::Integer(3.into(), 10)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_filter_not_a_number() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("filter({1 2}, ''a'')").is_err());
+    }
+}
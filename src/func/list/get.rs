@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Result};
+use num_traits::ToPrimitive;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct GetFunc {}
+
+impl GetFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for GetFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, index| {
+            let items = match a {
+                StackItem::List(items) | StackItem::Vector(items) => items,
+                _ => return Err(anyhow!("expected a list or vector but found {a}")),
+            };
+            let i = match index {
+                StackItem::Integer(i, _) => i.to_usize(),
+                _ => None,
+            };
+            i.and_then(|i| items.get(i)).cloned().ok_or_else(|| {
+                anyhow!(
+                    "index {index} is out of range, expected 0 to {}",
+                    items.len() as i64 - 1
+                )
+            })
+        })
+    }
+
+    fn name(&self) -> &str {
+        "get"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The get function gives the item at a zero based index in a list or vector."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_get() {
+        test_expr!("get({1 'a' 3}, 1)", StackItem::String("a".to_string()));
+        test_expr!("get([4, 5], 0)", StackItem::Integer(4.into(), 10));
+    }
+
+    #[test]
+    fn test_get_out_of_range() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("get({1 2}, 2)").is_err());
+        assert!(state.push_str("get({1 2}, -1)").is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct LenFunc {}
+
+impl LenFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for LenFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| match a {
+            StackItem::List(items) | StackItem::Vector(items) => {
+                Ok(StackItem::Integer(items.len().into(), 10))
+            }
+            _ => Err(anyhow!("expected a list or vector but found {a}")),
+        })
+    }
+
+    fn name(&self) -> &str {
+        "len"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The len function gives the number of items in a list or vector."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_expr;
+
+    #[test]
+    fn test_len() {
+        test_expr!("len({1 'a' 3})", StackItem::Integer(3.into(), 10));
+        test_expr!("len({})", StackItem::Integer(0.into(), 10));
+        test_expr!("len([1, 2])", StackItem::Integer(2.into(), 10));
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+
+use crate::{
+    expr::run::evaluate,
+    func::{execute_binary_with_state, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{expression, list_items};
+
+pub struct MapFunc {}
+
+impl MapFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for MapFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary_with_state(state, |list, f, state| {
+            let f = expression(f)?;
+            let items = list_items(list)?
+                .iter()
+                .map(|x| evaluate(f, std::slice::from_ref(x), &[("x", x)], state))
+                .collect::<Result<_>>()?;
+            Ok(StackItem::List(items))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "map"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The map function applies a function name ('sqrt') or an expression of x ('x * 2') to every item of a list."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState, test_expr};
+
+    #[test]
+    fn test_map_function_name() {
+        test_expr!(
+            "map({1 4 9}, 'sqrt')",
+            StackItem::List(vec![
+                StackItem::Integer(1.into(), 10),
+                StackItem::Integer(2.into(), 10),
+                StackItem::Integer(3.into(), 10)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_map_expression() {
+        test_expr!(
+            "map({1 2}, 'x * 2 + 1')",
+            StackItem::List(vec![
+                StackItem::Integer(3.into(), 10),
+                StackItem::Integer(5.into(), 10)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_map_keeps_variables() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("5").unwrap();
+        state.push_str("'x'").unwrap();
+        state.push_str("store").unwrap();
+        state.push_str("map({1 2}, 'x')").unwrap();
+        assert_eq!(
+            Some(&StackItem::Integer(5.into(), 10)),
+            state.variables.get("x")
+        );
+        assert_eq!(1, state.stack.len());
+    }
+
+    #[test]
+    fn test_map_invalid_expression() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("map({1 2}, 'nosuchfunc')").is_err());
+        assert!(state.push_str("map({1 2}, 3)").is_err());
+    }
+}
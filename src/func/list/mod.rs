@@ -0,0 +1,103 @@
+use anyhow::{anyhow, Result};
+use explode::ExplodeFunc;
+use filter::FilterFunc;
+use get::GetFunc;
+use len::LenFunc;
+use map::MapFunc;
+use reduce::ReduceFunc;
+use sort::SortFunc;
+use to_list::ToListFunc;
+
+use crate::{stack::item::StackItem, state::RpnState};
+
+pub mod explode;
+pub mod filter;
+pub mod get;
+pub mod len;
+pub mod map;
+pub mod reduce;
+pub mod sort;
+pub mod to_list;
+
+pub fn list_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(ExplodeFunc::new()));
+    state.register_function(Box::new(FilterFunc::new()));
+    state.register_function(Box::new(GetFunc::new()));
+    state.register_function(Box::new(LenFunc::new()));
+    state.register_function(Box::new(MapFunc::new()));
+    state.register_function(Box::new(ReduceFunc::new()));
+    state.register_function(Box::new(SortFunc::new()));
+    state.register_function(Box::new(ToListFunc::new()));
+}
+
+fn list_items(item: &StackItem) -> Result<&Vec<StackItem>> {
+    match item {
+        StackItem::List(items) => Ok(items),
+        _ => Err(anyhow!("expected a list but found {item}")),
+    }
+}
+
+/// Returns the function name or quoted expression given as the last argument.
+fn expression(item: &StackItem) -> Result<&str> {
+    match item {
+        StackItem::String(s) => Ok(s),
+        _ => Err(anyhow!(
+            "expected a function name or expression such as 'sqrt' or 'x * 2' but found {item}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState, test_expr};
+
+    fn ints(v: &[i64]) -> Vec<StackItem> {
+        v.iter()
+            .map(|v| StackItem::Integer((*v).into(), 10))
+            .collect()
+    }
+
+    #[test]
+    fn test_list_literal() {
+        test_expr!("{1 2 3}", StackItem::List(ints(&[1, 2, 3])));
+        test_expr!("{1, -2, 3}", StackItem::List(ints(&[1, -2, 3])));
+        test_expr!("{1 -3 2}", StackItem::List(ints(&[1, -3, 2])));
+        test_expr!("{1 - 3 2}", StackItem::List(ints(&[1, -3, 2])));
+        test_expr!("{1, -3 + 1}", StackItem::List(ints(&[1, -2])));
+        test_expr!("{(1 - 3) 2}", StackItem::List(ints(&[-2, 2])));
+        test_expr!(
+            "{{1 -1}, 2}",
+            StackItem::List(vec![
+                StackItem::List(ints(&[1, -1])),
+                StackItem::Integer(2.into(), 10),
+            ])
+        );
+        test_expr!("{}", StackItem::List(vec![]));
+        test_expr!(
+            "{1 'a' [1, 2] {}}",
+            StackItem::List(vec![
+                StackItem::Integer(1.into(), 10),
+                StackItem::String("a".to_string()),
+                StackItem::Vector(ints(&[1, 2])),
+                StackItem::List(vec![])
+            ])
+        );
+    }
+
+    #[test]
+    fn test_list_mixed_separators() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("{1 2, 3}").is_err());
+    }
+
+    #[test]
+    fn test_list_concat() {
+        test_expr!("{1 2} + {3}", StackItem::List(ints(&[1, 2, 3])));
+    }
+
+    #[test]
+    fn test_unclosed_list() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("{1 2").is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    expr::run::evaluate,
+    func::{execute_binary_with_state, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{expression, list_items};
+
+pub struct ReduceFunc {}
+
+impl ReduceFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ReduceFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary_with_state(state, |list, f, state| {
+            let f = expression(f)?;
+            let mut items = list_items(list)?.iter();
+            let mut acc = items
+                .next()
+                .ok_or_else(|| anyhow!("cannot reduce an empty list"))?
+                .clone();
+            for x in items {
+                acc = evaluate(
+                    f,
+                    &[acc.clone(), x.clone()],
+                    &[("acc", &acc), ("x", x)],
+                    state,
+                )?;
+            }
+            Ok(acc)
+        })
+    }
+
+    fn name(&self) -> &str {
+        "reduce"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The reduce function combines the items of a list from the left using a binary function name ('add') or an expression of acc and x ('acc * x')."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_reduce() {
+        test_expr!(
+            "reduce({1 2 3 4}, 'add')",
+            StackItem::Integer(10.into(), 10)
+        );
+        test_expr!(
+            "reduce({1 2 3 4}, 'acc * 10 + x')",
+            StackItem::Integer(1234.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_reduce_empty() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("reduce({}, 'add')").is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::list_items;
+
+pub struct SortFunc {}
+
+impl SortFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for SortFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |list| {
+            let mut items = list_items(list)?.clone();
+            for pair in items.windows(2) {
+                pair[0].compare(&pair[1]).ok_or_else(|| {
+                    anyhow!("cannot sort a list containing {} and {}", pair[0], pair[1])
+                })?;
+            }
+            items.sort_by(|a, b| a.compare(b).unwrap_or(Ordering::Equal));
+            Ok(StackItem::List(items))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The sort function sorts a list of real numbers or a list of strings in ascending order."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_sort_numbers() {
+        test_expr!(
+            "sort({3, 1/2, -1, 2.5})",
+            StackItem::List(vec![
+                StackItem::Integer((-1).into(), 10),
+                StackItem::Rational(num_rational::BigRational::new(1.into(), 2.into())),
+                StackItem::Number(2.5, 10),
+                StackItem::Integer(3.into(), 10)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sort_strings() {
+        test_expr!(
+            "sort({'b' 'c' 'a'})",
+            StackItem::List(vec![
+                StackItem::String("a".to_string()),
+                StackItem::String("b".to_string()),
+                StackItem::String("c".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sort_mixed() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("sort({1 'a'})").is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use anyhow::{anyhow, Result};
+use num_traits::ToPrimitive;
+
+use crate::{
+    func::Func,
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::{replace::ReplaceUndoEvent, UndoEvent},
+};
+
+pub struct ToListFunc {}
+
+impl ToListFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ToListFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let n = match state.stack.peek(0) {
+            Some(StackItem::Integer(n, _)) => n
+                .to_usize()
+                .ok_or_else(|| anyhow!("count must be a non-negative integer"))?,
+            Some(n) => return Err(anyhow!("count must be an integer but found {n}")),
+            None => return Err(anyhow!("Not enough arguments")),
+        };
+        if state.stack.len() < n + 1 {
+            return Err(anyhow!("Not enough arguments"));
+        }
+
+        let mut removed = state.stack.pop_n(n + 1)?;
+        removed.reverse();
+        let list = StackItem::List(removed[..n].to_vec());
+        state.stack.push(list.clone());
+        Ok(Box::new(ReplaceUndoEvent::new(removed, vec![list])))
+    }
+
+    fn name(&self) -> &str {
+        "tolist"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The tolist function pops a count n and collects the top n stack items into a list."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_to_list() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("1").unwrap();
+        state.push_str("2").unwrap();
+        state.push_str("3").unwrap();
+        state.push_str("2").unwrap();
+        state.push_str("tolist").unwrap();
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            &StackItem::List(vec![
+                StackItem::Integer(2.into(), 10),
+                StackItem::Integer(3.into(), 10)
+            ]),
+            state.stack.peek(0).unwrap()
+        );
+
+        state.undo().unwrap();
+        assert_eq!(4, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(2.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+
+        state.redo().unwrap();
+        assert_eq!(2, state.stack.len());
+    }
+
+    #[test]
+    fn test_to_list_not_enough_items() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("1").unwrap();
+        state.push_str("3").unwrap();
+        assert!(state.push_str("tolist").is_err());
+        assert_eq!(2, state.stack.len());
+    }
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct AlogFunc {}
+
+impl AlogFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for AlogFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| StackItem::Integer(10.into(), 10).pow(a))
+    }
+
+    fn name(&self) -> &str {
+        "alog"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["antilog", "pow10"]
+    }
+
+    fn description(&self) -> &str {
+        "The antilogarithm (alog) function returns 10 raised to the power of a number (10^x)."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_alog() {
+        test_unary_func!(
+            StackItem::Integer(3.into(), 10),
+            "alog",
+            StackItem::Integer(1000.into(), 10)
+        );
+        test_expr!("alog(0.5)", StackItem::Number(10.0_f64.sqrt(), 10));
+    }
+}
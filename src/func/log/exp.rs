@@ -0,0 +1,57 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ExpFunc {}
+
+impl ExpFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ExpFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.exp())
+    }
+
+    fn name(&self) -> &str {
+        "exp"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["antiln"]
+    }
+
+    fn description(&self) -> &str {
+        "The exponential (exp) function returns e raised to the power of a number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_exp() {
+        test_unary_func!(
+            StackItem::Integer(2.into(), 10),
+            "exp",
+            StackItem::Number(2.0_f64.exp(), 10)
+        );
+    }
+
+    #[test]
+    fn test_exp_complex() {
+        test_expr!(
+            "exp(2i)",
+            StackItem::Complex(Complex64::new(0.0, 2.0).exp())
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct Expm1Func {}
+
+impl Expm1Func {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for Expm1Func {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.exp_m1())
+    }
+
+    fn name(&self) -> &str {
+        "expm1"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["exp_m1"]
+    }
+
+    fn description(&self) -> &str {
+        "The expm1 function returns e raised to the power of a number minus one, accurate for values near zero."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_unary_func;
+
+    #[test]
+    fn test_expm1() {
+        test_unary_func!(
+            StackItem::Number(1e-10, 10),
+            "expm1",
+            StackItem::Number(1e-10_f64.exp_m1(), 10)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct LnFunc {}
+
+impl LnFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for LnFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.ln())
+    }
+
+    fn name(&self) -> &str {
+        "ln"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["loge"]
+    }
+
+    fn description(&self) -> &str {
+        "The natural logarithm (ln) function returns the logarithm to base e of a number, a complex number for negative values."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+
+    use crate::{stack::item::StackItem, state::RpnState, test_expr, test_unary_func};
+
+    #[test]
+    fn test_ln() {
+        test_unary_func!(
+            StackItem::Integer(5.into(), 10),
+            "ln",
+            StackItem::Number(5.0_f64.ln(), 10)
+        );
+    }
+
+    #[test]
+    fn test_ln_negative() {
+        test_expr!(
+            "ln(-1)",
+            StackItem::Complex(Complex64::new(0.0, std::f64::consts::PI))
+        );
+    }
+
+    #[test]
+    fn test_ln_zero() {
+        test_expr!("ln(0)", StackItem::Undefined);
+    }
+
+    #[test]
+    fn test_ln_string() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("'a'").unwrap();
+        state.push_str("ln").unwrap();
+        assert_eq!(StackItem::Undefined, *state.stack.peek(0).unwrap());
+    }
+}
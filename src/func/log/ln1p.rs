@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct Ln1pFunc {}
+
+impl Ln1pFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for Ln1pFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.ln_1p())
+    }
+
+    fn name(&self) -> &str {
+        "ln1p"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["log1p", "ln_1p"]
+    }
+
+    fn description(&self) -> &str {
+        "The ln1p function returns the natural logarithm of one plus a number, accurate for values near zero."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_ln1p() {
+        test_unary_func!(
+            StackItem::Number(1e-10, 10),
+            "ln1p",
+            StackItem::Number(1e-10_f64.ln_1p(), 10)
+        );
+    }
+
+    #[test]
+    fn test_ln1p_domain() {
+        test_expr!("ln1p(-1)", StackItem::Undefined);
+        test_expr!(
+            "ln1p(-2)",
+            StackItem::Complex(Complex64::new(0.0, std::f64::consts::PI))
+        );
+    }
+}
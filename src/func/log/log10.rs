@@ -0,0 +1,48 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct Log10Func {}
+
+impl Log10Func {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for Log10Func {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.log(10.0))
+    }
+
+    fn name(&self) -> &str {
+        "log10"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["log"]
+    }
+
+    fn description(&self) -> &str {
+        "The common logarithm (log10) function returns the logarithm to base 10 of a number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_log10() {
+        test_unary_func!(
+            StackItem::Integer(1000.into(), 10),
+            "log10",
+            StackItem::Number(3.0, 10)
+        );
+        test_expr!("log(0.01)", StackItem::Number(-2.0, 10));
+    }
+}
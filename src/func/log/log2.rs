@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct Log2Func {}
+
+impl Log2Func {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for Log2Func {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.log(2.0))
+    }
+
+    fn name(&self) -> &str {
+        "log2"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["lb"]
+    }
+
+    fn description(&self) -> &str {
+        "The binary logarithm (log2) function returns the logarithm to base 2 of a number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_unary_func;
+
+    #[test]
+    fn test_log2() {
+        test_unary_func!(
+            StackItem::Integer(1024.into(), 10),
+            "log2",
+            StackItem::Number(10.0, 10)
+        );
+    }
+}
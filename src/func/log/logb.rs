@@ -0,0 +1,61 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct LogbFunc {}
+
+impl LogbFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for LogbFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| match b.to_f64() {
+            Some(base) => a.log(base),
+            None => Err(anyhow!(
+                "logarithm base must be a real number but found {b}"
+            )),
+        })
+    }
+
+    fn name(&self) -> &str {
+        "logb"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["logn"]
+    }
+
+    fn description(&self) -> &str {
+        "The logb function returns the logarithm of a number to the base given by the second operand."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_logb() {
+        test_binary_func!(
+            StackItem::Integer(81.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "logb",
+            StackItem::Number(81.0_f64.log(3.0), 10)
+        );
+        test_expr!("logb(8, 2)", StackItem::Number(3.0, 10));
+    }
+
+    #[test]
+    fn test_logb_invalid_base() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("logb(8, 1)").is_err());
+        assert!(state.push_str("logb(8, -2)").is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use alog::AlogFunc;
+use exp::ExpFunc;
+use expm1::Expm1Func;
+use ln::LnFunc;
+use ln1p::Ln1pFunc;
+use log10::Log10Func;
+use log2::Log2Func;
+use logb::LogbFunc;
+
+use crate::state::RpnState;
+
+pub mod alog;
+pub mod exp;
+pub mod expm1;
+pub mod ln;
+pub mod ln1p;
+pub mod log10;
+pub mod log2;
+pub mod logb;
+
+pub fn log_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(AlogFunc::new()));
+    state.register_function(Box::new(ExpFunc::new()));
+    state.register_function(Box::new(Expm1Func::new()));
+    state.register_function(Box::new(LnFunc::new()));
+    state.register_function(Box::new(Ln1pFunc::new()));
+    state.register_function(Box::new(Log10Func::new()));
+    state.register_function(Box::new(Log2Func::new()));
+    state.register_function(Box::new(LogbFunc::new()));
+}
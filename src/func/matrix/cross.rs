@@ -0,0 +1,56 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::matrix,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct CrossFunc {}
+
+impl CrossFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for CrossFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, matrix::cross)
+    }
+
+    fn name(&self) -> &str {
+        "cross"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The cross function calculates the cross product of two vectors of length 3."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, test_expr};
+
+    #[test]
+    fn test_cross() {
+        let vector = |v: [i64; 3]| {
+            v.iter()
+                .map(|v| StackItem::Integer((*v).into(), 10))
+                .collect()
+        };
+        test_expr!(
+            "cross([1, 0, 0], [0, 1, 0])",
+            StackItem::Vector(vector([0, 0, 1]))
+        );
+        test_expr!(
+            "cross([1, 2, 3], [4, 5, 6])",
+            StackItem::Vector(vector([-3, 6, -3]))
+        );
+    }
+}
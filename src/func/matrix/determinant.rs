@@ -0,0 +1,55 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::matrix,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct DeterminantFunc {}
+
+impl DeterminantFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for DeterminantFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, matrix::determinant)
+    }
+
+    fn name(&self) -> &str {
+        "det"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The det function calculates the determinant of a square matrix."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_determinant() {
+        test_expr!("det([[1, 2], [3, 4]])", StackItem::Integer((-2).into(), 10));
+        test_expr!(
+            "det([[2, 0, 1], [1, 3, 2], [1, 1, 2]])",
+            StackItem::Integer(6.into(), 10)
+        );
+        test_expr!("det([[1, 2], [2, 4]])", StackItem::Integer(0.into(), 10));
+    }
+
+    #[test]
+    fn test_determinant_not_square() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("det([[1, 2, 3], [4, 5, 6]])").is_err());
+    }
+}
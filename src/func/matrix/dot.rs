@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::matrix,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct DotFunc {}
+
+impl DotFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for DotFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, matrix::dot)
+    }
+
+    fn name(&self) -> &str {
+        "dot"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The dot function calculates the dot product of two vectors of the same length."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_expr;
+
+    #[test]
+    fn test_dot() {
+        test_expr!(
+            "dot([1, 2, 3], [4, 5, 6])",
+            StackItem::Integer(32.into(), 10)
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::matrix,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct LinsolveFunc {}
+
+impl LinsolveFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for LinsolveFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, matrix::solve)
+    }
+
+    fn name(&self) -> &str {
+        "linsolve"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The linsolve function solves the linear system A x = b given the matrix A and the vector (or matrix) b."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{stack::item::StackItem, state::RpnState, test_expr};
+
+    #[test]
+    fn test_linsolve() {
+        let fifths = |n: i64| StackItem::Rational(BigRational::new(n.into(), 5.into()));
+        test_expr!(
+            "linsolve([[2, 1], [1, 3]], [3, 5])",
+            StackItem::Vector(vec![fifths(4), fifths(7)])
+        );
+    }
+
+    #[test]
+    fn test_linsolve_singular() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state
+            .push_str("linsolve([[1, 2], [2, 4]], [1, 1])")
+            .is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use cross::CrossFunc;
+use determinant::DeterminantFunc;
+use dot::DotFunc;
+use linsolve::LinsolveFunc;
+use transpose::TransposeFunc;
+
+use crate::state::RpnState;
+
+pub mod cross;
+pub mod determinant;
+pub mod dot;
+pub mod linsolve;
+pub mod transpose;
+
+pub fn matrix_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(CrossFunc::new()));
+    state.register_function(Box::new(DeterminantFunc::new()));
+    state.register_function(Box::new(DotFunc::new()));
+    state.register_function(Box::new(LinsolveFunc::new()));
+    state.register_function(Box::new(TransposeFunc::new()));
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+
+    use crate::{
+        stack::item::{StackItem, StackItemToStringOpts},
+        state::RpnState,
+        test_expr,
+    };
+
+    fn int(v: i64) -> StackItem {
+        StackItem::Integer(v.into(), 10)
+    }
+
+    fn ints(v: &[i64]) -> Vec<StackItem> {
+        v.iter().map(|v| int(*v)).collect()
+    }
+
+    fn rows(rows: &[&[i64]]) -> Vec<Vec<StackItem>> {
+        rows.iter().map(|row| ints(row)).collect()
+    }
+
+    #[test]
+    fn test_vector_literal() {
+        test_expr!("[1, 2, 3]", StackItem::Vector(ints(&[1, 2, 3])));
+        test_expr!("[1 + 1, 2 * 3]", StackItem::Vector(ints(&[2, 6])));
+    }
+
+    #[test]
+    fn test_matrix_literal() {
+        test_expr!(
+            "[[1, 2], [3, 4]]",
+            StackItem::Matrix(rows(&[&[1, 2], &[3, 4]]))
+        );
+    }
+
+    #[test]
+    fn test_invalid_literals() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("[]").is_err());
+        assert!(state.push_str("[[1, 2], [3]]").is_err());
+        assert!(state.push_str("[[1, 2], 3]").is_err());
+    }
+
+    #[test]
+    fn test_element_wise() {
+        test_expr!("[1, 2] + [3, 4]", StackItem::Vector(ints(&[4, 6])));
+        test_expr!(
+            "[[5, 6], [7, 8]] - [[1, 2], [3, 4]]",
+            StackItem::Matrix(rows(&[&[4, 4], &[4, 4]]))
+        );
+        test_expr!("2 * [1, 2]", StackItem::Vector(ints(&[2, 4])));
+        test_expr!("[2, 4] / 2", StackItem::Vector(ints(&[1, 2])));
+        test_expr!("-[1, 2]", StackItem::Vector(ints(&[-1, -2])));
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let mut state = RpnState::new().unwrap();
+        let e = state.push_str("[1, 2] + [1, 2, 3]").unwrap_err();
+        assert_eq!(
+            "cannot add a vector of length 2 and a vector of length 3",
+            e.to_string()
+        );
+        let e = state.push_str("[[1, 2, 3]] * [[1, 2, 3]]").unwrap_err();
+        assert_eq!(
+            "cannot multiply a 1x3 matrix by a 1x3 matrix, columns must match rows",
+            e.to_string()
+        );
+    }
+
+    #[test]
+    fn test_matrix_multiply() {
+        test_expr!(
+            "[[1, 2], [3, 4]] * [[5, 6], [7, 8]]",
+            StackItem::Matrix(rows(&[&[19, 22], &[43, 50]]))
+        );
+        test_expr!(
+            "[[1, 2], [3, 4]] * [1, 1]",
+            StackItem::Vector(ints(&[3, 7]))
+        );
+        test_expr!(
+            "[1, 1] * [[1, 2], [3, 4]]",
+            StackItem::Vector(ints(&[4, 6]))
+        );
+    }
+
+    #[test]
+    fn test_matrix_inverse() {
+        let half = |n: i64| StackItem::Rational(BigRational::new(BigInt::from(n), BigInt::from(2)));
+        test_expr!(
+            "inv([[1, 2], [3, 4]])",
+            StackItem::Matrix(vec![vec![int(-2), int(1)], vec![half(3), half(-1)]])
+        );
+        test_expr!(
+            "[[1, 2], [3, 4]] / [[1, 2], [3, 4]]",
+            StackItem::Matrix(rows(&[&[1, 0], &[0, 1]]))
+        );
+
+        let mut state = RpnState::new().unwrap();
+        let e = state.push_str("inv([[1, 2], [2, 4]])").unwrap_err();
+        assert_eq!("matrix is singular", e.to_string());
+    }
+
+    #[test]
+    fn test_matrix_display() {
+        let state = RpnState::new().unwrap();
+        let opts = StackItemToStringOpts {
+            base: None,
+            precision: None,
+            rational_display: None,
+            left_pad_with_zeros: true,
+            include_base_prefix: true,
+        };
+        assert_eq!(
+            "[   1  20 ]\n[ 300   4 ]",
+            StackItem::Matrix(rows(&[&[1, 20], &[300, 4]])).to_string_opts(&opts, &state)
+        );
+        assert_eq!(
+            "[[1, 2], [3, 4]]",
+            StackItem::Matrix(rows(&[&[1, 2], &[3, 4]])).to_string()
+        );
+    }
+}
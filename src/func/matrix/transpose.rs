@@ -0,0 +1,56 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::matrix,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct TransposeFunc {}
+
+impl TransposeFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for TransposeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, matrix::transpose)
+    }
+
+    fn name(&self) -> &str {
+        "transpose"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["trn"]
+    }
+
+    fn description(&self) -> &str {
+        "The transpose function swaps the rows and columns of a matrix, a vector becomes a single column matrix."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, test_expr};
+
+    #[test]
+    fn test_transpose() {
+        let int = |v: i64| StackItem::Integer(v.into(), 10);
+        test_expr!(
+            "transpose([[1, 2, 3], [4, 5, 6]])",
+            StackItem::Matrix(vec![
+                vec![int(1), int(4)],
+                vec![int(2), int(5)],
+                vec![int(3), int(6)]
+            ])
+        );
+        test_expr!(
+            "trn([1, 2])",
+            StackItem::Matrix(vec![vec![int(1)], vec![int(2)]])
+        );
+    }
+}
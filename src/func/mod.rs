@@ -1,21 +1,93 @@
 use anyhow::{anyhow, Result};
 use basic::basic_register_functions;
+use bitwise::bitwise_register_functions;
+use combinatorics::combinatorics_register_functions;
+use compare::compare_register_functions;
+use complex::complex_register_functions;
+use datetime::datetime_register_functions;
+use decimal::decimal_register_functions;
+use float::float_register_functions;
+use list::list_register_functions;
+use log::log_register_functions;
+use matrix::matrix_register_functions;
+use numeric::numeric_register_functions;
+use numtheory::numtheory_register_functions;
+use percent::percent_register_functions;
+use polynomial::polynomial_register_functions;
+use programmer::programmer_register_functions;
+use radix::radix_register_functions;
+use random::random_register_functions;
+use rational::rational_register_functions;
+use rounding::rounding_register_functions;
+use sexagesimal::sexagesimal_register_functions;
+use stats::stats_register_functions;
 use trig::trig_register_functions;
+use tvm::tvm_register_functions;
+use unit::unit_register_functions;
 use variable::variable_register_functions;
 
 use crate::{
     stack::item::StackItem,
     state::RpnState,
-    undo_action::{binary::BinaryFuncUndoEvent, unary::UnaryFuncUndoEvent, UndoEvent},
+    undo_action::{
+        binary::BinaryFuncUndoEvent, replace::ReplaceUndoEvent, unary::UnaryFuncUndoEvent,
+        UndoEvent,
+    },
 };
 
 pub mod basic;
+pub mod bitwise;
+pub mod combinatorics;
+pub mod compare;
+pub mod complex;
+pub mod datetime;
+pub mod decimal;
+pub mod float;
+pub mod list;
+pub mod log;
+pub mod matrix;
+pub mod numeric;
+pub mod numtheory;
+pub mod percent;
+pub mod polynomial;
+pub mod programmer;
+pub mod radix;
+pub mod random;
+pub mod rational;
+pub mod rounding;
+pub mod sexagesimal;
+pub mod stats;
 pub mod trig;
+pub mod tvm;
+pub mod unit;
 pub mod variable;
 
 pub fn register_functions(state: &mut RpnState) {
     basic_register_functions(state);
+    bitwise_register_functions(state);
+    combinatorics_register_functions(state);
+    compare_register_functions(state);
+    complex_register_functions(state);
+    datetime_register_functions(state);
+    decimal_register_functions(state);
+    float_register_functions(state);
+    list_register_functions(state);
+    log_register_functions(state);
+    matrix_register_functions(state);
+    numeric_register_functions(state);
+    numtheory_register_functions(state);
+    percent_register_functions(state);
+    polynomial_register_functions(state);
+    radix_register_functions(state);
+    random_register_functions(state);
+    programmer_register_functions(state);
+    rational_register_functions(state);
+    rounding_register_functions(state);
+    sexagesimal_register_functions(state);
+    stats_register_functions(state);
     trig_register_functions(state);
+    tvm_register_functions(state);
+    unit_register_functions(state);
     variable_register_functions(state);
 }
 
@@ -38,12 +110,72 @@ where
     }
     let a = state.stack.peek(1).unwrap().clone();
     let b = state.stack.peek(0).unwrap().clone();
-    let result = calc(&a, &b)?;
+    let result = state.apply_modes_in_base(calc(&a, &b)?, b.display_base());
     state.stack.pop_n(2)?;
     state.stack.push(result.clone());
     Ok(Box::new(BinaryFuncUndoEvent::new(a, b, result)))
 }
 
+/// Like `execute_binary` for calculations that need the state, such as those that
+/// evaluate an expression given as an argument.
+pub(super) fn execute_binary_with_state<F>(
+    state: &mut RpnState,
+    calc: F,
+) -> Result<Box<dyn UndoEvent>>
+where
+    F: FnOnce(&StackItem, &StackItem, &mut RpnState) -> Result<StackItem>,
+{
+    if state.stack.len() < 2 {
+        return Err(anyhow!("Not enough arguments"));
+    }
+    let a = state.stack.peek(1).unwrap().clone();
+    let b = state.stack.peek(0).unwrap().clone();
+    let result = calc(&a, &b, state)?;
+    let result = state.apply_modes_in_base(result, b.display_base());
+    state.stack.pop_n(2)?;
+    state.stack.push(result.clone());
+    Ok(Box::new(BinaryFuncUndoEvent::new(a, b, result)))
+}
+
+/// Like `execute_binary` for calculations that take three operands, given bottom first.
+pub(super) fn execute_ternary<F>(state: &mut RpnState, calc: F) -> Result<Box<dyn UndoEvent>>
+where
+    F: FnOnce(&StackItem, &StackItem, &StackItem) -> Result<StackItem>,
+{
+    if state.stack.len() < 3 {
+        return Err(anyhow!("Not enough arguments"));
+    }
+    let a = state.stack.peek(2).unwrap().clone();
+    let b = state.stack.peek(1).unwrap().clone();
+    let c = state.stack.peek(0).unwrap().clone();
+    let result = state.apply_modes(calc(&a, &b, &c)?);
+    state.stack.pop_n(3)?;
+    state.stack.push(result.clone());
+    Ok(Box::new(ReplaceUndoEvent::new(vec![a, b, c], vec![result])))
+}
+
+/// Like `execute_ternary` for calculations that need the state, such as those that
+/// evaluate an expression given as an argument.
+pub(super) fn execute_ternary_with_state<F>(
+    state: &mut RpnState,
+    calc: F,
+) -> Result<Box<dyn UndoEvent>>
+where
+    F: FnOnce(&StackItem, &StackItem, &StackItem, &mut RpnState) -> Result<StackItem>,
+{
+    if state.stack.len() < 3 {
+        return Err(anyhow!("Not enough arguments"));
+    }
+    let a = state.stack.peek(2).unwrap().clone();
+    let b = state.stack.peek(1).unwrap().clone();
+    let c = state.stack.peek(0).unwrap().clone();
+    let result = calc(&a, &b, &c, state)?;
+    let result = state.apply_modes(result);
+    state.stack.pop_n(3)?;
+    state.stack.push(result.clone());
+    Ok(Box::new(ReplaceUndoEvent::new(vec![a, b, c], vec![result])))
+}
+
 pub(super) fn execute_unary<F>(state: &mut RpnState, calc: F) -> Result<Box<dyn UndoEvent>>
 where
     F: FnOnce(&StackItem) -> Result<StackItem>,
@@ -52,7 +184,7 @@ where
         return Err(anyhow!("Not enough arguments"));
     }
     let a = state.stack.peek(0).unwrap().clone();
-    let result = calc(&a)?;
+    let result = state.apply_modes_in_base(calc(&a)?, a.display_base());
     state.stack.pop_n(1)?;
     state.stack.push(result.clone());
     Ok(Box::new(UnaryFuncUndoEvent::new(a, result)))
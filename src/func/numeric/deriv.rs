@@ -0,0 +1,125 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_ternary_with_state, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{real_operand, ExprFunction};
+
+// each step is this much smaller than the last
+const STEP_SHRINK: f64 = 1.4;
+const MAX_STEPS: usize = 10;
+// stop once the error grows this much past the best seen
+const SAFE: f64 = 2.0;
+
+pub struct DerivFunc {}
+
+impl DerivFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for DerivFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_ternary_with_state(state, |expr, variable, x, state| {
+            let f = ExprFunction::new(expr, variable)?;
+            let x = real_operand(x)?;
+            Ok(StackItem::Number(derivative(&f, x, state)?, 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "deriv"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["derivative"]
+    }
+
+    fn description(&self) -> &str {
+        "The deriv function returns the derivative of an expression of a variable at a point, as in deriv('x^3', 'x', 2)."
+    }
+}
+
+/// Central differences with shrinking steps, extrapolated to a zero step size
+/// (Ridders' method). The step sizes stop shrinking once rounding error takes over.
+fn derivative(f: &ExprFunction, x: f64, state: &mut RpnState) -> Result<f64> {
+    let mut central = |h: f64| -> Result<f64> {
+        let (lo, hi) = (f.eval(x - h, state)?, f.eval(x + h, state)?);
+        if !lo.is_finite() || !hi.is_finite() {
+            return Err(anyhow!("{} is not a real number near {x}", f.source));
+        }
+        Ok((hi - lo) / (2.0 * h))
+    };
+
+    let mut h = 0.1 * x.abs().max(1.0);
+    // table[j][i] is the estimate with step i extrapolated j times
+    let mut table = [[0.0; MAX_STEPS]; MAX_STEPS];
+    table[0][0] = central(h)?;
+    let mut best = table[0][0];
+    let mut best_error = f64::INFINITY;
+    for i in 1..MAX_STEPS {
+        h /= STEP_SHRINK;
+        table[0][i] = central(h)?;
+        let mut factor = STEP_SHRINK * STEP_SHRINK;
+        for j in 1..=i {
+            table[j][i] = (table[j - 1][i] * factor - table[j - 1][i - 1]) / (factor - 1.0);
+            factor *= STEP_SHRINK * STEP_SHRINK;
+            let error = (table[j][i] - table[j - 1][i])
+                .abs()
+                .max((table[j][i] - table[j - 1][i - 1]).abs());
+            if error <= best_error {
+                best_error = error;
+                best = table[j][i];
+            }
+        }
+        if (table[i][i] - table[i - 1][i - 1]).abs() >= SAFE * best_error {
+            break;
+        }
+    }
+    Ok(best)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::{angle_mode::AngleMode, RpnState};
+
+    fn deriv(expr: &str) -> f64 {
+        let mut state = RpnState::new().unwrap();
+        state.angle_mode = AngleMode::Radians;
+        state.push_str(expr).unwrap();
+        assert_eq!(1, state.stack.len());
+        state.stack.peek(0).unwrap().to_f64().unwrap()
+    }
+
+    #[test]
+    fn test_deriv() {
+        let d = deriv("deriv('x^3', 'x', 2)");
+        assert!((d - 12.0).abs() < 1e-10, "d = {d}");
+        let d = deriv("deriv('sin(t)', 't', 0)");
+        assert!((d - 1.0).abs() < 1e-12, "d = {d}");
+        let d = deriv("deriv('exp', 'x', 10)");
+        assert!((d / 10f64.exp() - 1.0).abs() < 1e-12, "d = {d}");
+    }
+
+    #[test]
+    fn test_deriv_undo() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("deriv('x^2', 'x', 5)").unwrap();
+        state.undo().unwrap();
+        assert_eq!(0, state.stack.len());
+        state.redo().unwrap();
+        let d = state.stack.peek(0).unwrap().to_f64().unwrap();
+        assert!((d - 10.0).abs() < 1e-10, "d = {d}");
+    }
+
+    #[test]
+    fn test_deriv_not_real() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("deriv('sqrt(x)', 'x', 0)").is_err());
+    }
+}
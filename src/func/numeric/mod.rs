@@ -0,0 +1,145 @@
+use anyhow::{anyhow, Result};
+use deriv::DerivFunc;
+use integrate::IntegrateFunc;
+use solve::SolveFunc;
+
+use crate::{
+    expr::{
+        run::{evaluate_expr, parse},
+        Expr,
+    },
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::{replace::ReplaceUndoEvent, UndoEvent},
+};
+
+pub mod deriv;
+pub mod integrate;
+pub mod solve;
+
+pub fn numeric_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(DerivFunc::new()));
+    state.register_function(Box::new(IntegrateFunc::new()));
+    state.register_function(Box::new(SolveFunc::new()));
+}
+
+/// Replaces the top `args` operands, passed bottom first, with the results of a
+/// calculation that evaluates an expression, all in one undo step.
+fn execute_numeric<F>(state: &mut RpnState, args: usize, calc: F) -> Result<Box<dyn UndoEvent>>
+where
+    F: FnOnce(&[StackItem], &mut RpnState) -> Result<Vec<StackItem>>,
+{
+    if state.stack.len() < args {
+        return Err(anyhow!("Not enough arguments"));
+    }
+    let consumed = (0..args)
+        .rev()
+        .map(|i| state.stack.peek(i).unwrap().clone())
+        .collect::<Vec<_>>();
+    let results = calc(&consumed, state)?
+        .into_iter()
+        .map(|result| state.apply_modes(result))
+        .collect::<Vec<_>>();
+    state.stack.pop_n(args)?;
+    for result in &results {
+        state.stack.push(result.clone());
+    }
+    Ok(Box::new(ReplaceUndoEvent::new(consumed, results)))
+}
+
+/// A real function of one variable, given as a quoted expression such as 'x^2 - 2'
+/// or a function name such as 'cos'. The expression is parsed once and evaluated
+/// with the variable bound to each sample point, leaving the stack, variables and
+/// undo history untouched.
+struct ExprFunction {
+    source: String,
+    expr: Expr,
+    variable: String,
+}
+
+impl ExprFunction {
+    fn new(expr: &StackItem, variable: &StackItem) -> Result<Self> {
+        let source = match expr {
+            StackItem::String(s) => s.clone(),
+            _ => {
+                return Err(anyhow!(
+                    "expected an expression such as 'x^2 - 2' but found {expr}"
+                ))
+            }
+        };
+        let variable = match variable {
+            StackItem::String(s) if is_identifier(s) => s.clone(),
+            _ => {
+                return Err(anyhow!(
+                    "expected a variable name such as 'x' but found {variable}"
+                ))
+            }
+        };
+        Ok(Self {
+            expr: parse(&source)?,
+            source,
+            variable,
+        })
+    }
+
+    /// Evaluates the function at `x`, giving NaN where the value is not a real number.
+    fn eval(&self, x: f64, state: &mut RpnState) -> Result<f64> {
+        let x = StackItem::Number(x, 10);
+        let value = evaluate_expr(
+            &self.expr,
+            std::slice::from_ref(&x),
+            &[(&self.variable, &x)],
+            state,
+        )?
+        .ok_or_else(|| anyhow!("{} did not leave a value on the stack", self.source))?;
+        Ok(value.to_f64().unwrap_or(f64::NAN))
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|ch| ch.is_ascii_alphabetic())
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+/// Returns the value of a real number argument such as a guess or an integration limit.
+fn real_operand(item: &StackItem) -> Result<f64> {
+    match item {
+        StackItem::Complex(_) => Err(anyhow!("expected a real number but found {item}")),
+        _ => item
+            .to_f64()
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| anyhow!("expected a real number but found {item}")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ExprFunction;
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    fn string(s: &str) -> StackItem {
+        StackItem::String(s.to_string())
+    }
+
+    #[test]
+    fn test_expr_function() {
+        let mut state = RpnState::new().unwrap();
+        let f = ExprFunction::new(&string("t * t + 1"), &string("t")).unwrap();
+        assert_eq!(10.0, f.eval(3.0, &mut state).unwrap());
+        assert!(f.eval(0.0, &mut state).is_ok());
+        assert!(!state.variables.contains_key("t"));
+        assert_eq!(0, state.stack.len());
+
+        let f = ExprFunction::new(&string("sqrt"), &string("x")).unwrap();
+        assert_eq!(3.0, f.eval(9.0, &mut state).unwrap());
+        assert!(f.eval(-1.0, &mut state).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_expr_function_invalid() {
+        assert!(ExprFunction::new(&StackItem::Number(1.0, 10), &string("x")).is_err());
+        assert!(ExprFunction::new(&string("x + 1"), &string("1x")).is_err());
+        assert!(ExprFunction::new(&string("x +"), &string("x")).is_err());
+    }
+}
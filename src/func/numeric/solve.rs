@@ -0,0 +1,276 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_ternary_with_state, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{real_operand, ExprFunction};
+
+const MAX_ITERATIONS: usize = 200;
+// the search for a sign change doubles its step this many times
+const BRACKET_STEPS: usize = 60;
+
+pub struct SolveFunc {}
+
+impl SolveFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for SolveFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_ternary_with_state(state, |expr, variable, guess, state| {
+            let f = ExprFunction::new(expr, variable)?;
+            let guess = real_operand(guess)?;
+            Ok(StackItem::Number(find_root(&f, guess, state)?, 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "solve"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["root"]
+    }
+
+    fn description(&self) -> &str {
+        "The solve function finds x where an expression is zero, given the expression, the variable name and an initial guess, as in solve('x^2 - 2', 'x', 1)."
+    }
+}
+
+/// Searches outwards from the guess for a sign change and then narrows it down with
+/// secant steps, falling back to bisection whenever a step leaves the bracket. Roots
+/// that touch zero without crossing it are found with unbracketed secant steps.
+fn find_root(f: &ExprFunction, guess: f64, state: &mut RpnState) -> Result<f64> {
+    let f_guess = f.eval(guess, state)?;
+    if f_guess == 0.0 {
+        return Ok(guess);
+    }
+    if !f_guess.is_finite() {
+        return Err(anyhow!(
+            "{} is not a real number at the guess {guess}",
+            f.source
+        ));
+    }
+    match find_bracket(f, guess, f_guess, state)? {
+        Some(bracket) => bracketed_root(f, bracket, state),
+        None => secant_root(f, guess, f_guess, state),
+    }
+}
+
+/// Returns the nearest points either side of the guess where the function changes
+/// sign, as (a, f(a), b, f(b)) with a < b.
+fn find_bracket(
+    f: &ExprFunction,
+    guess: f64,
+    f_guess: f64,
+    state: &mut RpnState,
+) -> Result<Option<(f64, f64, f64, f64)>> {
+    let mut step = 0.01 * guess.abs().max(1.0);
+    let (mut left, mut f_left) = (guess, f_guess);
+    let (mut right, mut f_right) = (guess, f_guess);
+    for _ in 0..BRACKET_STEPS {
+        let x = guess - step;
+        let fx = f.eval(x, state)?;
+        if fx.is_finite() {
+            if changes_sign(fx, f_left) {
+                return Ok(Some((x, fx, left, f_left)));
+            }
+            (left, f_left) = (x, fx);
+        }
+
+        let x = guess + step;
+        let fx = f.eval(x, state)?;
+        if fx.is_finite() {
+            if changes_sign(f_right, fx) {
+                return Ok(Some((right, f_right, x, fx)));
+            }
+            (right, f_right) = (x, fx);
+        }
+        step *= 2.0;
+    }
+    Ok(None)
+}
+
+fn changes_sign(a: f64, b: f64) -> bool {
+    a == 0.0 || b == 0.0 || (a < 0.0) != (b < 0.0)
+}
+
+fn bracketed_root(
+    f: &ExprFunction,
+    (mut a, mut fa, mut b, mut fb): (f64, f64, f64, f64),
+    state: &mut RpnState,
+) -> Result<f64> {
+    // start from the end nearer to zero, using the other end for the first secant
+    let ((mut x, mut fx), (mut prev, mut f_prev)) = if fa.abs() < fb.abs() {
+        ((a, fa), (b, fb))
+    } else {
+        ((b, fb), (a, fa))
+    };
+    let f_start = fx.abs();
+    for _ in 0..MAX_ITERATIONS {
+        if fx == 0.0 {
+            return Ok(x);
+        }
+        let secant = x - fx * (x - prev) / (fx - f_prev);
+        let next = if secant > a && secant < b {
+            secant
+        } else {
+            a + (b - a) / 2.0
+        };
+        if next <= a || next >= b {
+            // the bracket cannot be split any further
+            break;
+        }
+        let f_next = f.eval(next, state)?;
+        if !f_next.is_finite() {
+            return Err(anyhow!(
+                "{} is not a real number at {next}, it may have a pole rather than a root there",
+                f.source
+            ));
+        }
+        if changes_sign(fa, f_next) {
+            (b, fb) = (next, f_next);
+        } else {
+            (a, fa) = (next, f_next);
+        }
+        (prev, f_prev) = (x, fx);
+        (x, fx) = (next, f_next);
+        if (x - prev).abs() <= f64::EPSILON * x.abs() {
+            break;
+        }
+    }
+    let (root, f_root) = [(a, fa), (b, fb)].into_iter().fold((x, fx), |best, p| {
+        if p.1.abs() < best.1.abs() {
+            p
+        } else {
+            best
+        }
+    });
+    // a sign change that grows as it narrows is a pole, such as 1/x at zero
+    if f_root.abs() > f_start {
+        return Err(anyhow!(
+            "{} changes sign near {root} but has a pole rather than a root there",
+            f.source
+        ));
+    }
+    Ok(root)
+}
+
+fn secant_root(f: &ExprFunction, guess: f64, f_guess: f64, state: &mut RpnState) -> Result<f64> {
+    let (mut prev, mut f_prev) = (guess, f_guess);
+    let mut x = guess + 1e-4 * guess.abs().max(1.0);
+    let mut fx = f.eval(x, state)?;
+    for _ in 0..MAX_ITERATIONS {
+        if fx == 0.0 {
+            return Ok(x);
+        }
+        let next = x - fx * (x - prev) / (fx - f_prev);
+        if !next.is_finite() {
+            break;
+        }
+        (prev, f_prev) = (x, fx);
+        x = next;
+        fx = f.eval(x, state)?;
+        if !fx.is_finite() {
+            break;
+        }
+        if (x - prev).abs() <= 1e-12 * x.abs().max(1.0) && fx.abs() <= 1e-9 {
+            return Ok(x);
+        }
+    }
+    Err(anyhow!(
+        "could not find a root of {} near {guess}",
+        f.source
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        stack::item::StackItem,
+        state::{angle_mode::AngleMode, RpnState},
+    };
+
+    fn solve(expr: &str) -> f64 {
+        let mut state = RpnState::new().unwrap();
+        state.angle_mode = AngleMode::Radians;
+        state.push_str(expr).unwrap();
+        assert_eq!(1, state.stack.len());
+        state.stack.peek(0).unwrap().to_f64().unwrap()
+    }
+
+    #[test]
+    fn test_solve() {
+        let root = solve("solve('x^2 - 2', 'x', 1)");
+        assert!((root - 2f64.sqrt()).abs() < 1e-15, "root = {root}");
+        let root = solve("solve('x^2 - 2', 'x', -3)");
+        assert!((root + 2f64.sqrt()).abs() < 1e-15, "root = {root}");
+        let root = solve("solve('cos(t) - t', 't', 0)");
+        assert!((root.cos() - root).abs() < 1e-15, "root = {root}");
+    }
+
+    #[test]
+    fn test_solve_function_name() {
+        let root = solve("solve('sin', 'x', 3)");
+        assert!((root - std::f64::consts::PI).abs() < 1e-15, "root = {root}");
+    }
+
+    #[test]
+    fn test_solve_double_root() {
+        let root = solve("solve('(x - 3)^2', 'x', 1)");
+        assert!((root - 3.0).abs() < 1e-4, "root = {root}");
+    }
+
+    #[test]
+    fn test_solve_undo() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("'x^3 - 8'").unwrap();
+        state.push_str("'x'").unwrap();
+        state.push_str("1").unwrap();
+        state.push_str("solve").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(StackItem::Number(2.0, 10), *state.stack.peek(0).unwrap());
+        assert!(!state.variables.contains_key("x"));
+
+        state.undo().unwrap();
+        assert_eq!(3, state.stack.len());
+        assert_eq!(
+            StackItem::Integer(1.into(), 10),
+            *state.stack.peek(0).unwrap()
+        );
+        assert_eq!(
+            StackItem::String("x^3 - 8".to_string()),
+            *state.stack.peek(2).unwrap()
+        );
+
+        state.redo().unwrap();
+        assert_eq!(StackItem::Number(2.0, 10), *state.stack.peek(0).unwrap());
+    }
+
+    #[test]
+    fn test_solve_keeps_variable() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("7").unwrap();
+        state.push_str("'x'").unwrap();
+        state.push_str("store").unwrap();
+        state.push_str("solve('x - 4', 'x', 0)").unwrap();
+        assert_eq!(
+            Some(&StackItem::Integer(7.into(), 10)),
+            state.variables.get("x")
+        );
+    }
+
+    #[test]
+    fn test_solve_no_root() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("solve('x^2 + 1', 'x', 1)").is_err());
+        assert!(state.push_str("solve('1 / x', 'x', 1)").is_err());
+        assert!(state.push_str("solve('x', 3, 1)").is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{factorable_operand, integer, prime_powers};
+
+pub struct DivisorsFunc {}
+
+impl DivisorsFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for DivisorsFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let mut divisors = vec![1_u64];
+            for (p, exp) in prime_powers(factorable_operand(a)?) {
+                let mut next = vec![];
+                for d in &divisors {
+                    let mut v = *d;
+                    for _ in 0..=exp {
+                        next.push(v);
+                        v = v.saturating_mul(p);
+                    }
+                }
+                divisors = next;
+            }
+            divisors.sort_unstable();
+            Ok(StackItem::List(divisors.into_iter().map(integer).collect()))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "divisors"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The divisors function returns a sorted list of every positive divisor of a positive integer up to 64 bits."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_expr;
+
+    #[test]
+    fn test_divisors() {
+        test_expr!(
+            "divisors(12)",
+            StackItem::List(
+                [1, 2, 3, 4, 6, 12]
+                    .into_iter()
+                    .map(|v| StackItem::Integer(v.into(), 10))
+                    .collect()
+            )
+        );
+        test_expr!(
+            "divisors(1)",
+            StackItem::List(vec![StackItem::Integer(1.into(), 10)])
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{factorable_operand, factorize, integer};
+
+pub struct FactorFunc {}
+
+impl FactorFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for FactorFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let factors = factorize(factorable_operand(a)?);
+            Ok(StackItem::List(factors.into_iter().map(integer).collect()))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "factor"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["factors"]
+    }
+
+    fn description(&self) -> &str {
+        "The factor function returns a list of the prime factors of a positive integer up to 64 bits, repeated by multiplicity. Use explode to push them onto the stack."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_factor() {
+        test_expr!(
+            "factor(360)",
+            StackItem::List(
+                [2, 2, 2, 3, 3, 5]
+                    .into_iter()
+                    .map(|v| StackItem::Integer(v.into(), 10))
+                    .collect()
+            )
+        );
+        test_expr!("factor(1)", StackItem::List(vec![]));
+    }
+
+    #[test]
+    fn test_factor_invalid() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("factor(0)").is_err());
+        assert!(state.push_str("factor(2.5)").is_err());
+        assert!(state.push_str("factor(18446744073709551616)").is_err());
+    }
+}
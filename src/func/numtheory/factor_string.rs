@@ -0,0 +1,64 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{factorable_operand, prime_powers};
+
+pub struct FactorStringFunc {}
+
+impl FactorStringFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for FactorStringFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let n = factorable_operand(a)?;
+            if n == 1 {
+                return Ok(StackItem::String("1".to_string()));
+            }
+            let s = prime_powers(n)
+                .into_iter()
+                .map(|(p, exp)| match exp {
+                    1 => p.to_string(),
+                    _ => format!("{p}^{exp}"),
+                })
+                .collect::<Vec<_>>()
+                .join(" * ");
+            Ok(StackItem::String(s))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "factorstr"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["factor_string"]
+    }
+
+    fn description(&self) -> &str {
+        "The factorstr function returns the prime factorization of a positive integer up to 64 bits as a string such as '2^3 * 3^2 * 5'."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_expr;
+
+    #[test]
+    fn test_factor_string() {
+        test_expr!(
+            "factorstr(360)",
+            StackItem::String("2^3 * 3^2 * 5".to_string())
+        );
+        test_expr!("factorstr(13)", StackItem::String("13".to_string()));
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::Result;
+use num_integer::Integer;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{integer, integer_operand};
+
+pub struct GcdFunc {}
+
+impl GcdFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for GcdFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            Ok(integer(integer_operand(a)?.gcd(&integer_operand(b)?)))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "gcd"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["gcf", "hcf"]
+    }
+
+    fn description(&self) -> &str {
+        "The gcd function returns the greatest common divisor of two integers."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_gcd() {
+        test_binary_func!(
+            StackItem::Integer(48.into(), 10),
+            StackItem::Integer(180.into(), 10),
+            "gcd",
+            StackItem::Integer(12.into(), 10)
+        );
+        test_expr!("gcd(-4, 6.0)", StackItem::Integer(2.into(), 10));
+        test_expr!("gcd(0, 0)", StackItem::Integer(0.into(), 10));
+    }
+
+    #[test]
+    fn test_gcd_fractional() {
+        let mut state = RpnState::new().unwrap();
+        let err = state.push_str("gcd(4.5, 6)").unwrap_err();
+        assert_eq!("expected an integer but found 4.5", err.to_string());
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{integer, integer_operand, is_prime};
+
+pub struct IsPrimeFunc {}
+
+impl IsPrimeFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for IsPrimeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            Ok(integer(if is_prime(&integer_operand(a)?) { 1 } else { 0 }))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "isprime"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["is_prime"]
+    }
+
+    fn description(&self) -> &str {
+        "The isprime function returns 1 if an integer is prime and 0 otherwise. Values above 64 bits are probable primes."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_is_prime() {
+        test_unary_func!(
+            StackItem::Integer(97.into(), 10),
+            "isprime",
+            StackItem::Integer(1.into(), 10)
+        );
+        test_expr!("isprime(1)", StackItem::Integer(0.into(), 10));
+        test_expr!("isprime(-7)", StackItem::Integer(0.into(), 10));
+        test_expr!("isprime(561)", StackItem::Integer(0.into(), 10));
+    }
+
+    #[test]
+    fn test_is_prime_large() {
+        // 2^89 - 1 and 2^89 + 1
+        test_expr!(
+            "isprime(618970019642690137449562111)",
+            StackItem::Integer(1.into(), 10)
+        );
+        test_expr!(
+            "isprime(618970019642690137449562113)",
+            StackItem::Integer(0.into(), 10)
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::Result;
+use num_integer::Integer;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{integer, integer_operand};
+
+pub struct LcmFunc {}
+
+impl LcmFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for LcmFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            Ok(integer(integer_operand(a)?.lcm(&integer_operand(b)?)))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "lcm"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The lcm function returns the least common multiple of two integers."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_lcm() {
+        test_binary_func!(
+            StackItem::Integer(4.into(), 10),
+            StackItem::Integer(6.into(), 10),
+            "lcm",
+            StackItem::Integer(12.into(), 10)
+        );
+        test_expr!("lcm(21, 0)", StackItem::Integer(0.into(), 10));
+    }
+}
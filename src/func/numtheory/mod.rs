@@ -0,0 +1,253 @@
+use anyhow::{anyhow, Result};
+use divisors::DivisorsFunc;
+use factor::FactorFunc;
+use factor_string::FactorStringFunc;
+use gcd::GcdFunc;
+use is_prime::IsPrimeFunc;
+use lcm::LcmFunc;
+use mod_inv::ModInvFunc;
+use mod_pow::ModPowFunc;
+use next_prime::NextPrimeFunc;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive};
+use prev_prime::PrevPrimeFunc;
+use totient::TotientFunc;
+
+use crate::{stack::item::StackItem, state::RpnState};
+
+pub mod divisors;
+pub mod factor;
+pub mod factor_string;
+pub mod gcd;
+pub mod is_prime;
+pub mod lcm;
+pub mod mod_inv;
+pub mod mod_pow;
+pub mod next_prime;
+pub mod prev_prime;
+pub mod totient;
+
+pub fn numtheory_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(DivisorsFunc::new()));
+    state.register_function(Box::new(FactorFunc::new()));
+    state.register_function(Box::new(FactorStringFunc::new()));
+    state.register_function(Box::new(GcdFunc::new()));
+    state.register_function(Box::new(IsPrimeFunc::new()));
+    state.register_function(Box::new(LcmFunc::new()));
+    state.register_function(Box::new(ModInvFunc::new()));
+    state.register_function(Box::new(ModPowFunc::new()));
+    state.register_function(Box::new(NextPrimeFunc::new()));
+    state.register_function(Box::new(PrevPrimeFunc::new()));
+    state.register_function(Box::new(TotientFunc::new()));
+}
+
+/// Bases that make Miller-Rabin exact for every 64-bit value.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Returns the value of a whole number operand, which may be an integer or an integral
+/// floating point or decimal number.
+fn integer_operand(item: &StackItem) -> Result<BigInt> {
+    let v = match item {
+        StackItem::Integer(v, _) => Some(v.clone()),
+        StackItem::Decimal(_) if item.is_integer() => item.to_rational().map(|v| v.to_integer()),
+        StackItem::Number(v, _) if item.is_integer() => BigInt::from_f64(*v),
+        _ => None,
+    };
+    v.ok_or_else(|| anyhow!("expected an integer but found {item}"))
+}
+
+/// Returns a positive operand small enough to factor.
+fn factorable_operand(item: &StackItem) -> Result<u64> {
+    let v = integer_operand(item)?;
+    if !v.is_positive() {
+        return Err(anyhow!("expected a positive integer but found {v}"));
+    }
+    v.to_u64()
+        .ok_or_else(|| anyhow!("{v} is too large to factor, the limit is 64 bits"))
+}
+
+/// Returns a modulus operand, which must be a positive integer.
+fn modulus_operand(item: &StackItem) -> Result<BigInt> {
+    let m = integer_operand(item)?;
+    if !m.is_positive() {
+        return Err(anyhow!("the modulus must be a positive integer, found {m}"));
+    }
+    Ok(m)
+}
+
+fn integer(v: impl Into<BigInt>) -> StackItem {
+    StackItem::Integer(v.into(), 10)
+}
+
+/// Miller-Rabin test, deterministic for 64-bit values and a strong probable prime test
+/// above that.
+fn is_prime(n: &BigInt) -> bool {
+    if let Some(n) = n.to_u64() {
+        return is_prime_u64(n);
+    }
+    if n.is_negative() || n.is_even() {
+        return false;
+    }
+    let one = BigInt::one();
+    let n_minus_one = n - &one;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+    WITNESSES.iter().all(|a| {
+        let mut x = BigInt::from(*a).modpow(&d, n);
+        if x == one || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    WITNESSES.iter().all(|a| {
+        let mut x = pow_mod(*a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Returns the prime factors of `n` in ascending order, repeated by multiplicity.
+fn factorize(mut n: u64) -> Vec<u64> {
+    let mut factors = vec![];
+    for p in [2, 3, 5] {
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+    }
+    // wheel of the numbers coprime to 30 takes out the small factors quickly
+    let mut p = 7;
+    let wheel = [4, 2, 4, 2, 4, 6, 2, 6];
+    let mut i = 0;
+    while p * p <= n && p < 1 << 12 {
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+        p += wheel[i];
+        i = (i + 1) % wheel.len();
+    }
+    let mut pending = vec![n];
+    while let Some(n) = pending.pop() {
+        if n == 1 {
+            continue;
+        }
+        if is_prime_u64(n) {
+            factors.push(n);
+            continue;
+        }
+        let d = pollard_rho(n);
+        pending.push(d);
+        pending.push(n / d);
+    }
+    factors.sort_unstable();
+    factors
+}
+
+/// Finds a non-trivial factor of the composite `n` using Pollard's rho with Floyd's cycle detection.
+fn pollard_rho(n: u64) -> u64 {
+    if n.is_multiple_of(2) {
+        return 2;
+    }
+    for c in 1.. {
+        let f = |x: u64| ((mul_mod(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = x.abs_diff(y).gcd(&n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!()
+}
+
+/// Groups sorted prime factors into (prime, exponent) pairs.
+fn prime_powers(n: u64) -> Vec<(u64, u32)> {
+    let mut powers: Vec<(u64, u32)> = vec![];
+    for p in factorize(n) {
+        match powers.last_mut() {
+            Some((last, exp)) if *last == p => *exp += 1,
+            _ => powers.push((p, 1)),
+        }
+    }
+    powers
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_prime_u64() {
+        let primes = (0..100).filter(|n| is_prime_u64(*n)).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79,
+                83, 89, 97
+            ],
+            primes
+        );
+        assert!(is_prime_u64(18446744073709551557));
+        // strong pseudoprime to bases 2 through 11
+        assert!(!is_prime_u64(3825123056546413051));
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(vec![2, 2, 3, 5], factorize(60));
+        assert_eq!(
+            vec![4294967291, 4294967291],
+            factorize(18446744030759878681)
+        );
+        assert_eq!(vec![3, 3, 11, 23, 79, 50077], factorize(9008000991));
+        assert_eq!(Vec::<u64>::new(), factorize(1));
+    }
+}
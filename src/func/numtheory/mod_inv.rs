@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Result};
+use num_integer::Integer;
+use num_traits::{One, Signed};
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{integer, integer_operand, modulus_operand};
+
+pub struct ModInvFunc {}
+
+impl ModInvFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ModInvFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            let a = integer_operand(a)?;
+            let m = modulus_operand(b)?;
+            let gcd = a.extended_gcd(&m);
+            if !gcd.gcd.abs().is_one() {
+                return Err(anyhow!("{a} has no inverse modulo {m}"));
+            }
+            Ok(integer(gcd.x.mod_floor(&m)))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "modinv"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["mod_inv"]
+    }
+
+    fn description(&self) -> &str {
+        "The modinv function returns the x in 0 to m - 1 where a x = 1 modulo m."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_mod_inv() {
+        test_binary_func!(
+            StackItem::Integer(3.into(), 10),
+            StackItem::Integer(11.into(), 10),
+            "modinv",
+            StackItem::Integer(4.into(), 10)
+        );
+        test_expr!("modinv(-3, 11)", StackItem::Integer(7.into(), 10));
+    }
+
+    #[test]
+    fn test_mod_inv_none() {
+        let mut state = RpnState::new().unwrap();
+        let err = state.push_str("modinv(6, 9)").unwrap_err();
+        assert_eq!("6 has no inverse modulo 9", err.to_string());
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::{anyhow, Result};
+use num_integer::Integer;
+use num_traits::{One, Signed};
+
+use crate::{
+    func::{execute_ternary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{integer, integer_operand, modulus_operand};
+
+pub struct ModPowFunc {}
+
+impl ModPowFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ModPowFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_ternary(state, |a, b, c| {
+            let mut base = integer_operand(a)?;
+            let mut exp = integer_operand(b)?;
+            let m = modulus_operand(c)?;
+            if exp.is_negative() {
+                let gcd = base.extended_gcd(&m);
+                if !gcd.gcd.abs().is_one() {
+                    return Err(anyhow!(
+                        "{base} has no inverse modulo {m}, so it cannot be raised to {exp}"
+                    ));
+                }
+                base = gcd.x;
+                exp = -exp;
+            }
+            Ok(integer(base.mod_floor(&m).modpow(&exp, &m)))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "modpow"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["mod_pow", "powmod"]
+    }
+
+    fn description(&self) -> &str {
+        "The modpow function returns a^b modulo m, taking a, b and m from the stack in that order."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_mod_pow() {
+        test_expr!("modpow(4, 13, 497)", StackItem::Integer(445.into(), 10));
+        test_expr!("modpow(-2, 3, 5)", StackItem::Integer(2.into(), 10));
+        test_expr!("modpow(3, -1, 11)", StackItem::Integer(4.into(), 10));
+        test_expr!(
+            "modpow(2, 1000000, 1000000007)",
+            StackItem::Integer(235042059.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_mod_pow_stack() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("4").unwrap();
+        state.push_str("13").unwrap();
+        state.push_str("497").unwrap();
+        state.push_str("modpow").unwrap();
+        assert_eq!(1, state.stack.len());
+        state.undo().unwrap();
+        assert_eq!(3, state.stack.len());
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use num_bigint::BigInt;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{integer, integer_operand, is_prime};
+
+pub struct NextPrimeFunc {}
+
+impl NextPrimeFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for NextPrimeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let mut n = integer_operand(a)?.max(BigInt::from(1)) + 1;
+            while !is_prime(&n) {
+                n += 1;
+            }
+            Ok(integer(n))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "nextprime"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["next_prime"]
+    }
+
+    fn description(&self) -> &str {
+        "The nextprime function returns the smallest prime greater than an integer."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_next_prime() {
+        test_unary_func!(
+            StackItem::Integer(13.into(), 10),
+            "nextprime",
+            StackItem::Integer(17.into(), 10)
+        );
+        test_expr!("nextprime(-10)", StackItem::Integer(2.into(), 10));
+        test_expr!(
+            "nextprime(18446744073709551557)",
+            StackItem::Integer("18446744073709551629".parse().unwrap(), 10)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{integer, integer_operand, is_prime};
+
+pub struct PrevPrimeFunc {}
+
+impl PrevPrimeFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PrevPrimeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let mut n = integer_operand(a)?;
+            if n <= 2.into() {
+                return Err(anyhow!("there is no prime less than {n}"));
+            }
+            n -= 1;
+            while !is_prime(&n) {
+                n -= 1;
+            }
+            Ok(integer(n))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "prevprime"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["prev_prime"]
+    }
+
+    fn description(&self) -> &str {
+        "The prevprime function returns the largest prime less than an integer."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr, test_unary_func};
+
+    #[test]
+    fn test_prev_prime() {
+        test_unary_func!(
+            StackItem::Integer(17.into(), 10),
+            "prevprime",
+            StackItem::Integer(13.into(), 10)
+        );
+        test_expr!("prevprime(3)", StackItem::Integer(2.into(), 10));
+        test_expr!(
+            "prevprime(18446744073709551615)",
+            StackItem::Integer(18446744073709551557_u64.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_prev_prime_none() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("prevprime(2)").is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{factorable_operand, integer, prime_powers};
+
+pub struct TotientFunc {}
+
+impl TotientFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for TotientFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            let n = factorable_operand(a)?;
+            let phi = prime_powers(n)
+                .into_iter()
+                .fold(n, |phi, (p, _)| phi / p * (p - 1));
+            Ok(integer(phi))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "totient"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["phi", "eulerphi"]
+    }
+
+    fn description(&self) -> &str {
+        "The totient function returns Euler's totient of a positive integer, the count of integers up to it that are coprime to it."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_totient() {
+        test_unary_func!(
+            StackItem::Integer(36.into(), 10),
+            "totient",
+            StackItem::Integer(12.into(), 10)
+        );
+        test_expr!("phi(1)", StackItem::Integer(1.into(), 10));
+        test_expr!("phi(97)", StackItem::Integer(96.into(), 10));
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::hundred;
+
+pub struct MarginFunc {}
+
+impl MarginFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for MarginFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |cost, margin| {
+            cost.multiply(&hundred())?
+                .divide(&hundred().subtract(margin)?)
+        })
+    }
+
+    fn name(&self) -> &str {
+        "margin"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The margin function returns the price of an item costing a that gives a margin of b percent of the price."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_margin() {
+        test_binary_func!(
+            StackItem::Integer(75.into(), 10),
+            StackItem::Integer(25.into(), 10),
+            "margin",
+            StackItem::Integer(100.into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_margin_hundred_percent() {
+        test_expr!("margin(75, 100)", StackItem::Undefined);
+    }
+}
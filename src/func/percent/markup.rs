@@ -0,0 +1,53 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::hundred;
+
+pub struct MarkupFunc {}
+
+impl MarkupFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for MarkupFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |cost, markup| {
+            cost.multiply(&hundred().add(markup)?)?.divide(&hundred())
+        })
+    }
+
+    fn name(&self) -> &str {
+        "markup"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["mu"]
+    }
+
+    fn description(&self) -> &str {
+        "The markup function returns the price of an item costing a with a markup of b percent on cost."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_binary_func, test_expr};
+
+    #[test]
+    fn test_markup() {
+        test_binary_func!(
+            StackItem::Integer(80.into(), 10),
+            StackItem::Integer(25.into(), 10),
+            "markup",
+            StackItem::Integer(100.into(), 10)
+        );
+        test_expr!("markup(10, 2.5)", StackItem::Number(10.25, 10));
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::{anyhow, Result};
+use margin::MarginFunc;
+use markup::MarkupFunc;
+use pct::PctFunc;
+use pct_change::PctChangeFunc;
+use pct_total::PctTotalFunc;
+use percent_key::PercentKeyFunc;
+
+use crate::{
+    stack::item::StackItem,
+    state::{percent_key::PercentKey, RpnState},
+    undo_action::{replace::ReplaceUndoEvent, UndoEvent},
+};
+
+pub mod margin;
+pub mod markup;
+pub mod pct;
+pub mod pct_change;
+pub mod pct_total;
+pub mod percent_key;
+
+pub fn percent_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(MarginFunc::new()));
+    state.register_function(Box::new(MarkupFunc::new()));
+    state.register_function(Box::new(PctFunc::new()));
+    state.register_function(Box::new(PctChangeFunc::new()));
+    state.register_function(Box::new(PctTotalFunc::new()));
+    state.register_function(Box::new(PercentKeyFunc::new("pctkey", PercentKey::Percent)));
+    state.register_function(Box::new(PercentKeyFunc::new("modkey", PercentKey::Modulus)));
+}
+
+/// Replaces the top of the stack with a percentage calculated from the two top items,
+/// leaving the base below it as HP calculators do, so `200 15 pct +` adds 15% to 200.
+fn execute_percent<F>(state: &mut RpnState, calc: F) -> Result<Box<dyn UndoEvent>>
+where
+    F: FnOnce(&StackItem, &StackItem) -> Result<StackItem>,
+{
+    if state.stack.len() < 2 {
+        return Err(anyhow!("Not enough arguments"));
+    }
+    let base = state.stack.peek(1).unwrap().clone();
+    let b = state.stack.peek(0).unwrap().clone();
+    let result = state.apply_modes(calc(&base, &b)?);
+    state.stack.pop();
+    state.stack.push(result.clone());
+    Ok(Box::new(ReplaceUndoEvent::new(
+        vec![base.clone(), b],
+        vec![base, result],
+    )))
+}
+
+fn hundred() -> StackItem {
+    StackItem::Integer(100.into(), 10)
+}
+
+#[derive(Debug)]
+pub struct PercentKeyUndoEvent {
+    previous_key: PercentKey,
+    new_key: PercentKey,
+}
+
+impl UndoEvent for PercentKeyUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.percent_key != self.new_key {
+            return Err(anyhow!(
+                "expected the % key to be {:?} but was {:?}",
+                self.new_key,
+                state.percent_key
+            ));
+        }
+        state.percent_key = self.previous_key;
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.percent_key != self.previous_key {
+            return Err(anyhow!(
+                "expected the % key to be {:?} but was {:?}",
+                self.previous_key,
+                state.percent_key
+            ));
+        }
+        state.percent_key = self.new_key;
+        Ok(())
+    }
+}
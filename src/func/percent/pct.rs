@@ -0,0 +1,73 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_percent, hundred};
+
+pub struct PctFunc {}
+
+impl PctFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PctFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_percent(state, |a, b| a.multiply(b)?.divide(&hundred()))
+    }
+
+    fn name(&self) -> &str {
+        "pct"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["percent"]
+    }
+
+    fn description(&self) -> &str {
+        "The pct function returns b percent of a, keeping a on the stack so the result can be added to or subtracted from it."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_pct() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("200").unwrap();
+        state.push_str("15").unwrap();
+        state.push_str("pct").unwrap();
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(30.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+        assert_eq!(
+            &StackItem::Integer(200.into(), 10),
+            state.stack.peek(1).unwrap()
+        );
+
+        state.push_str("add").unwrap();
+        assert_eq!(
+            &StackItem::Integer(230.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pct_undo() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("200").unwrap();
+        state.push_str("15").unwrap();
+        state.push_str("pct").unwrap();
+        state.undo().unwrap();
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(15.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_percent, hundred};
+
+pub struct PctChangeFunc {}
+
+impl PctChangeFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PctChangeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_percent(state, |a, b| b.subtract(a)?.divide(a)?.multiply(&hundred()))
+    }
+
+    fn name(&self) -> &str {
+        "pctch"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The pctch function returns the percentage change from a to b, keeping a on the stack."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_pct_change() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("40").unwrap();
+        state.push_str("52").unwrap();
+        state.push_str("pctch").unwrap();
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(30.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+        assert_eq!(
+            &StackItem::Integer(40.into(), 10),
+            state.stack.peek(1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pct_change_from_zero() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("0").unwrap();
+        state.push_str("5").unwrap();
+        state.push_str("pctch").unwrap();
+        assert_eq!(&StackItem::Undefined, state.stack.peek(0).unwrap());
+    }
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_percent, hundred};
+
+pub struct PctTotalFunc {}
+
+impl PctTotalFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PctTotalFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_percent(state, |a, b| b.divide(a)?.multiply(&hundred()))
+    }
+
+    fn name(&self) -> &str {
+        "pctt"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The pctt function returns the percentage b is of the total a, keeping a on the stack."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_pct_total() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("80").unwrap();
+        state.push_str("20").unwrap();
+        state.push_str("pctt").unwrap();
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(25.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
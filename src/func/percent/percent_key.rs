@@ -0,0 +1,71 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{percent_key::PercentKey, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::PercentKeyUndoEvent;
+
+pub struct PercentKeyFunc {
+    name: String,
+    key: PercentKey,
+}
+
+impl PercentKeyFunc {
+    pub fn new(name: &str, key: PercentKey) -> Self {
+        Self {
+            name: name.to_string(),
+            key,
+        }
+    }
+}
+
+impl Func for PercentKeyFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous_key = state.percent_key;
+        state.percent_key = self.key;
+        Ok(Box::new(PercentKeyUndoEvent {
+            previous_key,
+            new_key: self.key,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        match self.key {
+            PercentKey::Percent => {
+                "Makes the % key take a percentage (pct) rather than the modulus"
+            }
+            PercentKey::Modulus => "Makes the % key take the modulus rather than a percentage",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::{percent_key::PercentKey, RpnState};
+
+    #[test]
+    fn test_percent_key() {
+        let mut state = RpnState::new().unwrap();
+        assert_eq!(PercentKey::Modulus, state.percent_key);
+        state.push_str("pctkey").unwrap();
+        assert_eq!(PercentKey::Percent, state.percent_key);
+
+        state.undo().unwrap();
+        assert_eq!(PercentKey::Modulus, state.percent_key);
+
+        state.redo().unwrap();
+        state.push_str("modkey").unwrap();
+        assert_eq!(PercentKey::Modulus, state.percent_key);
+    }
+}
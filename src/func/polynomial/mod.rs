@@ -0,0 +1,38 @@
+use anyhow::{anyhow, Result};
+use num_traits::ToPrimitive;
+use peval::PEvalFunc;
+use pfit::PFitFunc;
+use proot::PRootFunc;
+
+use crate::{stack::item::StackItem, state::RpnState};
+
+pub mod peval;
+pub mod pfit;
+pub mod proot;
+
+pub fn polynomial_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(PEvalFunc::new()));
+    state.register_function(Box::new(PFitFunc::new()));
+    state.register_function(Box::new(PRootFunc::new()));
+}
+
+/// Returns the items of a list or vector, such as the coefficients of a polynomial
+/// from the highest power down.
+fn items(item: &StackItem) -> Result<&Vec<StackItem>> {
+    match item {
+        StackItem::List(items) | StackItem::Vector(items) if !items.is_empty() => Ok(items),
+        _ => Err(anyhow!(
+            "expected a non-empty list or vector of numbers but found {item}"
+        )),
+    }
+}
+
+/// Returns the degree of a polynomial, which must be a whole number.
+fn degree_operand(item: &StackItem) -> Result<usize> {
+    let degree = match item {
+        StackItem::Integer(v, _) => v.to_usize(),
+        _ if item.is_integer() => item.to_f64().filter(|v| *v >= 0.0).map(|v| v as usize),
+        _ => None,
+    };
+    degree.ok_or_else(|| anyhow!("expected a degree such as 2 but found {item}"))
+}
@@ -0,0 +1,78 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::items;
+
+pub struct PEvalFunc {}
+
+impl PEvalFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PEvalFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |coefficients, x| {
+            // Horner's rule, exact when the coefficients and x are
+            let mut coefficients = items(coefficients)?.iter();
+            let mut result = coefficients.next().unwrap().clone();
+            for c in coefficients {
+                result = result.multiply(x)?.add(c)?;
+            }
+            Ok(result)
+        })
+    }
+
+    fn name(&self) -> &str {
+        "peval"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["polyval"]
+    }
+
+    fn description(&self) -> &str {
+        "The peval function evaluates a polynomial, given as a list of coefficients from the highest power down, at x, as in peval({1, -3, 2}, 5)."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+    use num_rational::BigRational;
+
+    use crate::test_expr;
+
+    #[test]
+    fn test_peval() {
+        test_expr!("peval({1, -3, 2}, 5)", StackItem::Integer(12.into(), 10));
+        test_expr!("peval([2, 0, 1], 0.5)", StackItem::Number(1.5, 10));
+        test_expr!("peval({7}, 100)", StackItem::Integer(7.into(), 10));
+    }
+
+    #[test]
+    fn test_peval_exact_and_complex() {
+        test_expr!(
+            "peval({1 0 0}, 2/3)",
+            StackItem::Rational(BigRational::new(4.into(), 9.into()))
+        );
+        test_expr!("peval({1 0 1}, 1i)", StackItem::Number(0.0, 10));
+        test_expr!(
+            "peval({1 1}, 1i)",
+            StackItem::Complex(Complex64::new(1.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_peval_invalid() {
+        let mut state = crate::state::RpnState::new().unwrap();
+        assert!(state.push_str("peval({}, 2)").is_err());
+        assert!(state.push_str("peval(3, 2)").is_err());
+    }
+}
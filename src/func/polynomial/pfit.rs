@@ -0,0 +1,119 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_ternary, Func},
+    stack::{item::StackItem, matrix},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{degree_operand, items};
+
+pub struct PFitFunc {}
+
+impl PFitFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PFitFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_ternary(state, |xs, ys, degree| {
+            fit(items(xs)?, items(ys)?, degree_operand(degree)?)
+        })
+    }
+
+    fn name(&self) -> &str {
+        "pfit"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["polyfit"]
+    }
+
+    fn description(&self) -> &str {
+        "The pfit function fits a polynomial of the given degree to lists of x and y values by least squares, as in pfit({0 1 2}, {1 3 7}, 2), and returns its coefficients from the highest power down."
+    }
+}
+
+/// Solves the normal equations (VᵀV) c = Vᵀy for the Vandermonde matrix V of the x
+/// values, keeping exact inputs exact.
+fn fit(xs: &[StackItem], ys: &[StackItem], degree: usize) -> Result<StackItem> {
+    if xs.len() != ys.len() {
+        return Err(anyhow!(
+            "expected as many y values as x values but found {} and {}",
+            ys.len(),
+            xs.len()
+        ));
+    }
+    if xs.len() <= degree {
+        return Err(anyhow!(
+            "fitting a polynomial of degree {degree} needs at least {} points",
+            degree + 1
+        ));
+    }
+    let rows = xs
+        .iter()
+        .map(|x| {
+            (0..=degree)
+                .rev()
+                .map(|power| x.pow(&StackItem::Integer(power.into(), 10)))
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let v = StackItem::Matrix(rows);
+    let vt = matrix::transpose(&v)?;
+    let normal = matrix::multiply(&vt, &v)?;
+    let rhs = matrix::multiply(&vt, &StackItem::Vector(ys.to_vec()))?;
+    match matrix::solve(&normal, &rhs) {
+        Ok(StackItem::Vector(coefficients)) => Ok(StackItem::List(coefficients)),
+        Ok(item) => Err(anyhow!("unexpected solution {item}")),
+        Err(_) => Err(anyhow!(
+            "cannot fit a polynomial of degree {degree}, the x values need at least {} distinct values",
+            degree + 1
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{stack::item::StackItem, test_expr};
+
+    fn ints(v: &[i64]) -> Vec<StackItem> {
+        v.iter()
+            .map(|v| StackItem::Integer((*v).into(), 10))
+            .collect()
+    }
+
+    #[test]
+    fn test_pfit_exact() {
+        // y = 2x^2 - x + 1
+        test_expr!(
+            "pfit({0 1 2 3}, {1 2 7 16}, 2)",
+            StackItem::List(ints(&[2, -1, 1]))
+        );
+    }
+
+    #[test]
+    fn test_pfit_least_squares() {
+        test_expr!(
+            "pfit({0 1 2}, {0 1 1}, 1)",
+            StackItem::List(vec![
+                StackItem::Rational(BigRational::new(1.into(), 2.into())),
+                StackItem::Rational(BigRational::new(1.into(), 6.into())),
+            ])
+        );
+        test_expr!("pfit([1, 2, 3], [4, 4, 4], 0)", StackItem::List(ints(&[4])));
+    }
+
+    #[test]
+    fn test_pfit_invalid() {
+        let mut state = crate::state::RpnState::new().unwrap();
+        assert!(state.push_str("pfit({0 1}, {1 2 3}, 1)").is_err());
+        assert!(state.push_str("pfit({0 1}, {1 2}, 2)").is_err());
+        assert!(state.push_str("pfit({1 1 1}, {1 2 3}, 1)").is_err());
+    }
+}
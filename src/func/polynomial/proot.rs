@@ -0,0 +1,338 @@
+use std::f64::consts::TAU;
+
+use anyhow::{anyhow, Result};
+use num_complex::Complex64;
+use num_traits::Zero;
+
+use crate::{
+    func::Func,
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::{replace::ReplaceUndoEvent, UndoEvent},
+};
+
+use super::{degree_operand, items};
+
+const MAX_ITERATIONS: usize = 500;
+const POLISH_STEPS: usize = 5;
+// parts of a root this much smaller than the root itself are rounding noise
+const SNAP_TOLERANCE: f64 = 1e-12;
+
+pub struct PRootFunc {}
+
+impl PRootFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PRootFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let top = state
+            .stack
+            .peek(0)
+            .ok_or_else(|| anyhow!("Not enough arguments"))?;
+        // either a list of coefficients, or the degree n above n+1 coefficients
+        let count = match top {
+            StackItem::List(_) | StackItem::Vector(_) => Some(1),
+            _ => degree_operand(top)?.checked_add(2),
+        }
+        .filter(|count| *count <= state.stack.len())
+        .ok_or_else(|| anyhow!("Not enough arguments"))?;
+        let consumed = (0..count)
+            .rev()
+            .map(|i| state.stack.peek(i).unwrap().clone())
+            .collect::<Vec<_>>();
+        let coefficients = if count == 1 {
+            items(&consumed[0])?
+        } else {
+            &consumed[..count - 1]
+        };
+        let coefficients = coefficients
+            .iter()
+            .map(|c| {
+                c.to_complex()
+                    .ok_or_else(|| anyhow!("expected numeric coefficients but found {c}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let roots = roots(&coefficients)?
+            .into_iter()
+            .map(StackItem::from_complex)
+            .collect();
+        let result = state.apply_modes(StackItem::List(roots));
+        state.stack.pop_n(count)?;
+        state.stack.push(result.clone());
+        Ok(Box::new(ReplaceUndoEvent::new(consumed, vec![result])))
+    }
+
+    fn name(&self) -> &str {
+        "proot"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["roots"]
+    }
+
+    fn description(&self) -> &str {
+        "The proot function returns a list of all the roots, complex ones included, of a polynomial given as a list of coefficients from the highest power down, or as the coefficients followed by the degree."
+    }
+}
+
+/// Finds all the roots of a polynomial with coefficients from the highest power down,
+/// sorted by real and then imaginary part.
+fn roots(coefficients: &[Complex64]) -> Result<Vec<Complex64>> {
+    let start = coefficients
+        .iter()
+        .position(|c| !c.is_zero())
+        .ok_or_else(|| anyhow!("all the coefficients are zero"))?;
+    let mut c = coefficients[start..].to_vec();
+    if c.len() == 1 {
+        return Err(anyhow!("a constant polynomial has no roots"));
+    }
+    let mut roots = vec![];
+    while c.last().is_some_and(|c| c.is_zero()) {
+        c.pop();
+        roots.push(Complex64::zero());
+    }
+    let found = match c.len() - 1 {
+        0 => vec![],
+        1 => vec![-c[1] / c[0]],
+        2 => quadratic(c[0], c[1], c[2]),
+        3 => cubic(&c),
+        _ => durand_kerner(&c),
+    };
+    let real_coefficients = c.iter().all(|c| c.im == 0.0);
+    roots.extend(
+        found
+            .into_iter()
+            .map(|z| snap(polish(&c, z), real_coefficients)),
+    );
+    roots.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+    Ok(roots)
+}
+
+/// The quadratic formula, arranged to avoid cancellation between -b and the square root.
+fn quadratic(a: Complex64, b: Complex64, c: Complex64) -> Vec<Complex64> {
+    let root = (b * b - a * c * 4.0).sqrt();
+    let q = if (b.conj() * root).re >= 0.0 {
+        -(b + root) / 2.0
+    } else {
+        -(b - root) / 2.0
+    };
+    if q.is_zero() {
+        return vec![Complex64::zero(), Complex64::zero()];
+    }
+    vec![q / a, c / q]
+}
+
+/// Cardano's formula on the depressed cubic t^3 + p t + q, where x = t - a/3.
+fn cubic(c: &[Complex64]) -> Vec<Complex64> {
+    let (a, b, d) = (c[1] / c[0], c[2] / c[0], c[3] / c[0]);
+    let p = b - a * a / 3.0;
+    let q = a * a * a * 2.0 / 27.0 - a * b / 3.0 + d;
+    let root = (q * q / 4.0 + p * p * p / 27.0).sqrt();
+    // either sign works, the larger avoids cancellation
+    let (plus, minus) = (-q / 2.0 + root, -q / 2.0 - root);
+    let u = if plus.norm() >= minus.norm() {
+        plus
+    } else {
+        minus
+    }
+    .cbrt();
+    let omega = Complex64::from_polar(1.0, TAU / 3.0);
+    (0..3)
+        .map(|k| {
+            let u = u * omega.powi(k);
+            let t = if u.is_zero() { u } else { u - p / (u * 3.0) };
+            t - a / 3.0
+        })
+        .collect()
+}
+
+/// Refines all the roots at once, each step dividing p(z) by the product of the
+/// distances to the other estimates.
+fn durand_kerner(c: &[Complex64]) -> Vec<Complex64> {
+    let n = c.len() - 1;
+    let monic = c.iter().map(|v| v / c[0]).collect::<Vec<_>>();
+    // all the roots lie within this radius (Cauchy's bound)
+    let radius = 1.0 + monic[1..].iter().map(|v| v.norm()).fold(0.0, f64::max);
+    let mut z = (0..n)
+        .map(|k| Complex64::from_polar(radius, TAU * k as f64 / n as f64 + 0.4))
+        .collect::<Vec<_>>();
+    for _ in 0..MAX_ITERATIONS {
+        let mut change = 0.0f64;
+        for i in 0..n {
+            let denominator = (0..n)
+                .filter(|j| *j != i)
+                .fold(Complex64::new(1.0, 0.0), |acc, j| acc * (z[i] - z[j]));
+            let delta = horner(&monic, z[i]) / denominator;
+            if !delta.is_finite() {
+                continue;
+            }
+            z[i] -= delta;
+            change = change.max(delta.norm() / z[i].norm().max(1.0));
+        }
+        if change < f64::EPSILON {
+            break;
+        }
+    }
+    z
+}
+
+fn horner(c: &[Complex64], z: Complex64) -> Complex64 {
+    c.iter().fold(Complex64::zero(), |acc, c| acc * z + c)
+}
+
+/// A few Newton steps on the original polynomial, kept only while they improve the root.
+fn polish(c: &[Complex64], mut z: Complex64) -> Complex64 {
+    let derivative = c[..c.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(i, v)| v * (c.len() - 1 - i) as f64)
+        .collect::<Vec<_>>();
+    let mut value = horner(c, z);
+    for _ in 0..POLISH_STEPS {
+        let slope = horner(&derivative, z);
+        if value.is_zero() || slope.is_zero() {
+            break;
+        }
+        let next = z - value / slope;
+        let next_value = horner(c, next);
+        if !next_value.is_finite() || next_value.norm() >= value.norm() {
+            break;
+        }
+        (z, value) = (next, next_value);
+    }
+    z
+}
+
+/// Clears parts that are rounding noise, such as the imaginary part of a real root
+/// of a polynomial with real coefficients.
+fn snap(z: Complex64, real_coefficients: bool) -> Complex64 {
+    let tolerance = SNAP_TOLERANCE * z.norm();
+    let re = if z.re.abs() <= tolerance { 0.0 } else { z.re };
+    let im = if real_coefficients && z.im.abs() <= tolerance {
+        0.0
+    } else {
+        z.im
+    };
+    Complex64::new(re, im)
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+
+    use crate::{stack::item::StackItem, state::RpnState, test_expr};
+
+    fn numbers(v: &[f64]) -> Vec<StackItem> {
+        v.iter().map(|v| StackItem::Number(*v, 10)).collect()
+    }
+
+    fn roots(expr: &str) -> Vec<Complex64> {
+        let mut state = RpnState::new().unwrap();
+        state.push_str(expr).unwrap();
+        assert_eq!(1, state.stack.len());
+        match state.stack.peek(0).unwrap() {
+            StackItem::List(items) => items.iter().map(|v| v.to_complex().unwrap()).collect(),
+            item => panic!("expected a list but found {item}"),
+        }
+    }
+
+    fn assert_roots(expected: &[Complex64], actual: &[Complex64]) {
+        assert_eq!(expected.len(), actual.len(), "{actual:?}");
+        for (e, a) in expected.iter().zip(actual) {
+            assert!(
+                (e - a).norm() < 1e-12,
+                "expected {expected:?} but found {actual:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_proot_quadratic() {
+        test_expr!("proot({1, -3, 2})", StackItem::List(numbers(&[1.0, 2.0])));
+        test_expr!(
+            "proot([1, 0, 1])",
+            StackItem::List(vec![
+                StackItem::Complex(Complex64::new(0.0, -1.0)),
+                StackItem::Complex(Complex64::new(0.0, 1.0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_proot_from_stack() {
+        let mut state = RpnState::new().unwrap();
+        for item in ["1", "-2", "1", "2"] {
+            state.push_str(item).unwrap();
+        }
+        state.push_str("proot").unwrap();
+        assert_eq!(
+            StackItem::List(numbers(&[1.0, 1.0])),
+            *state.stack.peek(0).unwrap()
+        );
+        state.undo().unwrap();
+        assert_eq!(4, state.stack.len());
+        assert_eq!(
+            StackItem::Integer(2.into(), 10),
+            *state.stack.peek(0).unwrap()
+        );
+        state.redo().unwrap();
+        assert_eq!(1, state.stack.len());
+    }
+
+    #[test]
+    fn test_proot_linear_and_zero_roots() {
+        test_expr!("proot({2, -1})", StackItem::List(numbers(&[0.5])));
+        test_expr!(
+            "proot({0, 1, -1, 0})",
+            StackItem::List(numbers(&[0.0, 1.0]))
+        );
+    }
+
+    #[test]
+    fn test_proot_cubic() {
+        let c = |re| Complex64::new(re, 0.0);
+        assert_roots(&[c(1.0), c(2.0), c(3.0)], &roots("proot({1, -6, 11, -6})"));
+        let h = 3f64.sqrt() / 2.0;
+        assert_roots(
+            &[Complex64::new(-0.5, -h), Complex64::new(-0.5, h), c(1.0)],
+            &roots("proot({1, 0, 0, -1})"),
+        );
+    }
+
+    #[test]
+    fn test_proot_higher_degree() {
+        let c = |re, im| Complex64::new(re, im);
+        assert_roots(
+            &[c(-1.0, 0.0), c(0.0, -1.0), c(0.0, 1.0), c(1.0, 0.0)],
+            &roots("proot({1, 0, 0, 0, -1})"),
+        );
+        let expected = (1..=6).map(|v| c(v as f64, 0.0)).collect::<Vec<_>>();
+        let found = roots("proot({1, -21, 175, -735, 1624, -1764, 720})");
+        assert_eq!(6, found.len());
+        for (e, a) in expected.iter().zip(&found) {
+            assert!((e - a).norm() < 1e-8, "{found:?}");
+        }
+    }
+
+    #[test]
+    fn test_proot_huge_degree() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("1").unwrap();
+        state.push_str("18446744073709551615").unwrap();
+        assert!(state.push_str("proot").is_err());
+        state.stack.push(StackItem::Number(1e30, 10));
+        assert!(state.push_str("proot").is_err());
+        assert_eq!(3, state.stack.len());
+    }
+
+    #[test]
+    fn test_proot_invalid() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("proot({0 0})").is_err());
+        assert!(state.push_str("proot({5})").is_err());
+        assert!(state.push_str("proot({1 'a'})").is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use anyhow::{anyhow, Result};
+use signedness::SignednessFunc;
+use word_size::WordSizeFunc;
+
+use crate::{
+    stack::item::StackItem,
+    state::{integer_mode::IntegerMode, RpnState},
+    undo_action::UndoEvent,
+};
+
+pub mod signedness;
+pub mod word_size;
+
+pub fn programmer_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(WordSizeFunc::new("ws8", Some(8))));
+    state.register_function(Box::new(WordSizeFunc::new("ws16", Some(16))));
+    state.register_function(Box::new(WordSizeFunc::new("ws32", Some(32))));
+    state.register_function(Box::new(WordSizeFunc::new("ws64", Some(64))));
+    state.register_function(Box::new(WordSizeFunc::new("ws128", Some(128))));
+    state.register_function(Box::new(WordSizeFunc::new("wsinf", None)));
+    state.register_function(Box::new(SignednessFunc::new("signed", true)));
+    state.register_function(Box::new(SignednessFunc::new("unsigned", false)));
+}
+
+/// Switches the integer mode and wraps the integers already on the stack to the new
+/// word size and signedness, so they read the same as freshly computed results.
+fn set_integer_mode(state: &mut RpnState, new_mode: IntegerMode) -> Box<dyn UndoEvent> {
+    let previous_mode = state.integer_mode;
+    let previous_items = state.stack.iter().cloned().collect::<Vec<_>>();
+    state.integer_mode = new_mode;
+    let new_items = previous_items
+        .iter()
+        .map(|item| match item {
+            StackItem::Integer(_, display_base) => new_mode.wrap(item.clone(), *display_base),
+            _ => item.clone(),
+        })
+        .collect::<Vec<_>>();
+    replace_items(state, &new_items);
+    Box::new(IntegerModeUndoEvent {
+        previous_mode,
+        new_mode,
+        previous_items,
+        new_items,
+    })
+}
+
+fn replace_items(state: &mut RpnState, items: &[StackItem]) {
+    while state.stack.pop().is_some() {}
+    for item in items {
+        state.stack.push(item.clone());
+    }
+}
+
+#[derive(Debug)]
+pub struct IntegerModeUndoEvent {
+    previous_mode: IntegerMode,
+    new_mode: IntegerMode,
+    // the whole stack before and after its integers were wrapped
+    previous_items: Vec<StackItem>,
+    new_items: Vec<StackItem>,
+}
+
+impl IntegerModeUndoEvent {
+    fn check_stack(&self, state: &RpnState, expected: &[StackItem]) -> Result<()> {
+        if !state.stack.iter().eq(expected.iter()) {
+            return Err(anyhow!(
+                "unexpected state, expected the stack to be unchanged since the integer mode was set"
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl UndoEvent for IntegerModeUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.integer_mode != self.new_mode {
+            return Err(anyhow!(
+                "expected current mode to be {:?} but was {:?}",
+                self.new_mode,
+                state.integer_mode
+            ));
+        }
+        self.check_stack(state, &self.new_items)?;
+        state.integer_mode = self.previous_mode;
+        replace_items(state, &self.previous_items);
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.integer_mode != self.previous_mode {
+            return Err(anyhow!(
+                "expected current mode to be {:?} but was {:?}",
+                self.previous_mode,
+                state.integer_mode
+            ));
+        }
+        self.check_stack(state, &self.previous_items)?;
+        state.integer_mode = self.new_mode;
+        replace_items(state, &self.new_items);
+        Ok(())
+    }
+}
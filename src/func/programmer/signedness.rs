@@ -0,0 +1,94 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{integer_mode::IntegerMode, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::set_integer_mode;
+
+pub struct SignednessFunc {
+    name: String,
+    signed: bool,
+}
+
+impl SignednessFunc {
+    pub fn new(name: &str, signed: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            signed,
+        }
+    }
+}
+
+impl Func for SignednessFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let new_mode = IntegerMode {
+            signed: self.signed,
+            ..state.integer_mode
+        };
+        Ok(set_integer_mode(state, new_mode))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.signed {
+            "Treats integers as signed two's complement values when a word size is set"
+        } else {
+            "Treats integers as unsigned values when a word size is set"
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_unsigned() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws16").unwrap();
+        state.push_str("unsigned").unwrap();
+        assert!(!state.integer_mode.signed);
+
+        state.push_str("0 - 1").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0xffff), 10),
+            *state.stack.peek(0).unwrap()
+        );
+        state.undo().unwrap();
+
+        state.undo().unwrap();
+        assert!(state.integer_mode.signed);
+
+        state.redo().unwrap();
+        assert!(!state.integer_mode.signed);
+    }
+
+    #[test]
+    fn test_signedness_wraps_stack() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws8").unwrap();
+        state.push_str("0 - 1").unwrap();
+        state.push_str("unsigned").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0xff), 10),
+            *state.stack.peek(0).unwrap()
+        );
+        state.push_str("signed").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(-1), 10),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{integer_mode::IntegerMode, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::set_integer_mode;
+
+pub struct WordSizeFunc {
+    name: String,
+    word_size: Option<u32>,
+}
+
+impl WordSizeFunc {
+    pub fn new(name: &str, word_size: Option<u32>) -> Self {
+        Self {
+            name: name.to_string(),
+            word_size,
+        }
+    }
+}
+
+impl Func for WordSizeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let new_mode = IntegerMode {
+            word_size: self.word_size,
+            ..state.integer_mode
+        };
+        Ok(set_integer_mode(state, new_mode))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        match self.word_size {
+            Some(_) => "Sets the integer word size, integer results wrap using two's complement",
+            None => "Removes the integer word size limit, integers grow as needed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{
+        stack::item::{StackItem, StackItemToStringOpts},
+        state::RpnState,
+    };
+
+    #[test]
+    fn test_word_size() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws32").unwrap();
+        assert_eq!(Some(32), state.integer_mode.word_size);
+
+        state.undo().unwrap();
+        assert_eq!(None, state.integer_mode.word_size);
+
+        state.redo().unwrap();
+        assert_eq!(Some(32), state.integer_mode.word_size);
+    }
+
+    #[test]
+    fn test_word_size_wraps() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws8").unwrap();
+        state.push_str("0xff + 2").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(1), 10),
+            *state.stack.peek(0).unwrap()
+        );
+
+        state.push_str("7 / 2").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(3), 10),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_word_size_wraps_stack() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws32").unwrap();
+        state.push_str("0x1234").unwrap();
+        state.push_str("300").unwrap();
+        state.push_str("1.5").unwrap();
+        state.push_str("ws8").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0x34), 16),
+            *state.stack.peek(2).unwrap()
+        );
+        assert_eq!(
+            StackItem::Integer(BigInt::from(44), 10),
+            *state.stack.peek(1).unwrap()
+        );
+        assert_eq!(StackItem::Number(1.5, 10), *state.stack.peek(0).unwrap());
+
+        state.undo().unwrap();
+        assert_eq!(Some(32), state.integer_mode.word_size);
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0x1234), 16),
+            *state.stack.peek(2).unwrap()
+        );
+        assert_eq!(
+            StackItem::Integer(BigInt::from(300), 10),
+            *state.stack.peek(1).unwrap()
+        );
+
+        state.redo().unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(44), 10),
+            *state.stack.peek(1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_word_size_keeps_base_on_division() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws16").unwrap();
+        state.push_str("0x10 / 0x3").unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(5), 16),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_word_size_hex_twos_complement() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("ws32").unwrap();
+        state.push_str("0x0 - 0x1").unwrap();
+        let v = state.stack.peek(0).unwrap();
+        assert_eq!(StackItem::Integer(BigInt::from(-1), 16), *v);
+        assert_eq!(
+            "0xffff ffff",
+            v.to_string_opts(
+                &StackItemToStringOpts {
+                    base: None,
+                    precision: None,
+                    rational_display: None,
+                    left_pad_with_zeros: true,
+                    include_base_prefix: true,
+                },
+                &state
+            )
+        );
+    }
+}
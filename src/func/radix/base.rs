@@ -0,0 +1,105 @@
+use anyhow::{anyhow, Result};
+use num_bigint::BigInt;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::radix_operand;
+
+pub struct BaseFunc {}
+
+impl BaseFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for BaseFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            let radix = radix_operand(b)?;
+            match a {
+                StackItem::Integer(v, _) => Ok(StackItem::Integer(v.clone(), radix)),
+                StackItem::Number(v, _) => Ok(StackItem::Number(*v, radix)),
+                // digits written in the radix, with any grouping spaces
+                StackItem::String(s) => {
+                    let digits = s.split_whitespace().collect::<String>();
+                    BigInt::parse_bytes(digits.as_bytes(), radix as u32)
+                        .map(|v| StackItem::Integer(v, radix))
+                        .ok_or_else(|| anyhow!("'{s}' is not a number in radix {radix}"))
+                }
+                _ => Err(anyhow!(
+                    "expected a number or a string of digits but found {a}"
+                )),
+            }
+        })
+    }
+
+    fn name(&self) -> &str {
+        "base"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The base function shows a number in any radix from 2 to 36, as in base(255, 36), or reads a string of digits in that radix, as in base('1z', 36)."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState, test_expr};
+
+    #[test]
+    fn test_base() {
+        test_expr!("base(71, 36)", StackItem::Integer(71.into(), 36));
+        test_expr!("base(0xff, 10)", StackItem::Integer(255.into(), 10));
+        test_expr!("base('1z', 36)", StackItem::Integer(71.into(), 36));
+        test_expr!("base('-10 0000', 2)", StackItem::Integer((-32).into(), 2));
+    }
+
+    #[test]
+    fn test_base_display() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("base(71, 36)").unwrap();
+        assert_eq!("36r1z", state.stack.peek(0).unwrap().to_string());
+        state.push_str("base(-5, 2)").unwrap();
+        assert_eq!("-0b0101", state.stack.peek(0).unwrap().to_string());
+        state.push_str("base(1.5, 3)").unwrap();
+        assert_eq!("1.5", state.stack.peek(0).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_base_undo() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("100").unwrap();
+        state.push_str("7").unwrap();
+        state.push_str("base").unwrap();
+        assert_eq!(
+            StackItem::Integer(100.into(), 7),
+            *state.stack.peek(0).unwrap()
+        );
+        state.undo().unwrap();
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            StackItem::Integer(100.into(), 10),
+            *state.stack.peek(1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_base_invalid() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("base(10, 37)").is_err());
+        assert!(state.push_str("base(10, 1)").is_err());
+        assert!(state.push_str("base(10, 2.5)").is_err());
+        assert!(state.push_str("base('19', 8)").is_err());
+        assert!(state.push_str("base([1], 8)").is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{anyhow, Result};
+
+use crate::{func::Func, stack::item::StackItem, state::RpnState, undo_action::UndoEvent};
+
+use super::radix_operand;
+
+pub struct InfoBasesFunc {}
+
+impl InfoBasesFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for InfoBasesFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let Some(a) = state.stack.peek(0).cloned() else {
+            return Err(anyhow!("Not enough arguments"));
+        };
+        let new_bases = match &a {
+            StackItem::List(items) | StackItem::Vector(items) => items
+                .iter()
+                .map(radix_operand)
+                .collect::<Result<Vec<_>>>()?,
+            _ => {
+                return Err(anyhow!(
+                    "expected a list of radixes such as {{16, 10, 8, 2}} but found {a}"
+                ))
+            }
+        };
+
+        state.stack.pop();
+        let previous_bases = std::mem::replace(&mut state.info_bases, new_bases.clone());
+        Ok(Box::new(InfoBasesUndoEvent {
+            a,
+            previous_bases,
+            new_bases,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "infobases"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The infobases function sets the radixes the info pane shows the top value in, from a list such as {16, 10, 8, 2}"
+    }
+}
+
+#[derive(Debug)]
+pub struct InfoBasesUndoEvent {
+    a: StackItem,
+    previous_bases: Vec<u8>,
+    new_bases: Vec<u8>,
+}
+
+impl UndoEvent for InfoBasesUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.info_bases != self.new_bases {
+            return Err(anyhow!(
+                "expected current info bases to be {:?} but was {:?}",
+                self.new_bases,
+                state.info_bases
+            ));
+        }
+        state.info_bases = self.previous_bases.clone();
+        state.stack.push(self.a.clone());
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.info_bases != self.previous_bases {
+            return Err(anyhow!(
+                "expected current info bases to be {:?} but was {:?}",
+                self.previous_bases,
+                state.info_bases
+            ));
+        }
+        if state.stack.peek(0) != Some(&self.a) {
+            return Err(anyhow!(
+                "unexpected state for redo, expected item on the stack to be the same as argument 0"
+            ));
+        }
+        state.stack.pop();
+        state.info_bases = self.new_bases.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::RpnState;
+
+    #[test]
+    fn test_info_bases() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("{10, 36, 3}").unwrap();
+        state.push_str("infobases").unwrap();
+        assert_eq!(vec![10, 36, 3], state.info_bases);
+        assert_eq!(0, state.stack.len());
+
+        state.undo().unwrap();
+        assert_eq!(vec![16, 10, 8, 2], state.info_bases);
+        assert_eq!(1, state.stack.len());
+
+        state.redo().unwrap();
+        assert_eq!(vec![10, 36, 3], state.info_bases);
+        assert_eq!(0, state.stack.len());
+    }
+
+    #[test]
+    fn test_info_bases_invalid() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("{16, 40}").unwrap();
+        assert!(state.push_str("infobases").is_err());
+        state.push_str("16").unwrap();
+        assert!(state.push_str("infobases").is_err());
+        assert_eq!(2, state.stack.len());
+        assert_eq!(vec![16, 10, 8, 2], state.info_bases);
+    }
+}
@@ -0,0 +1,25 @@
+use anyhow::{anyhow, Result};
+use base::BaseFunc;
+use info_bases::InfoBasesFunc;
+use num_traits::ToPrimitive;
+
+use crate::{stack::item::StackItem, state::RpnState};
+
+pub mod base;
+pub mod info_bases;
+
+pub fn radix_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(BaseFunc::new()));
+    state.register_function(Box::new(InfoBasesFunc::new()));
+}
+
+/// Returns a radix from 2 to 36, the range that digits 0-9 and a-z can write.
+fn radix_operand(item: &StackItem) -> Result<u8> {
+    match item {
+        StackItem::Integer(v, _) => v.to_u8(),
+        StackItem::Number(v, _) if item.is_integer() => v.to_u8(),
+        _ => None,
+    }
+    .filter(|radix| (2..=36).contains(radix))
+    .ok_or_else(|| anyhow!("radix must be an integer from 2 to 36 but found {item}"))
+}
@@ -0,0 +1,161 @@
+use anyhow::{anyhow, Result};
+use rand::rngs::StdRng;
+use rand_exp::RandExpFunc;
+use rand_int::RandIntFunc;
+use rand_normal::RandNormalFunc;
+use rand_uniform::RandUniformFunc;
+use seed::SeedFunc;
+
+use crate::{stack::item::StackItem, state::RpnState, undo_action::UndoEvent};
+
+pub mod rand_exp;
+pub mod rand_int;
+pub mod rand_normal;
+pub mod rand_uniform;
+pub mod seed;
+
+pub fn random_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(RandExpFunc::new()));
+    state.register_function(Box::new(RandIntFunc::new()));
+    state.register_function(Box::new(RandNormalFunc::new()));
+    state.register_function(Box::new(RandUniformFunc::new()));
+    state.register_function(Box::new(SeedFunc::new()));
+}
+
+/// Replaces the top `args` items with a value drawn from the generator. The undo event
+/// keeps the value and the generator state on each side of the draw, so redo pushes the
+/// same value and undo rewinds the generator.
+fn execute_random<F>(state: &mut RpnState, args: usize, generate: F) -> Result<Box<dyn UndoEvent>>
+where
+    F: FnOnce(&[StackItem], &mut StdRng) -> Result<StackItem>,
+{
+    if state.stack.len() < args {
+        return Err(anyhow!("Not enough arguments"));
+    }
+    let removed = (0..args)
+        .rev()
+        .map(|i| state.stack.peek(i).unwrap().clone())
+        .collect::<Vec<_>>();
+    let previous_rng = state.rng.clone();
+    let value = match generate(&removed, &mut state.rng) {
+        Ok(value) => state.apply_modes(value),
+        Err(e) => {
+            state.rng = previous_rng;
+            return Err(e);
+        }
+    };
+    state.stack.pop_n(args)?;
+    state.stack.push(value.clone());
+    Ok(Box::new(RandomUndoEvent {
+        removed,
+        value,
+        previous_rng,
+        new_rng: state.rng.clone(),
+    }))
+}
+
+/// Returns a uniform value in (0, 1], safe to take the logarithm of.
+fn unit_interval(rng: &mut StdRng) -> f64 {
+    1.0 - rand::Rng::random::<f64>(rng)
+}
+
+#[derive(Debug)]
+pub struct RandomUndoEvent {
+    removed: Vec<StackItem>,
+    value: StackItem,
+    previous_rng: StdRng,
+    new_rng: StdRng,
+}
+
+impl UndoEvent for RandomUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.stack.peek(0) != Some(&self.value) {
+            return Err(anyhow!(
+                "unexpected state for undo, expected {} on the top of the stack",
+                self.value
+            ));
+        }
+        state.stack.pop();
+        for item in &self.removed {
+            state.stack.push(item.clone());
+        }
+        state.rng = self.previous_rng.clone();
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.stack.len() < self.removed.len() {
+            return Err(anyhow!(
+                "unexpected state for redo, expected at least {} items on the stack",
+                self.removed.len()
+            ));
+        }
+        for (i, item) in self.removed.iter().rev().enumerate() {
+            if state.stack.peek(i) != Some(item) {
+                return Err(anyhow!(
+                    "unexpected state for redo, expected item {i} on the stack to be {item}"
+                ));
+            }
+        }
+        state.stack.pop_n(self.removed.len())?;
+        state.stack.push(self.value.clone());
+        state.rng = self.new_rng.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    fn draw(state: &mut RpnState, s: &str) -> f64 {
+        state.push_str(s).unwrap();
+        state.stack.peek(0).unwrap().to_f64().unwrap()
+    }
+
+    #[test]
+    fn test_seed_repeats_values() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("seed(42)").unwrap();
+        let first = [draw(&mut state, "rand"), draw(&mut state, "randn")];
+
+        let mut state = RpnState::new().unwrap();
+        state.push_str("seed(42)").unwrap();
+        let second = [draw(&mut state, "rand"), draw(&mut state, "randn")];
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_undo_redo_replays_value() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("seed(7)").unwrap();
+        let v = draw(&mut state, "rand");
+
+        state.undo().unwrap();
+        assert_eq!(0, state.stack.len());
+        state.redo().unwrap();
+        assert_eq!(v, state.stack.peek(0).unwrap().to_f64().unwrap());
+
+        // undo rewinds the generator, so drawing again gives the same value
+        state.undo().unwrap();
+        assert_eq!(v, draw(&mut state, "rand"));
+    }
+
+    #[test]
+    fn test_redo_checks_stack() {
+        let mut state = RpnState::new().unwrap();
+        for s in ["1", "6", "randint"] {
+            state.push_str(s).unwrap();
+        }
+        state.undo().unwrap();
+        state.stack.pop();
+        state.stack.push(StackItem::Integer(7.into(), 10));
+        let err = state.redo().unwrap_err();
+        assert!(err.to_string().contains("unexpected state"), "{err}");
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(7.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::Result;
+
+use crate::{func::Func, stack::item::StackItem, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_random, unit_interval};
+
+pub struct RandExpFunc {}
+
+impl RandExpFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for RandExpFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_random(state, 0, |_, rng| {
+            Ok(StackItem::Number(-unit_interval(rng).ln(), 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "randexp"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The randexp function pushes a random number from the exponential distribution with mean 1. Divide by a rate to get other means."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::RpnState;
+
+    #[test]
+    fn test_rand_exp() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("seed(1)").unwrap();
+        let values = (0..500)
+            .map(|_| {
+                state.push_str("randexp").unwrap();
+                state.stack.pop().unwrap().to_f64().unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert!(values.iter().all(|v| *v >= 0.0));
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        assert!((mean - 1.0).abs() < 0.1, "mean {mean}");
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::{anyhow, Result};
+use num_traits::ToPrimitive;
+use rand::Rng;
+
+use crate::{func::Func, stack::item::StackItem, state::RpnState, undo_action::UndoEvent};
+
+use super::execute_random;
+
+pub struct RandIntFunc {}
+
+impl RandIntFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for RandIntFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_random(state, 2, |args, rng| {
+            let (a, b) = (bound(&args[0])?, bound(&args[1])?);
+            if a > b {
+                return Err(anyhow!(
+                    "the lower bound {a} is greater than the upper bound {b}"
+                ));
+            }
+            Ok(StackItem::Integer(rng.random_range(a..=b).into(), 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "randint"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The randint function returns a random integer from a to b inclusive."
+    }
+}
+
+fn bound(item: &StackItem) -> Result<i64> {
+    let v = match item {
+        StackItem::Integer(v, _) => v.to_i64(),
+        _ if item.is_integer() => item.to_f64().and_then(|v| v.to_i64()),
+        _ => None,
+    };
+    v.ok_or_else(|| anyhow!("expected a 64-bit integer bound but found {item}"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use num_traits::ToPrimitive;
+
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_rand_int() {
+        let mut state = RpnState::new().unwrap();
+        let mut seen = HashSet::new();
+        for _ in 0..100 {
+            state.push_str("randint(-2, 2)").unwrap();
+            match state.stack.pop().unwrap() {
+                StackItem::Integer(v, _) => seen.insert(v.to_i64().unwrap()),
+                v => panic!("expected an integer but found {v}"),
+            };
+        }
+        assert_eq!(HashSet::from([-2, -1, 0, 1, 2]), seen);
+    }
+
+    #[test]
+    fn test_rand_int_invalid() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("5").unwrap();
+        state.push_str("1").unwrap();
+        assert!(state.push_str("randint").is_err());
+        assert_eq!(2, state.stack.len());
+        assert!(state.push_str("randint(0.5, 1)").is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use std::f64::consts::TAU;
+
+use anyhow::Result;
+
+use crate::{func::Func, stack::item::StackItem, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_random, unit_interval};
+
+pub struct RandNormalFunc {}
+
+impl RandNormalFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for RandNormalFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_random(state, 0, |_, rng| {
+            // Box-Muller transform
+            let radius = (-2.0 * unit_interval(rng).ln()).sqrt();
+            let angle = TAU * unit_interval(rng);
+            Ok(StackItem::Number(radius * angle.cos(), 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "randn"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["randnorm"]
+    }
+
+    fn description(&self) -> &str {
+        "The randn function pushes a random number from the standard normal distribution, with mean 0 and standard deviation 1."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::RpnState;
+
+    #[test]
+    fn test_rand_normal() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("seed(1)").unwrap();
+        let values = (0..500)
+            .map(|_| {
+                state.push_str("randn").unwrap();
+                state.stack.pop().unwrap().to_f64().unwrap()
+            })
+            .collect::<Vec<_>>();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+        assert!(mean.abs() < 0.1, "mean {mean}");
+        assert!((variance - 1.0).abs() < 0.2, "variance {variance}");
+    }
+}
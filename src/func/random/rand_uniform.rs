@@ -0,0 +1,50 @@
+use anyhow::Result;
+use rand::Rng;
+
+use crate::{func::Func, stack::item::StackItem, state::RpnState, undo_action::UndoEvent};
+
+use super::execute_random;
+
+pub struct RandUniformFunc {}
+
+impl RandUniformFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for RandUniformFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_random(state, 0, |_, rng| {
+            Ok(StackItem::Number(rng.random::<f64>(), 10))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "rand"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["random"]
+    }
+
+    fn description(&self) -> &str {
+        "The rand function pushes a random number from 0 up to but not including 1."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::RpnState;
+
+    #[test]
+    fn test_rand() {
+        let mut state = RpnState::new().unwrap();
+        for _ in 0..100 {
+            state.push_str("rand").unwrap();
+            let v = state.stack.peek(0).unwrap().to_f64().unwrap();
+            assert!((0.0..1.0).contains(&v));
+        }
+        assert_eq!(100, state.stack.len());
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::{anyhow, Result};
+use num_traits::ToPrimitive;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{func::Func, stack::item::StackItem, state::RpnState, undo_action::UndoEvent};
+
+pub struct SeedFunc {}
+
+impl SeedFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for SeedFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let item = state
+            .stack
+            .peek(0)
+            .cloned()
+            .ok_or_else(|| anyhow!("Not enough arguments"))?;
+        let seed = match &item {
+            StackItem::Integer(v, _) => v.to_u64(),
+            _ if item.is_integer() => item.to_f64().and_then(|v| v.to_u64()),
+            _ => None,
+        }
+        .ok_or_else(|| anyhow!("expected a non-negative 64-bit integer seed but found {item}"))?;
+
+        let previous_rng = state.rng.clone();
+        state.rng = StdRng::seed_from_u64(seed);
+        state.stack.pop();
+        Ok(Box::new(SeedUndoEvent {
+            seed: item,
+            previous_rng,
+            new_rng: state.rng.clone(),
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "seed"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["srand"]
+    }
+
+    fn description(&self) -> &str {
+        "The seed function restarts the random number generator from a seed, so the same random values follow each time."
+    }
+}
+
+#[derive(Debug)]
+pub struct SeedUndoEvent {
+    seed: StackItem,
+    previous_rng: StdRng,
+    new_rng: StdRng,
+}
+
+impl UndoEvent for SeedUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        state.stack.push(self.seed.clone());
+        state.rng = self.previous_rng.clone();
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.stack.peek(0) != Some(&self.seed) {
+            return Err(anyhow!(
+                "unexpected state for redo, expected {} on the top of the stack",
+                self.seed
+            ));
+        }
+        state.stack.pop();
+        state.rng = self.new_rng.clone();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_seed_undo() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("3").unwrap();
+        let before = state.rng.clone();
+        state.push_str("seed").unwrap();
+        assert_eq!(0, state.stack.len());
+        assert_ne!(before, state.rng);
+
+        state.undo().unwrap();
+        assert_eq!(before, state.rng);
+        assert_eq!(
+            &StackItem::Integer(3.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+
+        state.redo().unwrap();
+        assert_eq!(0, state.stack.len());
+    }
+
+    #[test]
+    fn test_seed_invalid() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("seed(-1)").is_err());
+        assert!(state.push_str("seed(1.5)").is_err());
+    }
+}
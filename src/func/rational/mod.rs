@@ -0,0 +1,54 @@
+use anyhow::{anyhow, Result};
+use show_decimal::ShowDecimalFunc;
+use show_fraction::ShowFractionFunc;
+use to_float::ToFloatFunc;
+use to_rational::ToRationalFunc;
+
+use crate::{
+    state::{rational_display::RationalDisplay, RpnState},
+    undo_action::UndoEvent,
+};
+
+pub mod show_decimal;
+pub mod show_fraction;
+pub mod to_float;
+pub mod to_rational;
+
+pub fn rational_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(ShowDecimalFunc::new()));
+    state.register_function(Box::new(ShowFractionFunc::new()));
+    state.register_function(Box::new(ToFloatFunc::new()));
+    state.register_function(Box::new(ToRationalFunc::new()));
+}
+
+#[derive(Debug)]
+pub struct RationalDisplayUndoEvent {
+    previous_display: RationalDisplay,
+    new_display: RationalDisplay,
+}
+
+impl UndoEvent for RationalDisplayUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.rational_display != self.new_display {
+            return Err(anyhow!(
+                "expected current display to be {:?} but was {:?}",
+                self.new_display,
+                state.rational_display
+            ));
+        }
+        state.rational_display = self.previous_display;
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.rational_display != self.previous_display {
+            return Err(anyhow!(
+                "expected current display to be {:?} but was {:?}",
+                self.previous_display,
+                state.rational_display
+            ));
+        }
+        state.rational_display = self.new_display;
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{rational_display::RationalDisplay, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::RationalDisplayUndoEvent;
+
+pub struct ShowDecimalFunc {}
+
+impl ShowDecimalFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ShowDecimalFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous_display = state.rational_display;
+        state.rational_display = RationalDisplay::Decimal;
+        Ok(Box::new(RationalDisplayUndoEvent {
+            previous_display,
+            new_display: RationalDisplay::Decimal,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "showdec"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The showdec function displays rational numbers as decimals (0.6666666667)"
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{rational_display::RationalDisplay, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::RationalDisplayUndoEvent;
+
+pub struct ShowFractionFunc {}
+
+impl ShowFractionFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ShowFractionFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous_display = state.rational_display;
+        state.rational_display = RationalDisplay::Fraction;
+        Ok(Box::new(RationalDisplayUndoEvent {
+            previous_display,
+            new_display: RationalDisplay::Fraction,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "showfrac"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The showfrac function displays rational numbers as fractions (2/3)"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::{rational_display::RationalDisplay, RpnState};
+
+    #[test]
+    fn test_show_fraction() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("showfrac").unwrap();
+        assert_eq!(RationalDisplay::Fraction, state.rational_display);
+
+        state.undo().unwrap();
+        assert_eq!(RationalDisplay::Decimal, state.rational_display);
+
+        state.redo().unwrap();
+        assert_eq!(RationalDisplay::Fraction, state.rational_display);
+    }
+}
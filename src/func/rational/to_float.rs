@@ -0,0 +1,54 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ToFloatFunc {}
+
+impl ToFloatFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ToFloatFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.to_float())
+    }
+
+    fn name(&self) -> &str {
+        "tofloat"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The tofloat function converts an exact integer or rational number to floating point."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_to_float() {
+        test_unary_func!(
+            StackItem::Rational(BigRational::new(1.into(), 4.into())),
+            "tofloat",
+            StackItem::Number(0.25, 10)
+        );
+    }
+
+    #[test]
+    fn test_to_float_expr() {
+        test_expr!("tofloat(2 / 3)", StackItem::Number(2.0 / 3.0, 10));
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ToRationalFunc {}
+
+impl ToRationalFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ToRationalFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.to_exact())
+    }
+
+    fn name(&self) -> &str {
+        "torat"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The torat function converts a floating point number to the simplest exact rational with the same value."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_to_rational() {
+        test_unary_func!(
+            StackItem::Number(0.75, 10),
+            "torat",
+            StackItem::Rational(BigRational::new(3.into(), 4.into()))
+        );
+    }
+
+    #[test]
+    fn test_to_rational_expr() {
+        test_expr!(
+            "torat(0.1)",
+            StackItem::Rational(BigRational::new(1.into(), 10.into()))
+        );
+        test_expr!("torat(2.0)", StackItem::Integer(2.into(), 10));
+    }
+}
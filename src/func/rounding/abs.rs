@@ -0,0 +1,49 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct AbsFunc {}
+
+impl AbsFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for AbsFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.abs())
+    }
+
+    fn name(&self) -> &str {
+        "abs"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The abs function returns the absolute value, or the magnitude of a complex number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_abs() {
+        test_unary_func!(
+            StackItem::Integer((-5).into(), 16),
+            "abs",
+            StackItem::Integer(5.into(), 16)
+        );
+        test_expr!("abs(-2.5)", StackItem::Number(2.5, 10));
+        test_expr!("abs(3 + 4i)", StackItem::Number(5.0, 10));
+    }
+}
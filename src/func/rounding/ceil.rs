@@ -0,0 +1,60 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::Rounding,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct CeilFunc {}
+
+impl CeilFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for CeilFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.round_places(0, Rounding::Ceil))
+    }
+
+    fn name(&self) -> &str {
+        "ceil"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["ceiling"]
+    }
+
+    fn description(&self) -> &str {
+        "The ceil function rounds up to the nearest whole number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_ceil() {
+        test_unary_func!(
+            StackItem::Number(2.1, 10),
+            "ceil",
+            StackItem::Number(3.0, 10)
+        );
+        test_expr!("ceil(-2.5)", StackItem::Number(-2.0, 10));
+    }
+
+    #[test]
+    fn test_ceil_exact() {
+        test_unary_func!(
+            StackItem::Rational(BigRational::new(7.into(), 2.into())),
+            "ceil",
+            StackItem::Integer(4.into(), 10)
+        );
+    }
+}
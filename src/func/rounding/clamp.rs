@@ -0,0 +1,68 @@
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_ternary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ClampFunc {}
+
+impl ClampFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ClampFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_ternary(state, |x, lo, hi| {
+            let not_real = || anyhow!("clamp requires real numbers but found {x}, {lo} and {hi}");
+            if lo.compare(hi).ok_or_else(not_real)? == Ordering::Greater {
+                return Err(anyhow!(
+                    "the lower bound {lo} is greater than the upper bound {hi}"
+                ));
+            }
+            if x.compare(lo).ok_or_else(not_real)? == Ordering::Less {
+                Ok(lo.clone())
+            } else if x.compare(hi).ok_or_else(not_real)? == Ordering::Greater {
+                Ok(hi.clone())
+            } else {
+                Ok(x.clone())
+            }
+        })
+    }
+
+    fn name(&self) -> &str {
+        "clamp"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The clamp function limits x to the range lo to hi, taking x, lo and hi from the stack in that order."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_expr};
+
+    #[test]
+    fn test_clamp() {
+        test_expr!("clamp(15, 0, 10)", StackItem::Integer(10.into(), 10));
+        test_expr!("clamp(-0.5, 0, 10)", StackItem::Integer(0.into(), 10));
+        test_expr!("clamp(2.5, 0, 10)", StackItem::Number(2.5, 10));
+    }
+
+    #[test]
+    fn test_clamp_invalid() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("clamp(5, 10, 0)").is_err());
+        assert!(state.push_str("clamp(5, 0, 'a')").is_err());
+    }
+}
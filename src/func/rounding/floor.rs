@@ -0,0 +1,72 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::Rounding,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct FloorFunc {}
+
+impl FloorFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for FloorFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.round_places(0, Rounding::Floor))
+    }
+
+    fn name(&self) -> &str {
+        "floor"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The floor function rounds down to the nearest whole number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_floor() {
+        test_unary_func!(
+            StackItem::Number(2.7, 10),
+            "floor",
+            StackItem::Number(2.0, 10)
+        );
+        test_expr!("floor(-2.5)", StackItem::Number(-3.0, 10));
+        test_expr!("floor(7)", StackItem::Integer(7.into(), 10));
+    }
+
+    #[test]
+    fn test_floor_exact() {
+        test_unary_func!(
+            StackItem::Rational(BigRational::new((-7).into(), 2.into())),
+            "floor",
+            StackItem::Integer((-4).into(), 10)
+        );
+    }
+
+    #[test]
+    fn test_floor_vector() {
+        test_expr!(
+            "floor([1.5, -1.5])",
+            StackItem::Vector(vec![
+                StackItem::Number(1.0, 10),
+                StackItem::Number(-2.0, 10)
+            ])
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::Rounding,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct FracFunc {}
+
+impl FracFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for FracFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.subtract(&a.round_places(0, Rounding::Trunc)?))
+    }
+
+    fn name(&self) -> &str {
+        "frac"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["fpart"]
+    }
+
+    fn description(&self) -> &str {
+        "The frac function returns the fractional part, x - trunc(x), which has the same sign as x."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_frac() {
+        test_unary_func!(
+            StackItem::Number(-2.5, 10),
+            "frac",
+            StackItem::Number(-0.5, 10)
+        );
+        test_expr!("frac(5)", StackItem::Integer(0.into(), 10));
+    }
+
+    #[test]
+    fn test_frac_exact() {
+        test_unary_func!(
+            StackItem::Rational(BigRational::new(7.into(), 3.into())),
+            "frac",
+            StackItem::Rational(BigRational::new(1.into(), 3.into()))
+        );
+    }
+}
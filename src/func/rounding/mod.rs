@@ -0,0 +1,83 @@
+use abs::AbsFunc;
+use anyhow::{anyhow, Result};
+use ceil::CeilFunc;
+use clamp::ClampFunc;
+use floor::FloorFunc;
+use frac::FracFunc;
+use mround::MRoundFunc;
+use round::RoundFunc;
+use round_sig::RoundSigFunc;
+use sign::SignFunc;
+use trunc::TruncFunc;
+
+use crate::{stack::item::StackItem, state::RpnState};
+
+pub mod abs;
+pub mod ceil;
+pub mod clamp;
+pub mod floor;
+pub mod frac;
+pub mod mround;
+pub mod round;
+pub mod round_sig;
+pub mod sign;
+pub mod trunc;
+
+pub fn rounding_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(AbsFunc::new()));
+    state.register_function(Box::new(CeilFunc::new()));
+    state.register_function(Box::new(ClampFunc::new()));
+    state.register_function(Box::new(FloorFunc::new()));
+    state.register_function(Box::new(FracFunc::new()));
+    state.register_function(Box::new(MRoundFunc::new()));
+    state.register_function(Box::new(RoundFunc::new()));
+    state.register_function(Box::new(RoundSigFunc::new()));
+    state.register_function(Box::new(SignFunc::new()));
+    state.register_function(Box::new(TruncFunc::new()));
+}
+
+/// Returns a count of digits, which must be a whole number.
+fn digits_operand(item: &StackItem) -> Result<i64> {
+    match item.to_f64() {
+        Some(v) if item.is_integer() && v.abs() <= 1000.0 => Ok(v.round() as i64),
+        _ => Err(anyhow!(
+            "expected a whole number of digits but found {item}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::BigDecimal;
+
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    #[test]
+    fn test_rounding_keeps_display_base() {
+        let mut state = RpnState::new().unwrap();
+        state.stack.push(StackItem::Number(-2.7, 16));
+        state.push_str("floor").unwrap();
+        assert_eq!(&StackItem::Number(-3.0, 16), state.stack.peek(0).unwrap());
+        state.push_str("abs").unwrap();
+        assert_eq!(&StackItem::Number(3.0, 16), state.stack.peek(0).unwrap());
+    }
+
+    #[test]
+    fn test_rounding_decimal() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("decimal").unwrap();
+        state.push_str("1.005").unwrap();
+        state.push_str("2").unwrap();
+        state.push_str("round").unwrap();
+        assert_eq!(
+            &StackItem::Decimal("1.01".parse::<BigDecimal>().unwrap()),
+            state.stack.peek(0).unwrap()
+        );
+        state.push_str("-2.75").unwrap();
+        state.push_str("floor").unwrap();
+        assert_eq!(
+            &StackItem::Decimal("-3".parse::<BigDecimal>().unwrap()),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
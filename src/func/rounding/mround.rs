@@ -0,0 +1,67 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::Rounding,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct MRoundFunc {}
+
+impl MRoundFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for MRoundFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            if b.is_zero() {
+                return Err(anyhow!("cannot round to a multiple of zero"));
+            }
+            a.divide(b)?.round_places(0, Rounding::Nearest)?.multiply(b)
+        })
+    }
+
+    fn name(&self) -> &str {
+        "mround"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The mround function rounds x to the nearest multiple of m."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_mround() {
+        test_binary_func!(
+            StackItem::Integer(17.into(), 10),
+            StackItem::Integer(5.into(), 10),
+            "mround",
+            StackItem::Integer(15.into(), 10)
+        );
+        test_expr!("mround(1.3, 0.5)", StackItem::Number(1.5, 10));
+        test_expr!(
+            "mround(3/10, 1/8)",
+            StackItem::Rational(BigRational::new(1.into(), 4.into()))
+        );
+    }
+
+    #[test]
+    fn test_mround_zero() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("mround(3, 0)").is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::Rounding,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::digits_operand;
+
+pub struct RoundFunc {}
+
+impl RoundFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for RoundFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            a.round_places(digits_operand(b)?, Rounding::Nearest)
+        })
+    }
+
+    fn name(&self) -> &str {
+        "round"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The round function rounds x to n decimal places, with halfway values rounding away from zero. A negative n rounds to tens, hundreds and so on."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::{state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_round() {
+        test_binary_func!(
+            StackItem::Number(1.23456, 10),
+            StackItem::Integer(2.into(), 10),
+            "round",
+            StackItem::Number(1.23, 10)
+        );
+        test_expr!("round(2.5, 0)", StackItem::Number(3.0, 10));
+        test_expr!("round(-2.5, 0)", StackItem::Number(-3.0, 10));
+        test_expr!("round(1234, -2)", StackItem::Integer(1200.into(), 10));
+    }
+
+    #[test]
+    fn test_round_exact() {
+        test_binary_func!(
+            StackItem::Rational(BigRational::new(2.into(), 3.into())),
+            StackItem::Integer(2.into(), 10),
+            "round",
+            StackItem::Rational(BigRational::new(67.into(), 100.into()))
+        );
+    }
+
+    #[test]
+    fn test_round_fractional_places() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("round(2.5, 0.5)").is_err());
+    }
+}
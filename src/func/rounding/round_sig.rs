@@ -0,0 +1,80 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::{Rounding, StackItem},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::digits_operand;
+
+pub struct RoundSigFunc {}
+
+impl RoundSigFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for RoundSigFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            let digits = digits_operand(b)?;
+            if digits < 1 {
+                return Err(anyhow!(
+                    "expected at least 1 significant figure but found {b}"
+                ));
+            }
+            let magnitude = match a {
+                StackItem::Quantity(v, _) => Some(*v),
+                _ => a.to_f64(),
+            };
+            let Some(magnitude) = magnitude else {
+                return Err(anyhow!("expected a real number but found {a}"));
+            };
+            if magnitude == 0.0 {
+                return Ok(a.clone());
+            }
+            let exponent = magnitude.abs().log10().floor() as i64;
+            a.round_places(digits - 1 - exponent, Rounding::Nearest)
+        })
+    }
+
+    fn name(&self) -> &str {
+        "sround"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["roundsig", "sigfig"]
+    }
+
+    fn description(&self) -> &str {
+        "The sround function rounds x to n significant figures."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::RpnState, test_binary_func, test_expr};
+
+    #[test]
+    fn test_round_sig() {
+        test_binary_func!(
+            StackItem::Integer(123456.into(), 10),
+            StackItem::Integer(3.into(), 10),
+            "sround",
+            StackItem::Integer(123000.into(), 10)
+        );
+        test_expr!("sround(0.0012345, 2)", StackItem::Number(0.0012, 10));
+        test_expr!("sround(-9.96, 2)", StackItem::Number(-10.0, 10));
+        test_expr!("sigfig(123456, 2)", StackItem::Integer(120000.into(), 10));
+        test_expr!("sround(0, 2)", StackItem::Integer(0.into(), 10));
+    }
+
+    #[test]
+    fn test_round_sig_invalid() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("sround(5, 0)").is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct SignFunc {}
+
+impl SignFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for SignFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.signum())
+    }
+
+    fn name(&self) -> &str {
+        "sign"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["sgn", "signum"]
+    }
+
+    fn description(&self) -> &str {
+        "The sign function returns -1, 0 or 1 for real numbers and z / |z| for complex numbers."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_sign() {
+        test_unary_func!(
+            StackItem::Number(-2.5, 10),
+            "sign",
+            StackItem::Integer((-1).into(), 10)
+        );
+        test_expr!("sign(0.0)", StackItem::Integer(0.into(), 10));
+        test_expr!("sgn(1/3)", StackItem::Integer(1.into(), 10));
+        test_expr!("sign(3i)", StackItem::Complex(Complex64::new(0.0, 1.0)));
+    }
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::Rounding,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct TruncFunc {}
+
+impl TruncFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for TruncFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.round_places(0, Rounding::Trunc))
+    }
+
+    fn name(&self) -> &str {
+        "trunc"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["ipart"]
+    }
+
+    fn description(&self) -> &str {
+        "The trunc function removes the fractional part, rounding toward zero."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_trunc() {
+        test_unary_func!(
+            StackItem::Number(-2.7, 10),
+            "trunc",
+            StackItem::Number(-2.0, 10)
+        );
+        test_expr!("ipart(2.7)", StackItem::Number(2.0, 10));
+    }
+}
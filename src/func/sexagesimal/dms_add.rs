@@ -0,0 +1,73 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::angle_operand;
+
+pub struct DmsAddFunc {}
+
+impl DmsAddFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for DmsAddFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            Ok(StackItem::Dms(angle_operand(a)? + angle_operand(b)?))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "dms+"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The dms+ function adds two angles and shows the sum in degrees, minutes and seconds."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState, test_binary_func};
+
+    #[test]
+    fn test_dms_add() {
+        test_binary_func!(
+            StackItem::Dms(10.75),
+            StackItem::Dms(20.5),
+            "dms+",
+            StackItem::Dms(31.25)
+        );
+    }
+
+    #[test]
+    fn test_dms_add_number() {
+        test_binary_func!(
+            StackItem::Dms(10.75),
+            StackItem::Number(0.25, 10),
+            "dms+",
+            StackItem::Dms(11.0)
+        );
+    }
+
+    #[test]
+    fn test_dms_add_rejects_time() {
+        let mut state = RpnState::new().unwrap();
+        state.stack.push(StackItem::Dms(1.5));
+        state.stack.push(StackItem::Hms(0.5));
+        assert!(state.push_str("dms+").is_err());
+        assert_eq!(2, state.stack.len());
+        assert_eq!(&StackItem::Hms(0.5), state.stack.peek(0).unwrap());
+    }
+}
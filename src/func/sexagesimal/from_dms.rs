@@ -0,0 +1,50 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::real_operand;
+
+pub struct FromDmsFunc {}
+
+impl FromDmsFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for FromDmsFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| Ok(StackItem::Number(real_operand(a)?, 10)))
+    }
+
+    fn name(&self) -> &str {
+        "dms->"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The dms-> function converts degrees, minutes and seconds to decimal degrees."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_unary_func;
+
+    #[test]
+    fn test_from_dms() {
+        test_unary_func!(
+            StackItem::Dms(-12.75),
+            "dms->",
+            StackItem::Number(-12.75, 10)
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::real_operand;
+
+pub struct FromHmsFunc {}
+
+impl FromHmsFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for FromHmsFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| Ok(StackItem::Number(real_operand(a)?, 10)))
+    }
+
+    fn name(&self) -> &str {
+        "hms->"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The hms-> function converts hours, minutes and seconds to decimal hours."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_unary_func;
+
+    #[test]
+    fn test_from_hms() {
+        test_unary_func!(StackItem::Hms(-2.5), "hms->", StackItem::Number(-2.5, 10));
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::time_operand;
+
+pub struct HmsAddFunc {}
+
+impl HmsAddFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for HmsAddFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| {
+            Ok(StackItem::Hms(time_operand(a)? + time_operand(b)?))
+        })
+    }
+
+    fn name(&self) -> &str {
+        "hms+"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The hms+ function adds two times and shows the sum in hours, minutes and seconds."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState, test_binary_func};
+
+    #[test]
+    fn test_hms_add() {
+        test_binary_func!(
+            StackItem::Hms(10.75),
+            StackItem::Hms(20.5),
+            "hms+",
+            StackItem::Hms(31.25)
+        );
+    }
+
+    #[test]
+    fn test_hms_add_number() {
+        test_binary_func!(
+            StackItem::Hms(10.75),
+            StackItem::Number(0.25, 10),
+            "hms+",
+            StackItem::Hms(11.0)
+        );
+    }
+
+    #[test]
+    fn test_hms_add_rejects_angle() {
+        let mut state = RpnState::new().unwrap();
+        state.stack.push(StackItem::Hms(1.5));
+        state.stack.push(StackItem::Dms(0.5));
+        assert!(state.push_str("hms+").is_err());
+        assert_eq!(2, state.stack.len());
+        assert_eq!(&StackItem::Dms(0.5), state.stack.peek(0).unwrap());
+    }
+}
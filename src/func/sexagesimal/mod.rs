@@ -0,0 +1,98 @@
+use anyhow::{anyhow, Result};
+use dms_add::DmsAddFunc;
+use from_dms::FromDmsFunc;
+use from_hms::FromHmsFunc;
+use hms_add::HmsAddFunc;
+use to_dms::ToDmsFunc;
+use to_hms::ToHmsFunc;
+
+use crate::{stack::item::StackItem, state::RpnState};
+
+pub mod dms_add;
+pub mod from_dms;
+pub mod from_hms;
+pub mod hms_add;
+pub mod to_dms;
+pub mod to_hms;
+
+pub fn sexagesimal_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(DmsAddFunc::new()));
+    state.register_function(Box::new(FromDmsFunc::new()));
+    state.register_function(Box::new(FromHmsFunc::new()));
+    state.register_function(Box::new(HmsAddFunc::new()));
+    state.register_function(Box::new(ToDmsFunc::new()));
+    state.register_function(Box::new(ToHmsFunc::new()));
+}
+
+/// Returns the value of a real number, degrees, minutes and seconds or hours, minutes
+/// and seconds value.
+fn real_operand(item: &StackItem) -> Result<f64> {
+    match item {
+        StackItem::Complex(_) => Err(anyhow!("expected a real number but found {item}")),
+        _ => item
+            .to_f64()
+            .ok_or_else(|| anyhow!("expected a real number but found {item}")),
+    }
+}
+
+/// Returns the value of an angle operand, rejecting times so `dms+` never mixes them.
+fn angle_operand(item: &StackItem) -> Result<f64> {
+    match item {
+        StackItem::Hms(_) => Err(anyhow!("expected an angle but found the time {item}")),
+        _ => real_operand(item),
+    }
+}
+
+/// Returns the value of a time operand, rejecting angles so `hms+` never mixes them.
+fn time_operand(item: &StackItem) -> Result<f64> {
+    match item {
+        StackItem::Dms(_) => Err(anyhow!("expected a time but found the angle {item}")),
+        _ => real_operand(item),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        stack::{datetime::parse_date_time, item::StackItem},
+        state::{angle_mode::AngleMode, RpnState},
+        test_expr,
+    };
+
+    #[test]
+    fn test_dms_literal() {
+        test_expr!("12°30'", StackItem::Dms(12.5));
+        test_expr!("-45°", StackItem::Dms(-45.0));
+        test_expr!("1°30'0\" + 1", StackItem::Number(2.5, 10));
+    }
+
+    #[test]
+    fn test_hms_literal() {
+        test_expr!("1:30:00", StackItem::Hms(1.5));
+        test_expr!(
+            "2026-10-18 + 14:30:00",
+            StackItem::DateTime(parse_date_time("2026-10-18T14:30").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_paste_displayed_values() {
+        for item in [
+            StackItem::Dms(12.0 + 34.0 / 60.0 + 56.7 / 3600.0),
+            StackItem::Hms(1.0 + 2.0 / 60.0 + 3.5 / 3600.0),
+        ] {
+            let mut state = RpnState::new().unwrap();
+            state.push_str(&item.to_string()).unwrap();
+            assert_eq!(item.to_string(), state.stack.peek(0).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn test_trig_accepts_dms() {
+        let mut state = RpnState::new().unwrap();
+        state.angle_mode = AngleMode::Degrees;
+        state.push_str("sin(30°0'0\")").unwrap();
+        let v = state.stack.peek(0).unwrap().to_f64().unwrap();
+        assert!((v - 0.5).abs() < 1e-12, "sin = {v}");
+    }
+}
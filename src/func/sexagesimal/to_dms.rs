@@ -0,0 +1,46 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::real_operand;
+
+pub struct ToDmsFunc {}
+
+impl ToDmsFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ToDmsFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| Ok(StackItem::Dms(real_operand(a)?)))
+    }
+
+    fn name(&self) -> &str {
+        "->dms"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The ->dms function shows decimal degrees as degrees, minutes and seconds."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_unary_func;
+
+    #[test]
+    fn test_to_dms() {
+        test_unary_func!(StackItem::Number(12.5, 10), "->dms", StackItem::Dms(12.5));
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::real_operand;
+
+pub struct ToHmsFunc {}
+
+impl ToHmsFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ToHmsFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| Ok(StackItem::Hms(real_operand(a)?)))
+    }
+
+    fn name(&self) -> &str {
+        "->hms"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The ->hms function shows decimal hours as hours, minutes and seconds."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_unary_func;
+
+    #[test]
+    fn test_to_hms() {
+        test_unary_func!(StackItem::Number(1.25, 10), "->hms", StackItem::Hms(1.25));
+    }
+}
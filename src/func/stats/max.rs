@@ -0,0 +1,61 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, sorted};
+
+pub struct MaxFunc {
+    top_n: bool,
+}
+
+impl MaxFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for MaxFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| {
+            Ok(sorted(data)?.pop().unwrap())
+        })
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "maxn"
+        } else {
+            "max"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The max function returns the largest value. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The max function returns the largest value. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_max() {
+        let mut state = stack_of(&["3", "-1.5", "2"]);
+        state.push_str("max").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(3.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
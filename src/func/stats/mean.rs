@@ -0,0 +1,65 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, mean};
+
+pub struct MeanFunc {
+    top_n: bool,
+}
+
+impl MeanFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for MeanFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| mean(data))
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "meann"
+        } else {
+            "mean"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        if self.top_n {
+            vec!["avgn"]
+        } else {
+            vec!["avg"]
+        }
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The mean function returns the arithmetic mean of the values. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The mean function returns the arithmetic mean of the values. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_mean() {
+        let mut state = stack_of(&["1", "2", "4"]);
+        state.push_str("mean").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            &StackItem::Rational(BigRational::new(7.into(), 3.into())),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
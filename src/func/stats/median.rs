@@ -0,0 +1,80 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, mean, sorted};
+
+pub struct MedianFunc {
+    top_n: bool,
+}
+
+impl MedianFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for MedianFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| {
+            let data = sorted(data)?;
+            let mid = data.len() / 2;
+            if data.len() % 2 == 1 {
+                Ok(data[mid].clone())
+            } else {
+                mean(&data[mid - 1..=mid])
+            }
+        })
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "mediann"
+        } else {
+            "median"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The median function returns the middle value, or the mean of the two middle values when there is an even number of values. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The median function returns the middle value, or the mean of the two middle values when there is an even number of values. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_median() {
+        let mut state = stack_of(&["7", "1", "3"]);
+        state.push_str("median").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(3.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_median_even() {
+        let mut state = stack_of(&["4", "1", "3", "2"]);
+        state.push_str("median").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            &StackItem::Rational(BigRational::new(5.into(), 2.into())),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, sorted};
+
+pub struct MinFunc {
+    top_n: bool,
+}
+
+impl MinFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for MinFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| {
+            Ok(sorted(data)?.swap_remove(0))
+        })
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "minn"
+        } else {
+            "min"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The min function returns the smallest value. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The min function returns the smallest value. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_min() {
+        let mut state = stack_of(&["3", "-1.5", "2"]);
+        state.push_str("min").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(&StackItem::Number(-1.5, 10), state.stack.peek(0).unwrap());
+    }
+}
@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, Result};
+use max::MaxFunc;
+use mean::MeanFunc;
+use median::MedianFunc;
+use min::MinFunc;
+use mode::ModeFunc;
+use num_traits::ToPrimitive;
+use percentile::PercentileFunc;
+use pop_std_dev::PopStdDevFunc;
+use pop_variance::PopVarianceFunc;
+use range::RangeFunc;
+use std_dev::StdDevFunc;
+use sum::SumFunc;
+use variance::VarianceFunc;
+
+use crate::{stack::item::StackItem, state::RpnState, undo_action::UndoEvent};
+
+pub mod max;
+pub mod mean;
+pub mod median;
+pub mod min;
+pub mod mode;
+pub mod percentile;
+pub mod pop_std_dev;
+pub mod pop_variance;
+pub mod range;
+pub mod std_dev;
+pub mod sum;
+pub mod variance;
+
+/// Each statistic is registered twice, once working on the whole stack and once, with an
+/// `n` suffix, working on the top n items with n taken from the top of the stack.
+pub fn stats_register_functions(state: &mut RpnState) {
+    for top_n in [false, true] {
+        state.register_function(Box::new(MaxFunc::new(top_n)));
+        state.register_function(Box::new(MeanFunc::new(top_n)));
+        state.register_function(Box::new(MedianFunc::new(top_n)));
+        state.register_function(Box::new(MinFunc::new(top_n)));
+        state.register_function(Box::new(ModeFunc::new(top_n)));
+        state.register_function(Box::new(PercentileFunc::new(top_n)));
+        state.register_function(Box::new(PopStdDevFunc::new(top_n)));
+        state.register_function(Box::new(PopVarianceFunc::new(top_n)));
+        state.register_function(Box::new(RangeFunc::new(top_n)));
+        state.register_function(Box::new(StdDevFunc::new(top_n)));
+        state.register_function(Box::new(SumFunc::new(top_n)));
+        state.register_function(Box::new(VarianceFunc::new(top_n)));
+    }
+}
+
+/// Replaces the data, and the `params` operands above it, with a single statistic. The data
+/// is the whole stack, or the top n items when `top_n` is set and n is on top of the stack.
+/// Everything consumed comes back in one undo step.
+fn execute_stats<F>(
+    state: &mut RpnState,
+    top_n: bool,
+    params: usize,
+    calc: F,
+) -> Result<Box<dyn UndoEvent>>
+where
+    F: FnOnce(&[StackItem], &[StackItem]) -> Result<StackItem>,
+{
+    let (count, count_args) = if top_n {
+        let n = state
+            .stack
+            .peek(0)
+            .ok_or_else(|| anyhow!("Not enough arguments"))?;
+        (count_operand(n)?, 1)
+    } else {
+        (state.stack.len().saturating_sub(params), 0)
+    };
+    let total = count + params + count_args;
+    if state.stack.len() < total {
+        return Err(anyhow!("Not enough arguments"));
+    }
+    if count == 0 {
+        return Err(anyhow!("no values to compute a statistic from"));
+    }
+    let consumed = (0..total)
+        .rev()
+        .map(|i| state.stack.peek(i).unwrap().clone())
+        .collect::<Vec<_>>();
+    let result = state.apply_modes(calc(&consumed[..count], &consumed[count..count + params])?);
+    state.stack.pop_n(total)?;
+    state.stack.push(result.clone());
+    Ok(Box::new(StatsUndoEvent { consumed, result }))
+}
+
+/// Restores the data and operands a statistic consumed, kept in stack order (bottom
+/// first), in place of the statistic.
+#[derive(Debug)]
+pub struct StatsUndoEvent {
+    consumed: Vec<StackItem>,
+    result: StackItem,
+}
+
+impl UndoEvent for StatsUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.stack.peek(0) != Some(&self.result) {
+            return Err(anyhow!(
+                "unexpected state for undo, expected the statistic {} on the top of the stack",
+                self.result
+            ));
+        }
+        state.stack.pop();
+        for item in &self.consumed {
+            state.stack.push(item.clone());
+        }
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.stack.len() < self.consumed.len() {
+            return Err(anyhow!(
+                "unexpected state for redo, expected at least {} items on the stack",
+                self.consumed.len()
+            ));
+        }
+        for (i, item) in self.consumed.iter().rev().enumerate() {
+            if state.stack.peek(i) != Some(item) {
+                return Err(anyhow!(
+                    "unexpected state for redo, expected item {i} on the stack to be {item}"
+                ));
+            }
+        }
+        state.stack.pop_n(self.consumed.len())?;
+        state.stack.push(self.result.clone());
+        Ok(())
+    }
+}
+
+fn count_operand(item: &StackItem) -> Result<usize> {
+    let count = match item {
+        StackItem::Integer(v, _) => v.to_usize(),
+        _ if item.is_integer() => item.to_f64().filter(|v| *v >= 0.0).map(|v| v as usize),
+        _ => None,
+    };
+    count.ok_or_else(|| anyhow!("expected a count of items but found {item}"))
+}
+
+fn sum(data: &[StackItem]) -> Result<StackItem> {
+    let mut total = data[0].clone();
+    for v in &data[1..] {
+        total = total.add(v)?;
+    }
+    Ok(total)
+}
+
+fn mean(data: &[StackItem]) -> Result<StackItem> {
+    sum(data)?.divide(&StackItem::Integer(data.len().into(), 10))
+}
+
+/// Mean squared deviation from the mean, dividing by n - 1 for the sample variance.
+fn variance(data: &[StackItem], sample: bool) -> Result<StackItem> {
+    let n = if sample { data.len() - 1 } else { data.len() };
+    if n == 0 {
+        return Err(anyhow!("the sample variance needs at least two values"));
+    }
+    let mean = mean(data)?;
+    let mut total = StackItem::Integer(0.into(), 10);
+    for v in data {
+        let deviation = v.subtract(&mean)?;
+        total = total.add(&deviation.multiply(&deviation)?)?;
+    }
+    total.divide(&StackItem::Integer(n.into(), 10))
+}
+
+/// Sorts real values in ascending order, comparing exactly when possible.
+fn sorted(data: &[StackItem]) -> Result<Vec<StackItem>> {
+    for v in data {
+        v.compare(&data[0])
+            .ok_or_else(|| anyhow!("expected real numbers but found {v}"))?;
+    }
+    let mut data = data.to_vec();
+    data.sort_by(|a, b| a.compare(b).unwrap_or(Ordering::Equal));
+    Ok(data)
+}
+
+#[cfg(test)]
+fn stack_of(values: &[&str]) -> RpnState {
+    let mut state = RpnState::new().unwrap();
+    for v in values {
+        state.push_str(v).unwrap();
+    }
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    use super::stack_of;
+
+    #[test]
+    fn test_stats_undo() {
+        let mut state = stack_of(&["1", "2", "3", "4"]);
+        state.push_str("mean").unwrap();
+        assert_eq!(1, state.stack.len());
+
+        state.undo().unwrap();
+        assert_eq!(4, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(4.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+        assert_eq!(
+            &StackItem::Integer(1.into(), 10),
+            state.stack.peek(3).unwrap()
+        );
+
+        state.redo().unwrap();
+        assert_eq!(1, state.stack.len());
+    }
+
+    #[test]
+    fn test_stats_undo_checks_stack() {
+        let mut state = stack_of(&["1", "2", "3"]);
+        state.push_str("sum").unwrap();
+        state.undo().unwrap();
+        state.stack.pop();
+        assert!(state.redo().is_err());
+        assert_eq!(2, state.stack.len());
+    }
+
+    #[test]
+    fn test_stats_top_n() {
+        let mut state = stack_of(&["100", "1", "2", "3", "2"]);
+        state.push_str("sumn").unwrap();
+        assert_eq!(3, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(5.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+        assert_eq!(
+            &StackItem::Integer(1.into(), 10),
+            state.stack.peek(1).unwrap()
+        );
+
+        state.undo().unwrap();
+        assert_eq!(5, state.stack.len());
+    }
+
+    #[test]
+    fn test_stats_not_enough() {
+        let mut state = stack_of(&["1", "2", "3"]);
+        assert!(state.push_str("sumn").is_err());
+        assert_eq!(3, state.stack.len());
+        assert!(RpnState::new().unwrap().push_str("mean").is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, sorted};
+
+pub struct ModeFunc {
+    top_n: bool,
+}
+
+impl ModeFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for ModeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| {
+            let data = sorted(data)?;
+            let mut best = (&data[0], 0);
+            for run in data.chunk_by(|a, b| a.compare(b) == Some(Ordering::Equal)) {
+                if run.len() > best.1 {
+                    best = (&run[0], run.len());
+                }
+            }
+            Ok(best.0.clone())
+        })
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "moden"
+        } else {
+            "mode"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The mode function returns the most common value, the smallest one when several are equally common. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The mode function returns the most common value, the smallest one when several are equally common. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_mode() {
+        let mut state = stack_of(&["3", "1", "3", "2", "1", "5"]);
+        state.push_str("mode").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(1.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_mode_float() {
+        let mut state = stack_of(&["2.5", "1", "2.5"]);
+        state.push_str("mode").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(&StackItem::Number(2.5, 10), state.stack.peek(0).unwrap());
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{anyhow, Result};
+use num_traits::ToPrimitive;
+
+use crate::{
+    func::Func,
+    stack::item::{Rounding, StackItem},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::{execute_stats, sorted};
+
+pub struct PercentileFunc {
+    top_n: bool,
+}
+
+impl PercentileFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for PercentileFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 1, |data, params| {
+            percentile(&sorted(data)?, &params[0])
+        })
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "percentilen"
+        } else {
+            "percentile"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        // pct and pctn went to the percent functions
+        if self.top_n {
+            vec!["ptilen"]
+        } else {
+            vec!["ptile"]
+        }
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The percentile function returns the value p percent of the way through the sorted values, interpolating between neighbours, with p on top of the values. Uses the n items below p, with n taken from the top of the stack."
+        } else {
+            "The percentile function returns the value p percent of the way through the sorted values, interpolating between neighbours, with p on top of the values. Uses every item on the stack below p."
+        }
+    }
+}
+
+fn percentile(data: &[StackItem], p: &StackItem) -> Result<StackItem> {
+    if !p.to_f64().is_some_and(|p| (0.0..=100.0).contains(&p)) {
+        return Err(anyhow!("expected a percentile from 0 to 100 but found {p}"));
+    }
+    // the rank runs from 0 at the smallest value to n - 1 at the largest
+    let rank = p
+        .multiply(&StackItem::Integer((data.len() - 1).into(), 10))?
+        .divide(&StackItem::Integer(100.into(), 10))?;
+    let lower = rank.round_places(0, Rounding::Floor)?;
+    let i = lower.to_f64().and_then(|v| v.to_usize()).unwrap();
+    if i + 1 >= data.len() {
+        return Ok(data[i].clone());
+    }
+    let weight = rank.subtract(&lower)?;
+    data[i].add(&weight.multiply(&data[i + 1].subtract(&data[i])?)?)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_percentile() {
+        let mut state = stack_of(&["15", "20", "35", "40", "50", "40"]);
+        state.push_str("percentile").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(29.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_percentile_alias() {
+        let mut state = stack_of(&["100", "1", "2", "3", "50", "3"]);
+        state.push_str("ptilen").unwrap();
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(2.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_percentile_bounds() {
+        let mut state = stack_of(&["3", "1", "2", "100"]);
+        state.push_str("percentile").unwrap();
+        assert_eq!(
+            &StackItem::Integer(3.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+
+        let mut state = stack_of(&["3", "1", "2", "101"]);
+        assert!(state.push_str("percentile").is_err());
+        assert_eq!(4, state.stack.len());
+    }
+
+    #[test]
+    fn test_percentilen() {
+        let mut state = stack_of(&["1000", "1", "2", "3", "50", "3"]);
+        state.push_str("percentilen").unwrap();
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(2.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, variance};
+
+pub struct PopStdDevFunc {
+    top_n: bool,
+}
+
+impl PopStdDevFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for PopStdDevFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| {
+            variance(data, false)?.sqrt()
+        })
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "pstddevn"
+        } else {
+            "pstddev"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        if self.top_n {
+            vec!["psdevn"]
+        } else {
+            vec!["psdev"]
+        }
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The pstddev function returns the population standard deviation of the values, dividing by n. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The pstddev function returns the population standard deviation of the values, dividing by n. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_pstddev() {
+        let mut state = stack_of(&["2", "4", "4", "4", "5", "5", "7", "9"]);
+        state.push_str("pstddev").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            &StackItem::Integer(2.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
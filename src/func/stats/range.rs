@@ -0,0 +1,59 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, sorted};
+
+pub struct RangeFunc {
+    top_n: bool,
+}
+
+impl RangeFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for RangeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| {
+            let data = sorted(data)?;
+            data[data.len() - 1].subtract(&data[0])
+        })
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "rangen"
+        } else {
+            "range"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The range function returns the difference between the largest and smallest values. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The range function returns the difference between the largest and smallest values. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_range() {
+        let mut state = stack_of(&["3", "-1.5", "2"]);
+        state.push_str("range").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(&StackItem::Number(4.5, 10), state.stack.peek(0).unwrap());
+    }
+}
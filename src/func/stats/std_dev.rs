@@ -0,0 +1,63 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, variance};
+
+pub struct StdDevFunc {
+    top_n: bool,
+}
+
+impl StdDevFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for StdDevFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| variance(data, true)?.sqrt())
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "stddevn"
+        } else {
+            "stddev"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        if self.top_n {
+            vec!["sdevn", "sstddevn"]
+        } else {
+            vec!["sdev", "sstddev"]
+        }
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The stddev function returns the sample standard deviation of the values, dividing by n - 1. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The stddev function returns the sample standard deviation of the values, dividing by n - 1. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_stddev() {
+        let mut state = stack_of(&["1", "2", "3", "4"]);
+        state.push_str("stddev").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            &StackItem::Number((5.0_f64 / 3.0).sqrt(), 10),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, sum};
+
+pub struct SumFunc {
+    top_n: bool,
+}
+
+impl SumFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for SumFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| sum(data))
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "sumn"
+        } else {
+            "sum"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The sum function adds the values together. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The sum function adds the values together. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_sum() {
+        let mut state = stack_of(&["1", "2.5", "3"]);
+        state.push_str("sum").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(&StackItem::Number(6.5, 10), state.stack.peek(0).unwrap());
+    }
+}
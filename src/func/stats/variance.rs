@@ -0,0 +1,65 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::{execute_stats, variance};
+
+pub struct VarianceFunc {
+    top_n: bool,
+}
+
+impl VarianceFunc {
+    pub fn new(top_n: bool) -> Self {
+        Self { top_n }
+    }
+}
+
+impl Func for VarianceFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_stats(state, self.top_n, 0, |data, _| variance(data, true))
+    }
+
+    fn name(&self) -> &str {
+        if self.top_n {
+            "varn"
+        } else {
+            "var"
+        }
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        if self.top_n {
+            vec!["svarn"]
+        } else {
+            vec!["svar"]
+        }
+    }
+
+    fn description(&self) -> &str {
+        if self.top_n {
+            "The var function returns the sample variance of the values, dividing by n - 1. Uses the top n items, with n taken from the top of the stack."
+        } else {
+            "The var function returns the sample variance of the values, dividing by n - 1. Uses every item on the stack."
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_rational::BigRational;
+
+    use crate::stack::item::StackItem;
+
+    use super::super::stack_of;
+
+    #[test]
+    fn test_var() {
+        let mut state = stack_of(&["2", "4", "4", "4", "5", "5", "7", "9"]);
+        state.push_str("var").unwrap();
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            &StackItem::Rational(BigRational::new(32.into(), 7.into())),
+            state.stack.peek(0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ACoshFunc {}
+
+impl ACoshFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ACoshFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.acosh())
+    }
+
+    fn name(&self) -> &str {
+        "acosh"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The acosh function returns the inverse hyperbolic cosine of a number, a complex number for values below 1."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_acosh() {
+        test_unary_func!(
+            StackItem::Integer(2.into(), 10),
+            "acosh",
+            StackItem::Number(2.0_f64.acosh(), 10)
+        );
+    }
+
+    #[test]
+    fn test_acosh_out_of_range() {
+        test_expr!(
+            "acosh(0.5)",
+            StackItem::Complex(Complex64::new(0.5, 0.0).acosh())
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use anyhow::Result;
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::{radians_to_angle_mode, StackItem},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::reciprocal;
+
+pub struct ACotFunc {}
+
+impl ACotFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ACotFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let angle_mode = state.angle_mode;
+        execute_unary(state, |a| {
+            if a.is_zero() {
+                return Ok(StackItem::Number(
+                    radians_to_angle_mode(FRAC_PI_2, angle_mode),
+                    10,
+                ));
+            }
+            reciprocal(a)?.atan(angle_mode)
+        })
+    }
+
+    fn name(&self) -> &str {
+        "acot"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The acot function returns the inverse cotangent (atan(1/x)) of a number in the current angle mode."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::angle_mode::AngleMode, test_expr, test_unary_angle_func};
+
+    #[test]
+    fn test_acot() {
+        test_unary_angle_func!(
+            AngleMode::Radians,
+            StackItem::Integer(2.into(), 10),
+            "acot",
+            StackItem::Number(0.5_f64.atan(), 10)
+        );
+    }
+
+    #[test]
+    fn test_acot_zero() {
+        test_expr!("acot(0)", StackItem::Number(90.0, 10));
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::reciprocal;
+
+pub struct ACscFunc {}
+
+impl ACscFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ACscFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let angle_mode = state.angle_mode;
+        execute_unary(state, |a| reciprocal(a)?.asin(angle_mode))
+    }
+
+    fn name(&self) -> &str {
+        "acsc"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The acsc function returns the inverse cosecant (asin(1/x)) of a number in the current angle mode."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::angle_mode::AngleMode, test_unary_angle_func};
+
+    #[test]
+    fn test_acsc() {
+        test_unary_angle_func!(
+            AngleMode::Radians,
+            StackItem::Integer(2.into(), 10),
+            "acsc",
+            StackItem::Number(0.5_f64.asin(), 10)
+        );
+    }
+}
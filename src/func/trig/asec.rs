@@ -0,0 +1,51 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::reciprocal;
+
+pub struct ASecFunc {}
+
+impl ASecFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ASecFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let angle_mode = state.angle_mode;
+        execute_unary(state, |a| reciprocal(a)?.acos(angle_mode))
+    }
+
+    fn name(&self) -> &str {
+        "asec"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The asec function returns the inverse secant (acos(1/x)) of a number in the current angle mode."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::angle_mode::AngleMode, test_unary_angle_func};
+
+    #[test]
+    fn test_asec() {
+        test_unary_angle_func!(
+            AngleMode::Radians,
+            StackItem::Integer(2.into(), 10),
+            "asec",
+            StackItem::Number(0.5_f64.acos(), 10)
+        );
+    }
+}
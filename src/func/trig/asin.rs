@@ -35,6 +35,8 @@ impl Func for ASinFunc {
 
 #[cfg(test)]
 mod test {
+    use num_complex::Complex64;
+
     use crate::{state::angle_mode::AngleMode, test_expr, test_unary_angle_func};
 
     #[test]
@@ -53,6 +55,16 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_asin_out_of_range() {
+        test_unary_angle_func!(
+            AngleMode::Radians,
+            StackItem::Integer(2.into(), 10),
+            "asin",
+            StackItem::Complex(Complex64::new(2.0, 0.0).asin())
+        );
+    }
+
     #[test]
     fn test_asin_expr() {
         test_expr!(
@@ -60,4 +72,14 @@ mod test {
             StackItem::Number(59.99999999999999, 10)
         );
     }
+
+    #[test]
+    fn test_asin_gradians() {
+        test_unary_angle_func!(
+            AngleMode::Gradians,
+            StackItem::Integer(1.into(), 10),
+            "asin",
+            StackItem::Number(100.0, 10)
+        );
+    }
 }
@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ASinhFunc {}
+
+impl ASinhFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ASinhFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.asinh())
+    }
+
+    fn name(&self) -> &str {
+        "asinh"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The asinh function returns the inverse hyperbolic sine of a number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_unary_func;
+
+    #[test]
+    fn test_asinh() {
+        test_unary_func!(
+            StackItem::Integer(1.into(), 10),
+            "asinh",
+            StackItem::Number(1.0_f64.asinh(), 10)
+        );
+    }
+}
@@ -41,15 +41,15 @@ mod test {
     fn test_atan2() {
         test_binary_angle_func!(
             AngleMode::Radians,
-            StackItem::Number(1.0, 10),
-            StackItem::Number(2.0, 10),
+            StackItem::Integer(1.into(), 10),
+            StackItem::Integer(2.into(), 10),
             "atan2",
             StackItem::Number(0.4636476090008061, 10)
         );
         test_binary_angle_func!(
             AngleMode::Degrees,
-            StackItem::Number(1.0, 10),
-            StackItem::Number(2.0, 10),
+            StackItem::Integer(1.into(), 10),
+            StackItem::Integer(2.into(), 10),
             "atan2",
             StackItem::Number(26.56505117707799, 10)
         );
@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ATanhFunc {}
+
+impl ATanhFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ATanhFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.atanh())
+    }
+
+    fn name(&self) -> &str {
+        "atanh"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The atanh function returns the inverse hyperbolic tangent of a number, a complex number outside -1 to 1."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_complex::Complex64;
+
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_atanh() {
+        test_unary_func!(
+            StackItem::Number(0.5, 10),
+            "atanh",
+            StackItem::Number(0.5_f64.atanh(), 10)
+        );
+    }
+
+    #[test]
+    fn test_atanh_out_of_range() {
+        test_expr!("atanh(1)", StackItem::Undefined);
+        test_expr!(
+            "atanh(2)",
+            StackItem::Complex(Complex64::new(2.0, 0.0).atanh())
+        );
+    }
+}
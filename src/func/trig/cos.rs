@@ -41,7 +41,7 @@ mod test {
     fn test_cos() {
         test_unary_angle_func!(
             AngleMode::Degrees,
-            StackItem::Number(30.0, 10),
+            StackItem::Integer(30.into(), 10),
             "cos",
             StackItem::Number((30.0_f64).to_radians().cos(), 10)
         );
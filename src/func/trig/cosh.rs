@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct CoshFunc {}
+
+impl CoshFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for CoshFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.cosh())
+    }
+
+    fn name(&self) -> &str {
+        "cosh"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The cosh function returns the hyperbolic cosine of a number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_unary_func;
+
+    #[test]
+    fn test_cosh() {
+        test_unary_func!(
+            StackItem::Integer(1.into(), 10),
+            "cosh",
+            StackItem::Number(1.0_f64.cosh(), 10)
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct CotFunc {}
+
+impl CotFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for CotFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let angle_mode = state.angle_mode;
+        execute_unary(state, |a| a.cos(angle_mode)?.divide(&a.sin(angle_mode)?))
+    }
+
+    fn name(&self) -> &str {
+        "cot"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The cot function returns the cotangent (cos/sin) of a number in the current angle mode."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::angle_mode::AngleMode, test_unary_angle_func};
+
+    #[test]
+    fn test_cot() {
+        test_unary_angle_func!(
+            AngleMode::Radians,
+            StackItem::Integer(1.into(), 10),
+            "cot",
+            StackItem::Number(1.0_f64.cos() / 1.0_f64.sin(), 10)
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::reciprocal;
+
+pub struct CscFunc {}
+
+impl CscFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for CscFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let angle_mode = state.angle_mode;
+        execute_unary(state, |a| reciprocal(&a.sin(angle_mode)?))
+    }
+
+    fn name(&self) -> &str {
+        "csc"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The csc function returns the cosecant (1/sin) of a number in the current angle mode."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::angle_mode::AngleMode, test_expr, test_unary_angle_func};
+
+    #[test]
+    fn test_csc() {
+        test_unary_angle_func!(
+            AngleMode::Radians,
+            StackItem::Integer(1.into(), 10),
+            "csc",
+            StackItem::Number(1.0 / 1.0_f64.sin(), 10)
+        );
+    }
+
+    #[test]
+    fn test_csc_zero() {
+        test_expr!("csc(0)", StackItem::Undefined);
+    }
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{angle_mode::AngleMode, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::AngleUndoEvent;
+
+pub struct GradiansFunc {}
+
+impl GradiansFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for GradiansFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous_mode = state.angle_mode;
+        state.angle_mode = AngleMode::Gradians;
+        Ok(Box::new(AngleUndoEvent {
+            previous_mode,
+            new_mode: AngleMode::Gradians,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "grad"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The grad function sets the current angle mode to gradians (400 to a full circle)"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::angle_mode::AngleMode, test_angle_mode_func};
+
+    #[test]
+    fn test_gradians() {
+        test_angle_mode_func!(AngleMode::Degrees, AngleMode::Gradians, "grad");
+    }
+}
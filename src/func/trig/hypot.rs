@@ -0,0 +1,79 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct HypotFunc {}
+
+impl HypotFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for HypotFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| a.hypot(b))
+    }
+
+    fn name(&self) -> &str {
+        "hypot"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The hypot function returns the length of the hypotenuse of a right triangle with the given sides, sqrt(x^2 + y^2)."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        stack::{item::StackItem, unit::Unit},
+        state::RpnState,
+        test_binary_func, test_expr,
+    };
+
+    #[test]
+    fn test_hypot() {
+        test_binary_func!(
+            StackItem::Integer(3.into(), 10),
+            StackItem::Integer(4.into(), 10),
+            "hypot",
+            StackItem::Number(5.0, 10)
+        );
+    }
+
+    #[test]
+    fn test_hypot_expr() {
+        test_expr!("hypot(5, 12)", StackItem::Number(13.0, 10));
+    }
+
+    #[test]
+    fn test_hypot_quantities() {
+        test_expr!(
+            "hypot(3 m, 400 cm)",
+            StackItem::Quantity(5.0, Unit::parse("m").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_hypot_incompatible_units() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("3 m").unwrap();
+        state.push_str("4 s").unwrap();
+        let err = state.push_str("hypot").unwrap_err();
+        assert!(err.to_string().contains("incompatible units"), "{err}");
+        assert_eq!(2, state.stack.len());
+        assert_eq!(
+            StackItem::Quantity(4.0, Unit::parse("s").unwrap()),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+}
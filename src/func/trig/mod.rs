@@ -1,28 +1,61 @@
 use acos::ACosFunc;
+use acosh::ACoshFunc;
+use acot::ACotFunc;
+use acsc::ACscFunc;
 use anyhow::{anyhow, Result};
+use asec::ASecFunc;
 use asin::ASinFunc;
+use asinh::ASinhFunc;
 use atan::ATanFunc;
 use atan2::ATan2Func;
+use atanh::ATanhFunc;
 use cos::CosFunc;
+use cosh::CoshFunc;
+use cot::CotFunc;
+use csc::CscFunc;
 use degrees::DegreesFunc;
+use gradians::GradiansFunc;
+use hypot::HypotFunc;
 use radians::RadiansFunc;
+use sec::SecFunc;
 use sin::SinFunc;
+use sinc::SincFunc;
+use sinh::SinhFunc;
 use tan::TanFunc;
+use tanh::TanhFunc;
+use turns::TurnsFunc;
 
 use crate::{
+    stack::item::StackItem,
     state::{angle_mode::AngleMode, RpnState},
     undo_action::UndoEvent,
 };
 
 pub mod acos;
+pub mod acosh;
+pub mod acot;
+pub mod acsc;
+pub mod asec;
 pub mod asin;
+pub mod asinh;
 pub mod atan;
 pub mod atan2;
+pub mod atanh;
 pub mod cos;
+pub mod cosh;
+pub mod cot;
+pub mod csc;
 pub mod degrees;
+pub mod gradians;
+pub mod hypot;
 pub mod radians;
+pub mod sec;
 pub mod sin;
+pub mod sinc;
+pub mod sinh;
 pub mod tan;
+pub mod tanh;
+pub mod turns;
 
 pub fn trig_register_functions(state: &mut RpnState) {
     state.register_function(Box::new(RadiansFunc::new()));
@@ -34,6 +67,27 @@ pub fn trig_register_functions(state: &mut RpnState) {
     state.register_function(Box::new(ATanFunc::new()));
     state.register_function(Box::new(ATan2Func::new()));
     state.register_function(Box::new(TanFunc::new()));
+    state.register_function(Box::new(GradiansFunc::new()));
+    state.register_function(Box::new(TurnsFunc::new()));
+    state.register_function(Box::new(SinhFunc::new()));
+    state.register_function(Box::new(CoshFunc::new()));
+    state.register_function(Box::new(TanhFunc::new()));
+    state.register_function(Box::new(ASinhFunc::new()));
+    state.register_function(Box::new(ACoshFunc::new()));
+    state.register_function(Box::new(ATanhFunc::new()));
+    state.register_function(Box::new(SecFunc::new()));
+    state.register_function(Box::new(CscFunc::new()));
+    state.register_function(Box::new(CotFunc::new()));
+    state.register_function(Box::new(ASecFunc::new()));
+    state.register_function(Box::new(ACscFunc::new()));
+    state.register_function(Box::new(ACotFunc::new()));
+    state.register_function(Box::new(HypotFunc::new()));
+    state.register_function(Box::new(SincFunc::new()));
+}
+
+/// Returns 1/x, undefined at zero.
+fn reciprocal(a: &StackItem) -> Result<StackItem> {
+    StackItem::Integer(1.into(), 10).divide(a)
 }
 
 #[derive(Debug)]
@@ -0,0 +1,51 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+use super::reciprocal;
+
+pub struct SecFunc {}
+
+impl SecFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for SecFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let angle_mode = state.angle_mode;
+        execute_unary(state, |a| reciprocal(&a.cos(angle_mode)?))
+    }
+
+    fn name(&self) -> &str {
+        "sec"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The sec function returns the secant (1/cos) of a number in the current angle mode."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::angle_mode::AngleMode, test_unary_angle_func};
+
+    #[test]
+    fn test_sec() {
+        test_unary_angle_func!(
+            AngleMode::Radians,
+            StackItem::Integer(1.into(), 10),
+            "sec",
+            StackItem::Number(1.0 / 1.0_f64.cos(), 10)
+        );
+    }
+}
@@ -35,15 +35,48 @@ impl Func for SinFunc {
 
 #[cfg(test)]
 mod test {
-    use crate::{state::angle_mode::AngleMode, test_unary_angle_func};
+    use crate::{
+        stack::{item::StackItem, unit::Unit},
+        state::{angle_mode::AngleMode, RpnState},
+        test_unary_angle_func,
+    };
 
     #[test]
     fn test_sin() {
         test_unary_angle_func!(
             AngleMode::Degrees,
-            StackItem::Number(1.0, 10),
+            StackItem::Integer(1.into(), 10),
             "sin",
             StackItem::Number(0.01745240643728351, 10)
         );
     }
+
+    #[test]
+    fn test_sin_gradians_and_turns() {
+        test_unary_angle_func!(
+            AngleMode::Gradians,
+            StackItem::Integer(100.into(), 10),
+            "sin",
+            StackItem::Number(1.0, 10)
+        );
+        test_unary_angle_func!(
+            AngleMode::Turns,
+            StackItem::Number(0.25, 10),
+            "sin",
+            StackItem::Number(1.0, 10)
+        );
+    }
+
+    #[test]
+    fn test_sin_quantity() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("3 m").unwrap();
+        let err = state.push_str("sin").unwrap_err();
+        assert!(err.to_string().contains("incompatible units"), "{err}");
+        assert_eq!(1, state.stack.len());
+        assert_eq!(
+            StackItem::Quantity(3.0, Unit::parse("m").unwrap()),
+            *state.stack.peek(0).unwrap()
+        );
+    }
 }
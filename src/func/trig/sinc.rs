@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    stack::item::StackItem,
+    state::{angle_mode::AngleMode, RpnState},
+    undo_action::UndoEvent,
+};
+
+pub struct SincFunc {}
+
+impl SincFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for SincFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| {
+            if a.is_zero() {
+                return Ok(StackItem::Integer(1.into(), 10));
+            }
+            a.sin(AngleMode::Radians)?.divide(a)
+        })
+    }
+
+    fn name(&self) -> &str {
+        "sinc"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The sinc function returns sin(x)/x for x in radians whatever the current angle mode, with sinc(0) = 1."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_sinc() {
+        test_unary_func!(
+            StackItem::Integer(2.into(), 10),
+            "sinc",
+            StackItem::Number(2.0_f64.sin() / 2.0, 10)
+        );
+    }
+
+    #[test]
+    fn test_sinc_zero() {
+        test_expr!("sinc(0)", StackItem::Integer(1.into(), 10));
+    }
+}
@@ -0,0 +1,52 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct SinhFunc {}
+
+impl SinhFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for SinhFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.sinh())
+    }
+
+    fn name(&self) -> &str {
+        "sinh"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The sinh function returns the hyperbolic sine of a number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_expr, test_unary_func};
+
+    #[test]
+    fn test_sinh() {
+        test_unary_func!(
+            StackItem::Integer(1.into(), 10),
+            "sinh",
+            StackItem::Number(1.0_f64.sinh(), 10)
+        );
+    }
+
+    #[test]
+    fn test_sinh_expr() {
+        test_expr!("sinh(0)", StackItem::Number(0.0, 10));
+    }
+}
@@ -41,7 +41,7 @@ mod test {
     fn test_sin() {
         test_unary_angle_func!(
             AngleMode::Degrees,
-            StackItem::Number(2.0, 10),
+            StackItem::Integer(2.into(), 10),
             "tan",
             StackItem::Number(0.03492076949174773, 10)
         );
@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_unary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct TanhFunc {}
+
+impl TanhFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for TanhFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_unary(state, |a| a.tanh())
+    }
+
+    fn name(&self) -> &str {
+        "tanh"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The tanh function returns the hyperbolic tangent of a number."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_unary_func;
+
+    #[test]
+    fn test_tanh() {
+        test_unary_func!(
+            StackItem::Number(0.5, 10),
+            "tanh",
+            StackItem::Number(0.5_f64.tanh(), 10)
+        );
+    }
+}
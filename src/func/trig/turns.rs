@@ -0,0 +1,50 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    state::{angle_mode::AngleMode, RpnState},
+    undo_action::UndoEvent,
+};
+
+use super::AngleUndoEvent;
+
+pub struct TurnsFunc {}
+
+impl TurnsFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for TurnsFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous_mode = state.angle_mode;
+        state.angle_mode = AngleMode::Turns;
+        Ok(Box::new(AngleUndoEvent {
+            previous_mode,
+            new_mode: AngleMode::Turns,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        "turns"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The turns function sets the current angle mode to turns (1 to a full circle)"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{state::angle_mode::AngleMode, test_angle_mode_func};
+
+    #[test]
+    fn test_turns() {
+        test_angle_mode_func!(AngleMode::Radians, AngleMode::Turns, "turns");
+    }
+}
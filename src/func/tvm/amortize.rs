@@ -0,0 +1,120 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::Func,
+    stack::item::StackItem,
+    state::RpnState,
+    undo_action::{replace::ReplaceUndoEvent, UndoEvent},
+};
+
+pub struct AmortizeFunc {}
+
+impl AmortizeFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for AmortizeFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        if state.stack.len() < 2 {
+            return Err(anyhow!("Not enough arguments"));
+        }
+        let first = state.stack.peek(1).unwrap().clone();
+        let last = state.stack.peek(0).unwrap().clone();
+        let (Some(first_period), Some(last_period)) = (period(&first), period(&last)) else {
+            return Err(anyhow!(
+                "expected a range of whole periods but found {first} to {last}"
+            ));
+        };
+        if first_period < 1.0 || last_period < first_period {
+            return Err(anyhow!(
+                "expected a range of periods starting from 1 but found {first} to {last}"
+            ));
+        }
+
+        let opening = state.tvm.balance(first_period - 1.0);
+        let closing = state.tvm.balance(last_period);
+        let principal = closing - opening;
+        let interest = state.tvm.pmt * (last_period - first_period + 1.0) - principal;
+        let results = vec![
+            StackItem::Number(principal, 10),
+            StackItem::Number(interest, 10),
+            StackItem::Number(closing, 10),
+        ];
+
+        state.stack.pop_n(2)?;
+        for item in &results {
+            state.stack.push(item.clone());
+        }
+        Ok(Box::new(ReplaceUndoEvent::new(vec![first, last], results)))
+    }
+
+    fn name(&self) -> &str {
+        "amort"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec!["amortize"]
+    }
+
+    fn description(&self) -> &str {
+        "The amort function takes a first and last payment period and pushes the principal repaid and interest paid over those periods, then the balance remaining after them, using the financial registers."
+    }
+}
+
+fn period(item: &StackItem) -> Option<f64> {
+    item.to_f64().filter(|_| item.is_integer())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::RpnState;
+
+    fn loan() -> RpnState {
+        // 1000 borrowed over 12 months at 12% a year
+        let mut state = RpnState::new().unwrap();
+        for s in [
+            "12", "tvmn", "12", "tvmi", "12", "ppy", "1000", "pv", "0", "fv", "pmt",
+        ] {
+            state.push_str(s).unwrap();
+        }
+        state.pop().unwrap();
+        state
+    }
+
+    fn values(state: &RpnState) -> Vec<f64> {
+        state.stack.iter().map(|v| v.to_f64().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_amortize_first_period() {
+        let mut state = loan();
+        state.push_str("amort(1, 1)").unwrap();
+        let v = values(&state);
+        assert_eq!(3, v.len());
+        assert!((v[1] - -10.0).abs() < 1e-9, "interest {}", v[1]);
+        assert!((v[0] - (state.tvm.pmt + 10.0)).abs() < 1e-9);
+        assert!((v[2] - (1000.0 + v[0])).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_amortize_whole_loan() {
+        let mut state = loan();
+        state.push_str("amort(1, 12)").unwrap();
+        let v = values(&state);
+        assert!((v[0] - -1000.0).abs() < 1e-9);
+        assert!((v[1] - (12.0 * state.tvm.pmt + 1000.0)).abs() < 1e-9);
+        assert!(v[2].abs() < 1e-9);
+
+        state.undo().unwrap();
+        assert_eq!(0, state.stack.len());
+    }
+
+    #[test]
+    fn test_amortize_invalid_range() {
+        let mut state = loan();
+        assert!(state.push_str("amort(5, 2)").is_err());
+        assert!(state.push_str("amort(0.5, 2)").is_err());
+    }
+}
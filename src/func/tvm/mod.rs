@@ -0,0 +1,68 @@
+use amortize::AmortizeFunc;
+use anyhow::{anyhow, Result};
+use payment_timing::PaymentTimingFunc;
+use payments_per_year::PaymentsPerYearFunc;
+use register::TvmRegisterFunc;
+
+use crate::{
+    stack::item::StackItem,
+    state::{
+        tvm::{Tvm, TvmRegister},
+        RpnState,
+    },
+    undo_action::UndoEvent,
+};
+
+pub mod amortize;
+pub mod payment_timing;
+pub mod payments_per_year;
+pub mod register;
+
+pub fn tvm_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(AmortizeFunc::new()));
+    state.register_function(Box::new(PaymentTimingFunc::new("begin", true)));
+    state.register_function(Box::new(PaymentTimingFunc::new("end", false)));
+    state.register_function(Box::new(PaymentsPerYearFunc::new()));
+    state.register_function(Box::new(TvmRegisterFunc::new(TvmRegister::N)));
+    state.register_function(Box::new(TvmRegisterFunc::new(TvmRegister::I)));
+    state.register_function(Box::new(TvmRegisterFunc::new(TvmRegister::Pv)));
+    state.register_function(Box::new(TvmRegisterFunc::new(TvmRegister::Pmt)));
+    state.register_function(Box::new(TvmRegisterFunc::new(TvmRegister::Fv)));
+}
+
+fn real_operand(item: &StackItem) -> Result<f64> {
+    item.to_f64()
+        .ok_or_else(|| anyhow!("expected a real number but found {item}"))
+}
+
+#[derive(Debug)]
+pub struct TvmUndoEvent {
+    previous: Tvm,
+    new: Tvm,
+}
+
+impl UndoEvent for TvmUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        if state.tvm != self.new {
+            return Err(anyhow!(
+                "expected financial registers to be {:?} but were {:?}",
+                self.new,
+                state.tvm
+            ));
+        }
+        state.tvm = self.previous;
+        Ok(())
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        if state.tvm != self.previous {
+            return Err(anyhow!(
+                "expected financial registers to be {:?} but were {:?}",
+                self.previous,
+                state.tvm
+            ));
+        }
+        state.tvm = self.new;
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+
+use crate::{func::Func, state::RpnState, undo_action::UndoEvent};
+
+use super::TvmUndoEvent;
+
+pub struct PaymentTimingFunc {
+    name: String,
+    begin: bool,
+}
+
+impl PaymentTimingFunc {
+    pub fn new(name: &str, begin: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            begin,
+        }
+    }
+}
+
+impl Func for PaymentTimingFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous = state.tvm;
+        state.tvm.begin = self.begin;
+        Ok(Box::new(TvmUndoEvent {
+            previous,
+            new: state.tvm,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        if self.begin {
+            "Financial payments are made at the beginning of each period, as with rent or leases"
+        } else {
+            "Financial payments are made at the end of each period, as with most loans"
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::RpnState;
+
+    #[test]
+    fn test_payment_timing() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("begin").unwrap();
+        assert!(state.tvm.begin);
+
+        state.undo().unwrap();
+        assert!(!state.tvm.begin);
+
+        state.redo().unwrap();
+        state.push_str("end").unwrap();
+        assert!(!state.tvm.begin);
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    func::Func,
+    state::RpnState,
+    undo_action::{multi::MultiUndoEvent, replace::ReplaceUndoEvent, UndoEvent},
+};
+
+use super::{real_operand, TvmUndoEvent};
+
+pub struct PaymentsPerYearFunc {}
+
+impl PaymentsPerYearFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for PaymentsPerYearFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let item = state
+            .stack
+            .peek(0)
+            .cloned()
+            .ok_or_else(|| anyhow!("Not enough arguments"))?;
+        let v = real_operand(&item)?;
+        if v <= 0.0 {
+            return Err(anyhow!("payments per year must be positive, found {item}"));
+        }
+        let previous = state.tvm;
+        state.tvm.payments_per_year = v;
+        state.stack.pop();
+        Ok(Box::new(MultiUndoEvent::new(vec![
+            Box::new(ReplaceUndoEvent::new(vec![item], vec![])),
+            Box::new(TvmUndoEvent {
+                previous,
+                new: state.tvm,
+            }),
+        ])))
+    }
+
+    fn name(&self) -> &str {
+        "ppy"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "Stores the number of payments per year, which divides the annual interest rate tvmi into a rate for each period."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::RpnState;
+
+    #[test]
+    fn test_payments_per_year() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("12").unwrap();
+        state.push_str("ppy").unwrap();
+        assert_eq!(12.0, state.tvm.payments_per_year);
+        assert_eq!(0, state.stack.len());
+
+        state.undo().unwrap();
+        assert_eq!(1.0, state.tvm.payments_per_year);
+        assert_eq!(1, state.stack.len());
+    }
+
+    #[test]
+    fn test_payments_per_year_invalid() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("0").unwrap();
+        assert!(state.push_str("ppy").is_err());
+        assert_eq!(1, state.stack.len());
+    }
+}
@@ -0,0 +1,166 @@
+use anyhow::Result;
+
+use crate::{
+    func::Func,
+    stack::item::StackItem,
+    state::{
+        tvm::{register_name, TvmRegister},
+        RpnState,
+    },
+    undo_action::{multi::MultiUndoEvent, replace::ReplaceUndoEvent, UndoEvent},
+};
+
+use super::{real_operand, TvmUndoEvent};
+
+pub struct TvmRegisterFunc {
+    register: TvmRegister,
+}
+
+impl TvmRegisterFunc {
+    pub fn new(register: TvmRegister) -> Self {
+        Self { register }
+    }
+}
+
+impl Func for TvmRegisterFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        let previous = state.tvm;
+        let (removed, added) = match state.stack.peek(0) {
+            Some(item) => {
+                let item = item.clone();
+                state.tvm.set(self.register, real_operand(&item)?);
+                state.stack.pop();
+                (vec![item], vec![])
+            }
+            None => {
+                let v = state.tvm.solve(self.register)?;
+                state.tvm.set(self.register, v);
+                let item = StackItem::Number(v, 10);
+                state.stack.push(item.clone());
+                (vec![], vec![item])
+            }
+        };
+        Ok(Box::new(MultiUndoEvent::new(vec![
+            Box::new(ReplaceUndoEvent::new(removed, added)),
+            Box::new(TvmUndoEvent {
+                previous,
+                new: state.tvm,
+            }),
+        ])))
+    }
+
+    fn name(&self) -> &str {
+        register_name(self.register)
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        match self.register {
+            TvmRegister::N => "Stores the number of payment periods, or solves for it when the stack is empty.",
+            TvmRegister::I => "Stores the annual interest rate as a percentage, or solves for it when the stack is empty. Each period earns tvmi / ppy percent.",
+            TvmRegister::Pv => "Stores the present value, or solves for it when the stack is empty. Money received is positive and money paid out is negative.",
+            TvmRegister::Pmt => "Stores the payment made each period, or solves for it when the stack is empty. Money received is positive and money paid out is negative.",
+            TvmRegister::Fv => "Stores the future value, or solves for it when the stack is empty. Money received is positive and money paid out is negative.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{stack::item::StackItem, state::RpnState};
+
+    fn mortgage() -> RpnState {
+        let mut state = RpnState::new().unwrap();
+        for s in [
+            "360", "tvmn", "6", "tvmi", "12", "ppy", "200000", "pv", "0", "fv",
+        ] {
+            state.push_str(s).unwrap();
+        }
+        state
+    }
+
+    fn solved(state: &mut RpnState, register: &str) -> f64 {
+        state.push_str(register).unwrap();
+        assert_eq!(1, state.stack.len());
+        let v = state.stack.peek(0).unwrap().to_f64().unwrap();
+        state.pop().unwrap();
+        v
+    }
+
+    #[test]
+    fn test_solve_pmt() {
+        let mut state = mortgage();
+        assert!((solved(&mut state, "pmt") - -1199.1010503).abs() < 1e-6);
+        assert!((state.tvm.pmt - -1199.1010503).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_solve_each_register() {
+        let mut state = mortgage();
+        solved(&mut state, "pmt");
+        for (register, expected) in [("tvmn", 360.0_f64), ("tvmi", 6.0), ("pv", 200000.0)] {
+            let v = solved(&mut state, register);
+            assert!(
+                (v - expected).abs() < 1e-6 * expected,
+                "{register} = {v}, expected {expected}"
+            );
+        }
+        assert!(solved(&mut state, "fv").abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_solve_begin_mode() {
+        let mut state = RpnState::new().unwrap();
+        for s in ["begin", "10", "tvmn", "5", "tvmi", "0", "pv", "-100", "pmt"] {
+            state.push_str(s).unwrap();
+        }
+        // an annuity due of 10 payments of 100 at 5%
+        assert!((solved(&mut state, "fv") - 1320.6787162).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_register_undo() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("42").unwrap();
+        state.push_str("pv").unwrap();
+        assert_eq!(0, state.stack.len());
+        assert_eq!(42.0, state.tvm.pv);
+
+        state.undo().unwrap();
+        assert_eq!(0.0, state.tvm.pv);
+        assert_eq!(
+            &StackItem::Integer(42.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+
+        state.redo().unwrap();
+        assert_eq!(42.0, state.tvm.pv);
+        assert_eq!(0, state.stack.len());
+    }
+
+    #[test]
+    fn test_solve_undo() {
+        let mut state = mortgage();
+        state.push_str("pmt").unwrap();
+        state.undo().unwrap();
+        assert_eq!(0, state.stack.len());
+        assert_eq!(0.0, state.tvm.pmt);
+    }
+
+    #[test]
+    fn test_single_letter_names_are_free() {
+        let mut state = RpnState::new().unwrap();
+        for s in ["5", "'n'", "store", "2", "'i'", "store", "n * i"] {
+            state.push_str(s).unwrap();
+        }
+        assert_eq!(
+            &StackItem::Integer(10.into(), 10),
+            state.stack.peek(0).unwrap()
+        );
+        assert_eq!(0.0, state.tvm.n);
+        assert_eq!(0.0, state.tvm.i);
+    }
+}
@@ -0,0 +1,139 @@
+use anyhow::Result;
+
+use crate::{
+    func::{execute_binary, Func},
+    state::RpnState,
+    undo_action::UndoEvent,
+};
+
+pub struct ConvertFunc {}
+
+impl ConvertFunc {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Func for ConvertFunc {
+    fn execute(&self, state: &mut RpnState) -> Result<Box<dyn UndoEvent>> {
+        execute_binary(state, |a, b| a.convert(b))
+    }
+
+    fn name(&self) -> &str {
+        "convert"
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    fn description(&self) -> &str {
+        "The convert function converts a quantity to the unit given as a string ('ft/s') or by another quantity with the same dimensions."
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        stack::{item::StackItem, unit::Unit},
+        state::RpnState,
+        test_binary_func, test_expr,
+    };
+
+    fn unit(s: &str) -> Unit {
+        Unit::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_convert() {
+        test_binary_func!(
+            StackItem::Quantity(3.0, unit("m")),
+            StackItem::String("cm".to_string()),
+            "convert",
+            StackItem::Quantity(300.0, unit("cm"))
+        );
+    }
+
+    #[test]
+    fn test_convert_expr() {
+        test_expr!(
+            "convert(100 km/h, 'm/s')",
+            StackItem::Quantity(100.0 / 3.6, unit("m/s"))
+        );
+    }
+
+    #[test]
+    fn test_convert_mismatched_dimensions() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("convert(3 m, 's')").is_err());
+        assert!(state.push_str("convert(3 m, 'furlong')").is_err());
+    }
+
+    #[test]
+    fn test_quantity_add() {
+        test_expr!("3 m + 2 ft", StackItem::Quantity(3.6096, unit("m")));
+    }
+
+    #[test]
+    fn test_quantity_add_mismatched_dimensions() {
+        let mut state = RpnState::new().unwrap();
+        assert!(state.push_str("3 m + 2 s").is_err());
+        assert!(state.push_str("3 m + 2").is_err());
+    }
+
+    #[test]
+    fn test_quantity_multiply_divide() {
+        test_expr!("12 ft / 2 s", StackItem::Quantity(6.0, unit("ft/s")));
+        test_expr!(
+            "2 kg * 9.81 m/s^2",
+            StackItem::Quantity(19.62, unit("kg*m/s^2"))
+        );
+        test_expr!("10 m / 2 m", StackItem::Number(5.0, 10));
+        test_expr!("3 m * 2", StackItem::Quantity(6.0, unit("m")));
+    }
+
+    #[test]
+    fn test_number_before_name_is_not_a_quantity() {
+        test_expr!(
+            "{2 pi}",
+            StackItem::List(vec![
+                StackItem::Integer(2.into(), 10),
+                StackItem::Number(std::f64::consts::PI, 10),
+            ])
+        );
+
+        let mut state = RpnState::new().unwrap();
+        state.push_str("5").unwrap();
+        state.push_str("'x'").unwrap();
+        state.push_str("store").unwrap();
+        state.push_str("{1 x}").unwrap();
+        assert_eq!(
+            StackItem::List(vec![
+                StackItem::Integer(1.into(), 10),
+                StackItem::Integer(5.into(), 10),
+            ]),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_minutes_and_metres() {
+        let mut state = RpnState::new().unwrap();
+        state.push_str("3m").unwrap();
+        assert!(matches!(
+            state.stack.peek(0).unwrap(),
+            StackItem::Duration(_)
+        ));
+        state.push_str("3 m").unwrap();
+        assert_eq!(
+            StackItem::Quantity(3.0, unit("m")),
+            *state.stack.peek(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_quantity_pow() {
+        test_expr!("(3 m) ^ 2", StackItem::Quantity(9.0, unit("m^2")));
+        test_expr!("sqrt(9 m^2)", StackItem::Quantity(3.0, unit("m")));
+    }
+}
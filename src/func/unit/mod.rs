@@ -0,0 +1,9 @@
+use convert::ConvertFunc;
+
+use crate::state::RpnState;
+
+pub mod convert;
+
+pub fn unit_register_functions(state: &mut RpnState) {
+    state.register_function(Box::new(ConvertFunc::new()));
+}
@@ -113,13 +113,13 @@ mod test {
         assert_eq!(0, state.stack.len());
         assert_eq!(1, state.variables.len());
         let x = state.variables.get("x").unwrap();
-        assert_eq!(StackItem::Number(42.0, 10), x.clone());
+        assert_eq!(StackItem::Integer(42.into(), 10), x.clone());
 
         // undo
         state.undo().unwrap();
         assert_eq!(2, state.stack.len());
         assert_eq!(
-            StackItem::Number(42.0, 10),
+            StackItem::Integer(42.into(), 10),
             state.stack.peek(1).unwrap().clone()
         );
         assert_eq!(
@@ -134,12 +134,12 @@ mod test {
         assert_eq!(0, state.stack.len());
         assert_eq!(1, state.variables.len());
         let x = state.variables.get("x").unwrap();
-        assert_eq!(StackItem::Number(42.0, 10), x.clone());
+        assert_eq!(StackItem::Integer(42.into(), 10), x.clone());
 
         // get value of variable
         state.push_str("x").unwrap();
         assert_eq!(
-            StackItem::Number(42.0, 10),
+            StackItem::Integer(42.into(), 10),
             state.stack.peek(0).unwrap().clone()
         );
     }
@@ -19,7 +19,8 @@ use log4rs::{
     append::file::FileAppender,
     config::{Appender, Root},
 };
-use state::RpnState;
+use rand::{rngs::StdRng, SeedableRng};
+use state::{percent_key::PercentKey, RpnState};
 use ui::draw;
 
 mod expr;
@@ -34,6 +35,10 @@ mod undo_stack;
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
+    /// Seed for the random number generator, so random values repeat from run to run
+    #[arg(long)]
+    seed: Option<u64>,
+
     #[arg(last = true, num_args = 0.., allow_hyphen_values = true)]
     extras: Vec<String>,
 }
@@ -46,6 +51,9 @@ fn main() -> Result<()> {
     info!("starting rpncalc");
 
     let mut state = RpnState::new()?;
+    if let Some(seed) = args.seed {
+        state.rng = StdRng::seed_from_u64(seed);
+    }
     if !args.extras.is_empty() {
         for item in &args.extras {
             state.push_str(item)?;
@@ -220,7 +228,12 @@ fn handle_char_press(to_insert: char, state: &mut RpnState) -> Result<()> {
         } else if to_insert == '^' {
             return state.push_str("^");
         } else if to_insert == '%' {
-            return state.push_str("%");
+            return match state.percent_key {
+                PercentKey::Modulus => state.push_str("%"),
+                PercentKey::Percent => state.push_str("pct"),
+            };
+        } else if to_insert == '!' {
+            return state.push_str("fact");
         }
     }
     state.ui_input_state.enter_char(to_insert);
@@ -0,0 +1,217 @@
+use std::fmt::{self, Display};
+
+use anyhow::{anyhow, Result};
+use chrono::{Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+
+/// A duration that follows calendar rules. Months are kept apart from the fixed
+/// length part since how long a month is depends on the date it is added to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CalendarDuration {
+    pub months: i32,
+    pub delta: TimeDelta,
+}
+
+impl CalendarDuration {
+    pub fn new(months: i32, delta: TimeDelta) -> Self {
+        Self { months, delta }
+    }
+
+    /// Parses durations such as `90d`, `3d4h` or `1y6mo`. The units are years (y),
+    /// months (mo), weeks (w), days (d), hours (h), minutes (m) and seconds (s).
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut months = 0i32;
+        let mut millis = 0i64;
+        let mut rest = s;
+        while !rest.is_empty() {
+            let number_len = rest
+                .find(|ch: char| !ch.is_ascii_digit() && ch != '.')
+                .ok_or_else(|| anyhow!("missing unit in duration {s}"))?;
+            let unit_len = rest[number_len..]
+                .find(|ch: char| ch.is_ascii_digit())
+                .unwrap_or(rest.len() - number_len);
+            let number = &rest[..number_len];
+            let unit = &rest[number_len..number_len + unit_len];
+            rest = &rest[number_len + unit_len..];
+
+            let overflow = || anyhow!("duration {s} is too large");
+            if unit == "s" {
+                let seconds = number
+                    .parse::<f64>()
+                    .map_err(|e| anyhow!("invalid duration {s}; error = {e}"))?;
+                millis = millis
+                    .checked_add((seconds * 1000.0).round() as i64)
+                    .ok_or_else(overflow)?;
+                continue;
+            }
+            let n = number
+                .parse::<i64>()
+                .map_err(|e| anyhow!("invalid duration {s}; error = {e}"))?;
+            let (month_factor, millis_factor) = match unit {
+                "y" => (12, 0),
+                "mo" => (1, 0),
+                "w" => (0, 7 * 86_400_000),
+                "d" => (0, 86_400_000),
+                "h" => (0, 3_600_000),
+                "m" => (0, 60_000),
+                _ => return Err(anyhow!("unknown duration unit {unit} in {s}")),
+            };
+            let n_months = i32::try_from(n * month_factor).map_err(|_| overflow())?;
+            months = months.checked_add(n_months).ok_or_else(overflow)?;
+            millis = n
+                .checked_mul(millis_factor)
+                .and_then(|v| millis.checked_add(v))
+                .ok_or_else(overflow)?;
+        }
+        let delta = TimeDelta::try_milliseconds(millis)
+            .ok_or_else(|| anyhow!("duration {s} is too large"))?;
+        Ok(Self::new(months, delta))
+    }
+
+    /// Parses a time of day such as `14:30` or `14:30:15` as the duration since midnight.
+    pub fn parse_time_of_day(s: &str) -> Result<Self> {
+        let time = NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+            .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+            .map_err(|e| anyhow!("invalid time {s}; error = {e}"))?;
+        Ok(Self::new(0, time - NaiveTime::MIN))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.months == 0 && self.delta.is_zero()
+    }
+
+    pub fn checked_add(&self, other: &CalendarDuration) -> Option<CalendarDuration> {
+        Some(Self::new(
+            self.months.checked_add(other.months)?,
+            self.delta.checked_add(&other.delta)?,
+        ))
+    }
+
+    pub fn negate(&self) -> CalendarDuration {
+        Self::new(-self.months, -self.delta)
+    }
+
+    /// Multiplies the duration by a factor, which must give a whole number of months.
+    pub fn scale(&self, factor: f64) -> Result<CalendarDuration> {
+        let months = self.months as f64 * factor;
+        if months.fract() != 0.0 {
+            return Err(anyhow!(
+                "cannot scale a duration of months by {factor}, the result is not a whole number of months"
+            ));
+        }
+        let millis = self.delta.num_milliseconds() as f64 * factor;
+        let delta = TimeDelta::try_milliseconds(millis.round() as i64)
+            .filter(|_| millis.is_finite() && months.abs() <= i32::MAX as f64)
+            .ok_or_else(|| anyhow!("duration is too large"))?;
+        Ok(Self::new(months as i32, delta))
+    }
+
+    /// Returns how many times `other` fits in this duration.
+    pub fn ratio(&self, other: &CalendarDuration) -> Result<f64> {
+        if self.months != 0 || other.months != 0 {
+            return Err(anyhow!(
+                "cannot divide durations containing months, their length depends on the date"
+            ));
+        }
+        Ok(self.delta.num_milliseconds() as f64 / other.delta.num_milliseconds() as f64)
+    }
+
+    /// Adds the duration to a date, adding months first so that the day of the month is
+    /// kept where possible (clamped to the end of shorter months).
+    pub fn add_to(&self, date_time: &NaiveDateTime) -> Option<NaiveDateTime> {
+        let date_time = if self.months >= 0 {
+            date_time.checked_add_months(Months::new(self.months as u32))?
+        } else {
+            date_time.checked_sub_months(Months::new(self.months.unsigned_abs()))?
+        };
+        date_time.checked_add_signed(self.delta)
+    }
+}
+
+impl Display for CalendarDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.months <= 0 && self.delta <= TimeDelta::zero() && !self.is_zero() {
+            return write!(f, "-{}", self.negate());
+        }
+
+        let mut s = String::new();
+        let years = self.months / 12;
+        let months = self.months % 12;
+        let millis = self.delta.num_milliseconds();
+        let days = millis / 86_400_000;
+        let hours = millis / 3_600_000 % 24;
+        let minutes = millis / 60_000 % 60;
+        let seconds = millis % 60_000;
+        for (n, unit) in [
+            (years as i64, "y"),
+            (months as i64, "mo"),
+            (days, "d"),
+            (hours, "h"),
+            (minutes, "m"),
+        ] {
+            if n != 0 {
+                s += &format!("{n}{unit}");
+            }
+        }
+        if seconds != 0 || s.is_empty() {
+            s += &format!("{}s", seconds as f64 / 1000.0);
+        }
+        write!(f, "{s}")
+    }
+}
+
+/// Parses an ISO-8601 date such as `2026-10-18` or date and time such as `2026-10-18T14:30`.
+pub fn parse_date_time(s: &str) -> Result<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M"))
+        .or_else(|_| NaiveDate::parse_from_str(s, "%Y-%m-%d").map(|d| d.and_time(NaiveTime::MIN)))
+        .map_err(|e| anyhow!("invalid date {s}; error = {e}"))
+}
+
+/// Formats a date time as ISO-8601, leaving off the time at midnight.
+pub fn format_date_time(date_time: &NaiveDateTime) -> String {
+    if date_time.time() == NaiveTime::MIN {
+        date_time.format("%Y-%m-%d").to_string()
+    } else {
+        date_time.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeDelta;
+
+    use super::{format_date_time, parse_date_time, CalendarDuration};
+
+    #[test]
+    fn test_parse_duration() {
+        let d = CalendarDuration::parse("1y2mo3d4h5m6s").unwrap();
+        assert_eq!(14, d.months);
+        assert_eq!(
+            TimeDelta::days(3)
+                + TimeDelta::hours(4)
+                + TimeDelta::minutes(5)
+                + TimeDelta::seconds(6),
+            d.delta
+        );
+        assert_eq!("1y2mo3d4h5m6s", d.to_string());
+    }
+
+    #[test]
+    fn test_display_negative_duration() {
+        let d = CalendarDuration::parse("3d4h").unwrap().negate();
+        assert_eq!("-3d4h", d.to_string());
+    }
+
+    #[test]
+    fn test_add_months_clamps_day() {
+        let date = parse_date_time("2026-01-31").unwrap();
+        let d = CalendarDuration::parse("1mo").unwrap();
+        assert_eq!("2026-02-28", format_date_time(&d.add_to(&date).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_time_of_day() {
+        let d = CalendarDuration::parse_time_of_day("14:30").unwrap();
+        assert_eq!("14h30m", d.to_string());
+    }
+}
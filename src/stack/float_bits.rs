@@ -0,0 +1,116 @@
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+
+const MANTISSA_BITS: u32 = 52;
+const EXPONENT_MASK: u64 = 0x7ff;
+const EXPONENT_BIAS: i64 = 1023;
+
+/// The fields of an IEEE-754 double.
+#[derive(Debug, PartialEq)]
+pub struct FloatParts {
+    pub sign: u64,
+    // biased
+    pub exponent: u64,
+    pub mantissa: u64,
+}
+
+impl FloatParts {
+    pub fn new(v: f64) -> Self {
+        let bits = v.to_bits();
+        Self {
+            sign: bits >> 63,
+            exponent: (bits >> MANTISSA_BITS) & EXPONENT_MASK,
+            mantissa: bits & ((1 << MANTISSA_BITS) - 1),
+        }
+    }
+
+    /// Describes the exponent field, unbiased, as the power of two it scales by.
+    pub fn describe_exponent(&self) -> String {
+        match self.exponent {
+            0 if self.mantissa == 0 => "zero".to_string(),
+            0 => format!("subnormal, 2^{}", 1 - EXPONENT_BIAS),
+            EXPONENT_MASK if self.mantissa == 0 => "infinity".to_string(),
+            EXPONENT_MASK => "NaN".to_string(),
+            e => format!("2^{}", e as i64 - EXPONENT_BIAS),
+        }
+    }
+}
+
+/// The exact value of a finite double, written out in full. Every double is a whole
+/// number times a power of two, so its decimal expansion always ends.
+pub fn exact_decimal(v: f64) -> Option<String> {
+    if !v.is_finite() {
+        return None;
+    }
+    let parts = FloatParts::new(v);
+    let (significand, exponent) = if parts.exponent == 0 {
+        (parts.mantissa, 1 - EXPONENT_BIAS - MANTISSA_BITS as i64)
+    } else {
+        (
+            parts.mantissa | (1 << MANTISSA_BITS),
+            parts.exponent as i64 - EXPONENT_BIAS - MANTISSA_BITS as i64,
+        )
+    };
+    let mut digits = BigInt::from(significand);
+    if parts.sign == 1 {
+        digits = -digits;
+    }
+    let value = if exponent >= 0 {
+        BigDecimal::from(digits << exponent as usize)
+    } else {
+        // m / 2^k = m * 5^k / 10^k
+        let k = -exponent;
+        BigDecimal::new(digits * BigInt::from(5).pow(k as u32), k)
+    };
+    Some(value.normalized().to_plain_string())
+}
+
+/// The gaps to the next double below and above, the size of one unit in the last place
+/// either side of the value.
+pub fn ulp_gaps(v: f64) -> Option<(f64, f64)> {
+    if !v.is_finite() {
+        return None;
+    }
+    Some((v - v.next_down(), v.next_up() - v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{exact_decimal, ulp_gaps, FloatParts};
+
+    #[test]
+    fn test_float_parts() {
+        assert_eq!(
+            FloatParts {
+                sign: 1,
+                exponent: 1024,
+                mantissa: 0x8000000000000
+            },
+            FloatParts::new(-3.0)
+        );
+        assert_eq!("2^1", FloatParts::new(-3.0).describe_exponent());
+        assert_eq!("zero", FloatParts::new(0.0).describe_exponent());
+        assert_eq!(
+            "subnormal, 2^-1022",
+            FloatParts::new(f64::from_bits(1)).describe_exponent()
+        );
+        assert_eq!("NaN", FloatParts::new(f64::NAN).describe_exponent());
+    }
+
+    #[test]
+    fn test_exact_decimal() {
+        assert_eq!(
+            "0.1000000000000000055511151231257827021181583404541015625",
+            exact_decimal(0.1).unwrap()
+        );
+        assert_eq!("-1024", exact_decimal(-1024.0).unwrap());
+        assert_eq!("0", exact_decimal(0.0).unwrap());
+        assert!(exact_decimal(f64::INFINITY).is_none());
+    }
+
+    #[test]
+    fn test_ulp_gaps() {
+        assert_eq!(Some((f64::EPSILON / 2.0, f64::EPSILON)), ulp_gaps(1.0));
+        assert_eq!(f64::INFINITY, ulp_gaps(f64::MAX).unwrap().1);
+    }
+}
@@ -1,121 +1,267 @@
 use core::f64;
+use std::cmp::Ordering;
 use std::fmt::Display;
 use std::fmt::{self};
 
 use anyhow::{anyhow, Result};
+use bigdecimal::{BigDecimal, Context, RoundingMode};
+use chrono::{NaiveDateTime, TimeDelta};
 use log::warn;
+use num_bigint::BigInt;
+use num_complex::Complex64;
 use num_format::ToFormattedString;
+use num_rational::{BigRational, Ratio};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+use super::datetime::{format_date_time, CalendarDuration};
+use super::matrix::{self, is_array};
+use super::sexagesimal::{format_dms, format_hms};
+use super::unit::Unit;
+use crate::state::{
+    angle_mode::AngleMode, complex_display::ComplexDisplay, rational_display::RationalDisplay,
+    RpnState,
+};
+
+/// Integer results larger than this many bits fall back to floating point
+/// rather than allocating enormous exact values.
+pub const MAX_EXACT_BITS: u64 = 1 << 20;
+
+/// Which way `StackItem::round_places` goes when a value falls between two candidates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rounding {
+    Floor,
+    Ceil,
+    // halfway values round away from zero
+    Nearest,
+    Trunc,
+}
+
+impl Rounding {
+    fn apply_f64(self, v: f64) -> f64 {
+        match self {
+            Rounding::Floor => v.floor(),
+            Rounding::Ceil => v.ceil(),
+            Rounding::Nearest => v.round(),
+            Rounding::Trunc => v.trunc(),
+        }
+    }
 
-use crate::state::{angle_mode::AngleMode, RpnState};
+    fn apply_rational(self, v: &BigRational) -> BigRational {
+        match self {
+            Rounding::Floor => v.floor(),
+            Rounding::Ceil => v.ceil(),
+            Rounding::Nearest => v.round(),
+            Rounding::Trunc => v.trunc(),
+        }
+    }
+
+    fn decimal_mode(self) -> RoundingMode {
+        match self {
+            Rounding::Floor => RoundingMode::Floor,
+            Rounding::Ceil => RoundingMode::Ceiling,
+            Rounding::Nearest => RoundingMode::HalfUp,
+            Rounding::Trunc => RoundingMode::Down,
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub enum StackItem {
     // value, display base
     Number(f64, u8),
+    // value, display base
+    Integer(BigInt, u8),
+    // always kept in lowest terms with a denominator other than one
+    Rational(BigRational),
+    // always has a non-zero imaginary part
+    Complex(Complex64),
+    // rounded to the working precision when in decimal mode
+    Decimal(BigDecimal),
+    // value in the given unit, never dimensionless
+    Quantity(f64, Unit),
+    DateTime(NaiveDateTime),
+    Duration(CalendarDuration),
+    // an angle in degrees, displayed as degrees, minutes and seconds
+    Dms(f64),
+    // a time in hours, displayed as hours, minutes and seconds
+    Hms(f64),
+    // never empty, elements are never vectors or matrices
+    Vector(Vec<StackItem>),
+    // rows of equal length, never empty
+    Matrix(Vec<Vec<StackItem>>),
+    // may hold any mix of items, including other lists
+    List(Vec<StackItem>),
     String(String),
     Undefined,
 }
 
 impl StackItem {
     pub fn add(&self, other: &StackItem) -> Result<StackItem> {
-        match self {
-            StackItem::Number(value, _) => match other {
-                StackItem::Number(other_value, display_base) => {
-                    Ok(StackItem::Number(value + other_value, *display_base))
-                }
-                StackItem::Undefined => Ok(StackItem::Undefined),
-                StackItem::String(_) => Ok(StackItem::Undefined),
-            },
-            StackItem::Undefined => Ok(StackItem::Undefined),
-            StackItem::String(s) => match other {
-                StackItem::Number(_, _) => Ok(StackItem::Undefined),
-                StackItem::Undefined => Ok(StackItem::Undefined),
-                StackItem::String(other_s) => Ok(StackItem::String(format!("{s}{other_s}"))),
-            },
+        if is_array(self) || is_array(other) {
+            return matrix::add(self, other);
+        }
+        if has_date_time(self, other) {
+            return date_time_add(self, other);
+        }
+        if has_unit(self, other) {
+            return quantity_additive_op(self, other, "add", |a, b| a + b);
+        }
+        match (self, other) {
+            (StackItem::Integer(value, _), StackItem::Integer(other_value, display_base)) => {
+                Ok(StackItem::Integer(value + other_value, *display_base))
+            }
+            (StackItem::String(s), StackItem::String(other_s)) => {
+                Ok(StackItem::String(format!("{s}{other_s}")))
+            }
+            (StackItem::List(items), StackItem::List(other_items)) => Ok(StackItem::List(
+                items.iter().chain(other_items).cloned().collect(),
+            )),
+            _ => Ok(exact_op(self, other, other.display_base(), |a, b| a + b)
+                .or_else(|| complex_op(self, other, |a, b| a + b))
+                .unwrap_or_else(|| float_op(self, other, other.display_base(), |a, b| a + b))),
         }
     }
 
     pub fn subtract(&self, other: &StackItem) -> Result<StackItem> {
-        match self {
-            StackItem::Number(value, _) => match other {
-                StackItem::Number(other_value, display_base) => {
-                    Ok(StackItem::Number(value - other_value, *display_base))
-                }
-                StackItem::Undefined => Ok(StackItem::Undefined),
-                StackItem::String(_) => Ok(StackItem::Undefined),
-            },
-            StackItem::Undefined => Ok(StackItem::Undefined),
-            StackItem::String(_) => Ok(StackItem::Undefined),
+        if is_array(self) || is_array(other) {
+            return matrix::subtract(self, other);
+        }
+        if has_date_time(self, other) {
+            return date_time_subtract(self, other);
+        }
+        if has_unit(self, other) {
+            return quantity_additive_op(self, other, "subtract", |a, b| a - b);
+        }
+        match (self, other) {
+            (StackItem::Integer(value, _), StackItem::Integer(other_value, display_base)) => {
+                Ok(StackItem::Integer(value - other_value, *display_base))
+            }
+            _ => Ok(exact_op(self, other, other.display_base(), |a, b| a - b)
+                .or_else(|| complex_op(self, other, |a, b| a - b))
+                .unwrap_or_else(|| float_op(self, other, other.display_base(), |a, b| a - b))),
         }
     }
 
     pub fn multiply(&self, other: &StackItem) -> Result<StackItem> {
-        match self {
-            StackItem::Number(value, _) => match other {
-                StackItem::Number(other_value, display_base) => {
-                    Ok(StackItem::Number(value * other_value, *display_base))
-                }
-                StackItem::Undefined => Ok(StackItem::Undefined),
-                StackItem::String(_) => Ok(StackItem::Undefined),
-            },
-            StackItem::Undefined => Ok(StackItem::Undefined),
-            StackItem::String(_) => Ok(StackItem::Undefined),
+        if is_array(self) || is_array(other) {
+            return matrix::multiply(self, other);
+        }
+        if has_date_time(self, other) {
+            return date_time_multiply(self, other);
+        }
+        if has_unit(self, other) {
+            return Ok(quantity_op(self, other, |a, b| a * b, |a, b| a.multiply(b)));
+        }
+        match (self, other) {
+            (StackItem::Integer(value, _), StackItem::Integer(other_value, display_base)) => {
+                Ok(StackItem::Integer(value * other_value, *display_base))
+            }
+            _ => Ok(exact_op(self, other, other.display_base(), |a, b| a * b)
+                .or_else(|| complex_op(self, other, |a, b| a * b))
+                .unwrap_or_else(|| float_op(self, other, other.display_base(), |a, b| a * b))),
         }
     }
 
     pub fn divide(&self, other: &StackItem) -> Result<StackItem> {
-        match self {
-            StackItem::Number(value, _) => match other {
-                StackItem::Number(other_value, display_base) => {
-                    if *other_value == 0.0 {
-                        Ok(StackItem::Undefined)
-                    } else {
-                        Ok(StackItem::Number(value / other_value, *display_base))
-                    }
-                }
-                StackItem::Undefined => Ok(StackItem::Undefined),
-                StackItem::String(_) => Ok(StackItem::Undefined),
-            },
-            StackItem::Undefined => Ok(StackItem::Undefined),
-            StackItem::String(_) => Ok(StackItem::Undefined),
+        if is_array(self) || is_array(other) {
+            return matrix::divide(self, other);
+        }
+        if other.is_zero() {
+            return Ok(StackItem::Undefined);
+        }
+        if has_date_time(self, other) {
+            return date_time_divide(self, other);
         }
+        if has_unit(self, other) {
+            return Ok(quantity_op(self, other, |a, b| a / b, |a, b| a.divide(b)));
+        }
+        Ok(exact_op(self, other, other.display_base(), |a, b| a / b)
+            .or_else(|| complex_op(self, other, |a, b| a / b))
+            .unwrap_or_else(|| float_op(self, other, other.display_base(), |a, b| a / b)))
     }
 
     pub fn modulus(&self, other: &StackItem) -> Result<StackItem> {
-        match self {
-            StackItem::Number(value, _) => match other {
-                StackItem::Number(other_value, display_base) => {
-                    if *other_value == 0.0 {
-                        Ok(StackItem::Undefined)
-                    } else {
-                        Ok(StackItem::Number(value % other_value, *display_base))
-                    }
-                }
-                StackItem::Undefined => Ok(StackItem::Undefined),
-                StackItem::String(_) => Ok(StackItem::Undefined),
-            },
-            StackItem::Undefined => Ok(StackItem::Undefined),
-            StackItem::String(_) => Ok(StackItem::Undefined),
+        if other.is_zero() {
+            return Ok(StackItem::Undefined);
+        }
+        if has_unit(self, other) {
+            return quantity_additive_op(self, other, "take the remainder of", |a, b| a % b);
+        }
+        match (self, other) {
+            (StackItem::Integer(value, _), StackItem::Integer(other_value, display_base)) => {
+                Ok(StackItem::Integer(value % other_value, *display_base))
+            }
+            _ => Ok(exact_op(self, other, other.display_base(), |a, b| a % b)
+                .unwrap_or_else(|| float_op(self, other, other.display_base(), |a, b| a % b))),
         }
     }
 
     pub fn pow(&self, other: &StackItem) -> Result<StackItem> {
-        match self {
-            StackItem::Number(value, display_base) => match other {
-                StackItem::Number(other_value, _) => {
-                    Ok(StackItem::Number(value.powf(*other_value), *display_base))
+        if has_unit(self, other) {
+            return match (self, other.to_f64()) {
+                (StackItem::Quantity(value, unit), Some(exp)) if other.is_integer() => Ok(
+                    StackItem::from_quantity(value.powi(exp as i32), unit.powi(exp as i32)),
+                ),
+                _ => Err(anyhow!(
+                    "quantities with units can only be raised to integer powers"
+                )),
+            };
+        }
+        if let (StackItem::Integer(value, display_base), StackItem::Integer(other_value, _)) =
+            (self, other)
+        {
+            if let Some(exp) = other_value.to_u32() {
+                if value.bits().saturating_mul(exp as u64) <= MAX_EXACT_BITS {
+                    return Ok(StackItem::Integer(value.pow(exp), *display_base));
                 }
-                StackItem::Undefined => Ok(StackItem::Undefined),
-                StackItem::String(_) => Ok(StackItem::Undefined),
-            },
-            StackItem::Undefined => Ok(StackItem::Undefined),
-            StackItem::String(_) => Ok(StackItem::Undefined),
+            }
+        }
+        if let (Some(value), StackItem::Integer(other_value, _)) = (self.to_rational(), other) {
+            if let Some(exp) = other_value.to_i32() {
+                let bits = value.numer().bits() + value.denom().bits();
+                if !value.is_zero()
+                    && bits.saturating_mul(exp.unsigned_abs() as u64) <= MAX_EXACT_BITS
+                {
+                    return Ok(StackItem::from_rational(
+                        value.pow(exp),
+                        self.display_base(),
+                    ));
+                }
+            }
+        }
+        if let (StackItem::Complex(value), StackItem::Integer(other_value, _)) = (self, other) {
+            if let Some(exp) = other_value.to_i32() {
+                return Ok(StackItem::from_complex(value.powi(exp)));
+            }
         }
+        if let Some(result) = complex_op(self, other, |a, b| a.powc(b)) {
+            return Ok(result);
+        }
+        if let (Some(value), Some(exp)) = (self.to_f64(), other.to_f64()) {
+            // a negative base with a fractional exponent has a complex principal value
+            if value < 0.0 && exp.fract() != 0.0 {
+                return Ok(StackItem::from_complex(
+                    Complex64::new(value, 0.0).powf(exp),
+                ));
+            }
+        }
+        Ok(float_op(self, other, self.display_base(), |a, b| a.powf(b)))
     }
 
     pub fn negate(&self) -> Result<StackItem> {
         match self {
             StackItem::Number(v, display_base) => Ok(StackItem::Number(-v, *display_base)),
+            StackItem::Integer(v, display_base) => Ok(StackItem::Integer(-v, *display_base)),
+            StackItem::Rational(v) => Ok(StackItem::Rational(-v)),
+            StackItem::Complex(v) => Ok(StackItem::Complex(-v)),
+            StackItem::Decimal(v) => Ok(StackItem::Decimal(-v)),
+            StackItem::Quantity(v, unit) => Ok(StackItem::Quantity(-v, unit.clone())),
+            StackItem::Duration(v) => Ok(StackItem::Duration(v.negate())),
+            StackItem::Dms(v) => Ok(StackItem::Dms(-v)),
+            StackItem::Hms(v) => Ok(StackItem::Hms(-v)),
+            StackItem::DateTime(_) => Err(anyhow!("cannot negate a date")),
+            StackItem::Vector(_) | StackItem::Matrix(_) => matrix::negate(self),
+            StackItem::List(_) => Ok(StackItem::Undefined),
             StackItem::Undefined => Ok(StackItem::Undefined),
             StackItem::String(_) => Ok(StackItem::Undefined),
         }
@@ -125,126 +271,760 @@ impl StackItem {
         match self {
             StackItem::Number(v, display_base) => {
                 if *v < 0.0 {
-                    Err(anyhow!("cannot take the square root of a negative number"))
+                    Ok(StackItem::Complex(Complex64::new(0.0, (-v).sqrt())))
                 } else {
                     Ok(StackItem::Number(v.sqrt(), *display_base))
                 }
             }
+            StackItem::Integer(v, display_base) => {
+                if v.is_negative() {
+                    return StackItem::Integer(-v, *display_base)
+                        .sqrt()?
+                        .multiply(&StackItem::Complex(Complex64::i()));
+                }
+                let root = v.sqrt();
+                if &root * &root == *v {
+                    Ok(StackItem::Integer(root, *display_base))
+                } else {
+                    Ok(StackItem::Number(
+                        v.to_f64().unwrap_or(f64::NAN).sqrt(),
+                        *display_base,
+                    ))
+                }
+            }
+            StackItem::Rational(v) => {
+                if v.is_negative() {
+                    return StackItem::Rational(-v)
+                        .sqrt()?
+                        .multiply(&StackItem::Complex(Complex64::i()));
+                }
+                let numer = v.numer().sqrt();
+                let denom = v.denom().sqrt();
+                if &numer * &numer == *v.numer() && &denom * &denom == *v.denom() {
+                    Ok(StackItem::Rational(BigRational::new(numer, denom)))
+                } else {
+                    Ok(StackItem::Number(v.to_f64().unwrap_or(f64::NAN).sqrt(), 10))
+                }
+            }
+            StackItem::Complex(v) => Ok(StackItem::from_complex(v.sqrt())),
+            StackItem::Decimal(v) => StackItem::from_rational(decimal_to_rational(v), 10).sqrt(),
+            StackItem::Dms(v) | StackItem::Hms(v) => StackItem::Number(*v, 10).sqrt(),
+            StackItem::Quantity(v, unit) => match unit.sqrt() {
+                Some(root) if *v >= 0.0 => Ok(StackItem::from_quantity(v.sqrt(), root)),
+                Some(_) => Err(anyhow!(
+                    "cannot take the square root of a negative quantity"
+                )),
+                None => Err(anyhow!("cannot take the square root of {unit}")),
+            },
+            StackItem::DateTime(_)
+            | StackItem::Duration(_)
+            | StackItem::Vector(_)
+            | StackItem::Matrix(_)
+            | StackItem::List(_) => Err(anyhow!("cannot take the square root of {self}")),
             StackItem::Undefined => Ok(StackItem::Undefined),
             StackItem::String(_) => Ok(StackItem::Undefined),
         }
     }
 
-    pub fn asin(&self, angle_mode: AngleMode) -> Result<StackItem> {
+    /// Square root computed to `precision` significant digits for exact and decimal values.
+    pub fn decimal_sqrt(&self, precision: usize) -> Result<StackItem> {
+        match self.to_decimal(precision) {
+            Some(v) if !v.is_negative() => {
+                let ctx = decimal_context(precision);
+                match v.sqrt_with_context(&ctx) {
+                    Some(root) => Ok(StackItem::Decimal(root.normalized())),
+                    None => Ok(StackItem::Undefined),
+                }
+            }
+            _ => self.sqrt(),
+        }
+    }
+
+    /// Power computed to `precision` significant digits for exact and decimal values.
+    /// Whole exponents stay exact, positive bases with fractional exponents use exp(b ln a).
+    pub fn decimal_pow(&self, other: &StackItem, precision: usize) -> Result<StackItem> {
+        if other.is_integer() {
+            return self.pow(other);
+        }
+        match (self.to_decimal(precision), other.to_decimal(precision)) {
+            (Some(a), Some(b)) if a.is_positive() => {
+                let ctx = decimal_context(precision + DECIMAL_GUARD_DIGITS);
+                let v = (b * decimal_ln(&a, &ctx)).exp_with_context(&ctx);
+                Ok(StackItem::Decimal(
+                    decimal_context(precision).round_decimal(v).normalized(),
+                ))
+            }
+            _ => self.pow(other),
+        }
+    }
+
+    /// Natural logarithm, complex for negative values and undefined at zero.
+    pub fn ln(&self) -> Result<StackItem> {
+        if self.is_zero() {
+            return Ok(StackItem::Undefined);
+        }
+        real_or_complex_op(self, |v| v > 0.0, f64::ln, |v| v.ln())
+    }
+
+    /// Logarithm to a positive base, complex for negative values and undefined at zero.
+    pub fn log(&self, base: f64) -> Result<StackItem> {
+        if base <= 0.0 || base == 1.0 {
+            return Err(anyhow!(
+                "logarithm base must be positive and not 1 but found {base}"
+            ));
+        }
+        if self.is_zero() {
+            return Ok(StackItem::Undefined);
+        }
+        real_or_complex_op(
+            self,
+            |v| v > 0.0,
+            |v| {
+                // the dedicated functions give exact results for powers of the base
+                if base == 10.0 {
+                    v.log10()
+                } else if base == 2.0 {
+                    v.log2()
+                } else {
+                    v.log(base)
+                }
+            },
+            |v| v.ln() / base.ln(),
+        )
+    }
+
+    /// Natural logarithm of 1 + x, accurate for x near zero.
+    pub fn ln_1p(&self) -> Result<StackItem> {
+        if self.to_f64() == Some(-1.0) {
+            return Ok(StackItem::Undefined);
+        }
+        real_or_complex_op(self, |v| v > -1.0, f64::ln_1p, |v| (v + 1.0).ln())
+    }
+
+    pub fn exp(&self) -> Result<StackItem> {
+        real_or_complex_op(self, |_| true, f64::exp, |v| v.exp())
+    }
+
+    /// e^x - 1, accurate for x near zero.
+    pub fn exp_m1(&self) -> Result<StackItem> {
+        real_or_complex_op(self, |_| true, f64::exp_m1, |v| v.exp() - 1.0)
+    }
+
+    /// Converts a number to an exact value, approximating floating point
+    /// values with the simplest fraction that rounds to the same float.
+    pub fn to_exact(&self) -> Result<StackItem> {
         match self {
-            StackItem::Number(v, display_base) => Ok(StackItem::Number(
-                radians_to_angle_mode(v.asin(), angle_mode),
-                *display_base,
-            )),
+            StackItem::Number(v, display_base) => {
+                if !v.is_finite() {
+                    return Err(anyhow!("cannot convert {v} to a rational number"));
+                }
+                let r = Ratio::<i64>::approximate_float(*v)
+                    .filter(|r| r.to_f64() == Some(*v))
+                    .map(|r| BigRational::new((*r.numer()).into(), (*r.denom()).into()))
+                    .or_else(|| BigRational::from_float(*v))
+                    .unwrap();
+                Ok(StackItem::from_rational(r, *display_base))
+            }
+            StackItem::Integer(_, _) => Ok(self.clone()),
+            StackItem::Rational(_) => Ok(self.clone()),
+            StackItem::Decimal(v) => Ok(StackItem::from_rational(decimal_to_rational(v), 10)),
+            StackItem::Dms(v) | StackItem::Hms(v) => StackItem::Number(*v, 10).to_exact(),
+            StackItem::Complex(_) => Err(anyhow!("cannot convert a complex number to a rational")),
+            StackItem::Quantity(_, _) => Err(anyhow!("cannot convert a quantity to a rational")),
+            StackItem::DateTime(_)
+            | StackItem::Duration(_)
+            | StackItem::Vector(_)
+            | StackItem::Matrix(_)
+            | StackItem::List(_) => Err(anyhow!("cannot convert {self} to a rational")),
             StackItem::Undefined => Ok(StackItem::Undefined),
             StackItem::String(_) => Ok(StackItem::Undefined),
         }
     }
 
+    pub fn to_float(&self) -> Result<StackItem> {
+        match self.to_f64() {
+            Some(v) => Ok(StackItem::Number(v, self.display_base())),
+            None => Ok(StackItem::Undefined),
+        }
+    }
+
+    pub fn asin(&self, angle_mode: AngleMode) -> Result<StackItem> {
+        reject_units(self, "asin")?;
+        match self {
+            StackItem::Complex(v) => Ok(complex_angle(v.asin(), angle_mode)),
+            _ => match self.to_f64() {
+                Some(v) if v.abs() > 1.0 => {
+                    Ok(complex_angle(Complex64::new(v, 0.0).asin(), angle_mode))
+                }
+                Some(v) => Ok(StackItem::Number(
+                    radians_to_angle_mode(v.asin(), angle_mode),
+                    self.display_base(),
+                )),
+                None => Ok(StackItem::Undefined),
+            },
+        }
+    }
+
     pub fn sin(&self, angle_mode: AngleMode) -> Result<StackItem> {
+        reject_units(self, "sin")?;
         let r = self.to_radians(angle_mode);
         match r {
             StackItem::Number(v, display_base) => Ok(StackItem::Number(v.sin(), display_base)),
-            StackItem::Undefined => Ok(StackItem::Undefined),
-            StackItem::String(_) => Ok(StackItem::Undefined),
+            StackItem::Complex(v) => Ok(StackItem::from_complex(v.sin())),
+            _ => Ok(StackItem::Undefined),
         }
     }
 
     pub fn acos(&self, angle_mode: AngleMode) -> Result<StackItem> {
+        reject_units(self, "acos")?;
         match self {
-            StackItem::Number(v, display_base) => Ok(StackItem::Number(
-                radians_to_angle_mode(v.acos(), angle_mode),
-                *display_base,
-            )),
-            StackItem::Undefined => Ok(StackItem::Undefined),
-            StackItem::String(_) => Ok(StackItem::Undefined),
+            StackItem::Complex(v) => Ok(complex_angle(v.acos(), angle_mode)),
+            _ => match self.to_f64() {
+                Some(v) if v.abs() > 1.0 => {
+                    Ok(complex_angle(Complex64::new(v, 0.0).acos(), angle_mode))
+                }
+                Some(v) => Ok(StackItem::Number(
+                    radians_to_angle_mode(v.acos(), angle_mode),
+                    self.display_base(),
+                )),
+                None => Ok(StackItem::Undefined),
+            },
         }
     }
 
     pub fn cos(&self, angle_mode: AngleMode) -> Result<StackItem> {
+        reject_units(self, "cos")?;
         let r = self.to_radians(angle_mode);
         match r {
             StackItem::Number(v, display_base) => Ok(StackItem::Number(v.cos(), display_base)),
-            StackItem::Undefined => Ok(StackItem::Undefined),
-            StackItem::String(_) => Ok(StackItem::Undefined),
+            StackItem::Complex(v) => Ok(StackItem::from_complex(v.cos())),
+            _ => Ok(StackItem::Undefined),
         }
     }
 
     pub fn atan(&self, angle_mode: AngleMode) -> Result<StackItem> {
+        reject_units(self, "atan")?;
+        match self {
+            StackItem::Complex(v) => Ok(complex_angle(v.atan(), angle_mode)),
+            _ => match self.to_f64() {
+                Some(v) => Ok(StackItem::Number(
+                    radians_to_angle_mode(v.atan(), angle_mode),
+                    self.display_base(),
+                )),
+                None => Ok(StackItem::Undefined),
+            },
+        }
+    }
+
+    pub fn atan2(&self, other: &StackItem, angle_mode: AngleMode) -> Result<StackItem> {
+        reject_units(self, "atan2")?;
+        reject_units(other, "atan2")?;
+        Ok(float_op(self, other, self.display_base(), |v, other_v| {
+            radians_to_angle_mode(v.atan2(other_v), angle_mode)
+        }))
+    }
+
+    pub fn tan(&self, angle_mode: AngleMode) -> Result<StackItem> {
+        reject_units(self, "tan")?;
+        let r = self.to_radians(angle_mode);
+        match r {
+            StackItem::Number(v, display_base) => Ok(StackItem::Number(v.tan(), display_base)),
+            StackItem::Complex(v) => Ok(StackItem::from_complex(v.tan())),
+            _ => Ok(StackItem::Undefined),
+        }
+    }
+
+    /// Converts an angle in the given angle mode to radians. Degrees, minutes and
+    /// seconds values are always in degrees, whatever the angle mode.
+    pub fn to_radians(&self, from_angle_mode: AngleMode) -> StackItem {
+        if let StackItem::Dms(v) = self {
+            return StackItem::Number(angle_mode_to_radians(*v, AngleMode::Degrees), 10);
+        }
+        if let StackItem::Complex(v) = self {
+            return StackItem::Complex(match from_angle_mode {
+                AngleMode::Radians => *v,
+                _ => v.scale(angle_mode_to_radians(1.0, from_angle_mode)),
+            });
+        }
+        match self.to_f64() {
+            Some(v) => StackItem::Number(
+                angle_mode_to_radians(v, from_angle_mode),
+                self.display_base(),
+            ),
+            None => StackItem::Undefined,
+        }
+    }
+
+    /// Returns sqrt(a^2 + b^2). Quantities must have the same dimensions, and the
+    /// result is in the unit of the first.
+    pub fn hypot(&self, other: &StackItem) -> Result<StackItem> {
+        if !has_unit(self, other) {
+            return Ok(float_op(self, other, 10, f64::hypot));
+        }
+        let (Some((a, a_unit)), Some((b, b_unit))) = (quantity_parts(self), quantity_parts(other))
+        else {
+            return Err(anyhow!("cannot hypot {self} and {other}"));
+        };
+        if a_unit.dimensions() != b_unit.dimensions() {
+            return Err(anyhow!(
+                "incompatible units for hypot, dimensions {} and {} differ",
+                a_unit.dimensions_string(),
+                b_unit.dimensions_string()
+            ));
+        }
+        Ok(StackItem::from_quantity(
+            a.hypot(b * b_unit.factor() / a_unit.factor()),
+            a_unit,
+        ))
+    }
+
+    pub fn sinh(&self) -> Result<StackItem> {
+        real_or_complex_op(self, |_| true, f64::sinh, |v| v.sinh())
+    }
+
+    pub fn cosh(&self) -> Result<StackItem> {
+        real_or_complex_op(self, |_| true, f64::cosh, |v| v.cosh())
+    }
+
+    pub fn tanh(&self) -> Result<StackItem> {
+        real_or_complex_op(self, |_| true, f64::tanh, |v| v.tanh())
+    }
+
+    pub fn asinh(&self) -> Result<StackItem> {
+        real_or_complex_op(self, |_| true, f64::asinh, |v| v.asinh())
+    }
+
+    /// Inverse hyperbolic cosine, complex for values below one.
+    pub fn acosh(&self) -> Result<StackItem> {
+        real_or_complex_op(self, |v| v >= 1.0, f64::acosh, |v| v.acosh())
+    }
+
+    /// Inverse hyperbolic tangent, complex outside (-1, 1) and undefined at -1 and 1.
+    pub fn atanh(&self) -> Result<StackItem> {
+        if self.to_f64().is_some_and(|v| v.abs() == 1.0) {
+            return Ok(StackItem::Undefined);
+        }
+        real_or_complex_op(self, |v| v.abs() < 1.0, f64::atanh, |v| v.atanh())
+    }
+
+    /// Rounds to `places` digits after the decimal point, or to tens, hundreds and so on
+    /// when `places` is negative. Complex values round each part and arrays each element.
+    pub fn round_places(&self, places: i64, rounding: Rounding) -> Result<StackItem> {
+        let round_f64 = |v: f64| {
+            if places == 0 {
+                return rounding.apply_f64(v);
+            }
+            let scale = 10f64.powi(places as i32);
+            let scaled = v * scale;
+            if scaled.is_finite() {
+                rounding.apply_f64(scaled) / scale
+            } else {
+                v
+            }
+        };
         match self {
-            StackItem::Number(v, display_base) => Ok(StackItem::Number(
-                radians_to_angle_mode(v.atan(), angle_mode),
-                *display_base,
+            StackItem::Number(v, display_base) => {
+                Ok(StackItem::Number(round_f64(*v), *display_base))
+            }
+            StackItem::Integer(_, _) if places >= 0 => Ok(self.clone()),
+            StackItem::Integer(_, _) | StackItem::Rational(_) => {
+                let v = self.to_rational().unwrap();
+                let scale =
+                    BigRational::from_integer(BigInt::from(10).pow(places.unsigned_abs() as u32));
+                let v = if places >= 0 {
+                    rounding.apply_rational(&(v * &scale)) / scale
+                } else {
+                    rounding.apply_rational(&(v / &scale)) * scale
+                };
+                let display_base = match self {
+                    StackItem::Integer(_, display_base) => *display_base,
+                    _ => 10,
+                };
+                Ok(StackItem::from_rational(v, display_base))
+            }
+            StackItem::Decimal(v) => Ok(StackItem::Decimal(
+                v.with_scale_round(places, rounding.decimal_mode()),
+            )),
+            StackItem::Complex(v) => Ok(StackItem::from_complex(Complex64::new(
+                round_f64(v.re),
+                round_f64(v.im),
+            ))),
+            StackItem::Quantity(v, unit) => Ok(StackItem::Quantity(round_f64(*v), unit.clone())),
+            StackItem::Dms(v) => Ok(StackItem::Dms(round_f64(*v))),
+            StackItem::Hms(v) => Ok(StackItem::Hms(round_f64(*v))),
+            StackItem::Vector(_) | StackItem::Matrix(_) => {
+                matrix::map(self, |v| v.round_places(places, rounding))
+            }
+            StackItem::List(items) => Ok(StackItem::List(
+                items
+                    .iter()
+                    .map(|v| v.round_places(places, rounding))
+                    .collect::<Result<_>>()?,
             )),
+            StackItem::DateTime(_) | StackItem::Duration(_) => Err(anyhow!("cannot round {self}")),
             StackItem::Undefined => Ok(StackItem::Undefined),
             StackItem::String(_) => Ok(StackItem::Undefined),
         }
     }
 
-    pub fn atan2(&self, other: &StackItem, angle_mode: AngleMode) -> Result<StackItem> {
+    /// Absolute value, the magnitude for complex numbers and each element's for arrays.
+    pub fn abs(&self) -> Result<StackItem> {
         match self {
-            StackItem::Number(v, display_base) => match other {
-                StackItem::Number(other_v, _) => Ok(StackItem::Number(
-                    radians_to_angle_mode(v.atan2(*other_v), angle_mode),
-                    *display_base,
-                )),
-                StackItem::String(_) => Ok(StackItem::Undefined),
-                StackItem::Undefined => Ok(StackItem::Undefined),
-            },
+            StackItem::Number(v, display_base) => Ok(StackItem::Number(v.abs(), *display_base)),
+            StackItem::Integer(v, display_base) => Ok(StackItem::Integer(v.abs(), *display_base)),
+            StackItem::Rational(v) => Ok(StackItem::Rational(v.abs())),
+            StackItem::Complex(v) => Ok(StackItem::Number(v.norm(), 10)),
+            StackItem::Decimal(v) => Ok(StackItem::Decimal(v.abs())),
+            StackItem::Quantity(v, unit) => Ok(StackItem::Quantity(v.abs(), unit.clone())),
+            StackItem::Dms(v) => Ok(StackItem::Dms(v.abs())),
+            StackItem::Hms(v) => Ok(StackItem::Hms(v.abs())),
+            StackItem::Vector(_) | StackItem::Matrix(_) => matrix::map(self, |v| v.abs()),
+            StackItem::List(items) => Ok(StackItem::List(
+                items.iter().map(|v| v.abs()).collect::<Result<_>>()?,
+            )),
+            StackItem::DateTime(_) | StackItem::Duration(_) => {
+                Err(anyhow!("cannot take the absolute value of {self}"))
+            }
             StackItem::Undefined => Ok(StackItem::Undefined),
             StackItem::String(_) => Ok(StackItem::Undefined),
         }
     }
 
-    pub fn tan(&self, angle_mode: AngleMode) -> Result<StackItem> {
-        let r = self.to_radians(angle_mode);
-        match r {
-            StackItem::Number(v, display_base) => Ok(StackItem::Number(v.tan(), display_base)),
+    /// Returns -1, 0 or 1 for real values and the unit vector z / |z| for complex numbers.
+    pub fn signum(&self) -> Result<StackItem> {
+        let sign = |v: f64| {
+            if v == 0.0 {
+                0
+            } else {
+                v.signum() as i32
+            }
+        };
+        match self {
+            StackItem::Number(v, display_base) => {
+                Ok(StackItem::Integer(sign(*v).into(), *display_base))
+            }
+            StackItem::Integer(v, display_base) => {
+                Ok(StackItem::Integer(v.signum(), *display_base))
+            }
+            StackItem::Rational(_) | StackItem::Decimal(_) => Ok(StackItem::Integer(
+                self.to_rational().unwrap().numer().signum(),
+                10,
+            )),
+            StackItem::Complex(v) => Ok(StackItem::Complex(v / v.norm())),
+            StackItem::Quantity(v, _) | StackItem::Dms(v) | StackItem::Hms(v) => {
+                Ok(StackItem::Integer(sign(*v).into(), 10))
+            }
+            StackItem::Vector(_) | StackItem::Matrix(_) => matrix::map(self, |v| v.signum()),
+            StackItem::List(items) => Ok(StackItem::List(
+                items.iter().map(|v| v.signum()).collect::<Result<_>>()?,
+            )),
+            StackItem::DateTime(_) | StackItem::Duration(_) => {
+                Err(anyhow!("cannot take the sign of {self}"))
+            }
             StackItem::Undefined => Ok(StackItem::Undefined),
             StackItem::String(_) => Ok(StackItem::Undefined),
         }
     }
 
-    pub fn to_radians(&self, from_angle_mode: AngleMode) -> StackItem {
+    /// Orders two real numbers, exactly when both have exact values, or two strings.
+    pub fn compare(&self, other: &StackItem) -> Option<Ordering> {
+        if let (Some(a), Some(b)) = (self.to_rational(), other.to_rational()) {
+            return Some(a.cmp(&b));
+        }
+        match (self, other) {
+            (StackItem::String(a), StackItem::String(b)) => Some(a.cmp(b)),
+            _ => self.to_f64()?.partial_cmp(&other.to_f64()?),
+        }
+    }
+
+    /// Creates a complex number from a magnitude (self) and an angle in the given angle mode.
+    pub fn polar(&self, angle: &StackItem, angle_mode: AngleMode) -> Result<StackItem> {
+        match (self.to_f64(), angle.to_radians(angle_mode).to_f64()) {
+            (Some(r), Some(theta)) => Ok(StackItem::from_complex(Complex64::from_polar(r, theta))),
+            _ => Err(anyhow!("polar form requires a real magnitude and angle")),
+        }
+    }
+
+    pub fn real_part(&self) -> Result<StackItem> {
         match self {
-            StackItem::Number(v, display_base) => match from_angle_mode {
-                AngleMode::Degrees => StackItem::Number(degrees_to_radians(*v), *display_base),
-                AngleMode::Radians => StackItem::Number(*v, *display_base),
-            },
-            StackItem::Undefined => StackItem::Undefined,
-            StackItem::String(_) => StackItem::Undefined,
+            StackItem::Complex(v) => Ok(StackItem::Number(v.re, 10)),
+            StackItem::String(_) => Ok(StackItem::Undefined),
+            _ => Ok(self.clone()),
+        }
+    }
+
+    pub fn imaginary_part(&self) -> Result<StackItem> {
+        match self {
+            StackItem::Complex(v) => Ok(StackItem::Number(v.im, 10)),
+            StackItem::String(_) => Ok(StackItem::Undefined),
+            StackItem::Undefined => Ok(StackItem::Undefined),
+            _ => Ok(StackItem::Integer(BigInt::zero(), self.display_base())),
+        }
+    }
+
+    pub fn arg(&self, angle_mode: AngleMode) -> Result<StackItem> {
+        match self.to_complex() {
+            Some(v) => Ok(StackItem::Number(
+                radians_to_angle_mode(v.arg(), angle_mode),
+                10,
+            )),
+            None => Ok(StackItem::Undefined),
+        }
+    }
+
+    pub fn conj(&self) -> Result<StackItem> {
+        match self {
+            StackItem::Complex(v) => Ok(StackItem::Complex(v.conj())),
+            StackItem::String(_) => Ok(StackItem::Undefined),
+            _ => Ok(self.clone()),
         }
     }
 
     pub fn is_integer(&self) -> bool {
         match self {
             StackItem::Number(v, _) => is_integer(*v),
+            StackItem::Integer(_, _) => true,
+            StackItem::Rational(_) => false,
+            StackItem::Complex(_) => false,
+            StackItem::Decimal(v) => v.is_integer(),
+            StackItem::Quantity(_, _) => false,
+            StackItem::DateTime(_) => false,
+            StackItem::Duration(_) => false,
+            StackItem::Dms(_) => false,
+            StackItem::Hms(_) => false,
+            StackItem::Vector(_) => false,
+            StackItem::Matrix(_) => false,
+            StackItem::List(_) => false,
             StackItem::Undefined => false,
             StackItem::String(_) => false,
         }
     }
 
+    pub fn is_zero(&self) -> bool {
+        match self {
+            StackItem::Number(v, _) => *v == 0.0,
+            StackItem::Integer(v, _) => v.is_zero(),
+            StackItem::Rational(v) => v.is_zero(),
+            StackItem::Complex(v) => v.is_zero(),
+            StackItem::Decimal(v) => v.is_zero(),
+            StackItem::Quantity(v, _) => *v == 0.0,
+            StackItem::DateTime(_) => false,
+            StackItem::Duration(v) => v.is_zero(),
+            StackItem::Dms(v) => *v == 0.0,
+            StackItem::Hms(v) => *v == 0.0,
+            StackItem::Vector(_) => false,
+            StackItem::Matrix(_) => false,
+            StackItem::List(_) => false,
+            StackItem::Undefined => false,
+            StackItem::String(_) => false,
+        }
+    }
+
+    /// Returns the value as a floating point number, or `None` if this item is not numeric.
+    pub fn to_f64(&self) -> Option<f64> {
+        match self {
+            StackItem::Number(v, _) => Some(*v),
+            StackItem::Integer(v, _) => v.to_f64(),
+            StackItem::Rational(v) => v.to_f64(),
+            StackItem::Decimal(v) => v.to_f64(),
+            StackItem::Complex(_) => None,
+            StackItem::Quantity(_, _) => None,
+            StackItem::DateTime(_) => None,
+            StackItem::Duration(_) => None,
+            StackItem::Dms(v) => Some(*v),
+            StackItem::Hms(v) => Some(*v),
+            StackItem::Vector(_) => None,
+            StackItem::Matrix(_) => None,
+            StackItem::List(_) => None,
+            StackItem::Undefined => None,
+            StackItem::String(_) => None,
+        }
+    }
+
+    /// Returns the value as a complex number, or `None` if this item is not numeric.
+    pub fn to_complex(&self) -> Option<Complex64> {
+        match self {
+            StackItem::Complex(v) => Some(*v),
+            _ => self.to_f64().map(|v| Complex64::new(v, 0.0)),
+        }
+    }
+
+    /// Creates an item from a complex number, collapsing values with no imaginary part to reals.
+    pub fn from_complex(v: Complex64) -> StackItem {
+        if v.im == 0.0 {
+            StackItem::Number(v.re, 10)
+        } else {
+            StackItem::Complex(v)
+        }
+    }
+
+    /// Returns the exact value of integer, rational and decimal items, or `None` for anything else.
+    pub fn to_rational(&self) -> Option<BigRational> {
+        match self {
+            StackItem::Integer(v, _) => Some(BigRational::from_integer(v.clone())),
+            StackItem::Rational(v) => Some(v.clone()),
+            StackItem::Decimal(v) => Some(decimal_to_rational(v)),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of exact and decimal items rounded to `precision` significant digits,
+    /// or `None` for anything else.
+    pub fn to_decimal(&self, precision: usize) -> Option<BigDecimal> {
+        let ctx = decimal_context(precision);
+        match self {
+            // rounding pads to the precision, normalizing keeps only the digits the value needs
+            StackItem::Integer(v, _) => {
+                Some(ctx.round_decimal(BigDecimal::from(v.clone())).normalized())
+            }
+            StackItem::Rational(v) => {
+                let guard_ctx = decimal_context(precision + DECIMAL_GUARD_DIGITS);
+                let numer = BigDecimal::from(v.numer().clone());
+                let denom = BigDecimal::from(v.denom().clone());
+                let v = guard_ctx.multiply(&numer, &guard_ctx.invert(&denom));
+                Some(ctx.round_decimal(v).normalized())
+            }
+            StackItem::Decimal(v) => Some(ctx.round_decimal_ref(v).normalized()),
+            _ => None,
+        }
+    }
+
+    /// Creates an exact item from a rational, collapsing whole numbers to integers.
+    pub fn from_rational(v: BigRational, display_base: u8) -> StackItem {
+        if v.is_integer() {
+            StackItem::Integer(v.to_integer(), display_base)
+        } else {
+            StackItem::Rational(v)
+        }
+    }
+
+    /// Creates an item from a value in a unit, collapsing dimensionless units to plain numbers.
+    pub fn from_quantity(v: f64, unit: Unit) -> StackItem {
+        if unit.is_dimensionless() {
+            StackItem::Number(v * unit.factor(), 10)
+        } else {
+            StackItem::Quantity(v, unit)
+        }
+    }
+
+    /// Converts a value to the unit of `target`, which is either a quantity or a unit string.
+    pub fn convert(&self, target: &StackItem) -> Result<StackItem> {
+        let to_unit = match target {
+            StackItem::String(s) => Unit::parse(s)?,
+            StackItem::Quantity(_, unit) => unit.clone(),
+            _ => {
+                return Err(anyhow!(
+                    "convert expects a unit such as 'ft' but found {target}"
+                ))
+            }
+        };
+        let Some((v, from_unit)) = quantity_parts(self) else {
+            return Err(anyhow!("cannot convert {self} to {to_unit}"));
+        };
+        if from_unit.dimensions() != to_unit.dimensions() {
+            return Err(anyhow!(
+                "cannot convert {from_unit} to {to_unit}, dimensions {} and {} differ",
+                from_unit.dimensions_string(),
+                to_unit.dimensions_string()
+            ));
+        }
+        Ok(StackItem::from_quantity(
+            v * from_unit.factor() / to_unit.factor(),
+            to_unit,
+        ))
+    }
+
+    pub fn display_base(&self) -> u8 {
+        match self {
+            StackItem::Number(_, display_base) => *display_base,
+            StackItem::Integer(_, display_base) => *display_base,
+            StackItem::Rational(_) => 10,
+            StackItem::Complex(_) => 10,
+            StackItem::Decimal(_) => 10,
+            StackItem::Quantity(_, _) => 10,
+            StackItem::DateTime(_) => 10,
+            StackItem::Duration(_) => 10,
+            StackItem::Dms(_) => 10,
+            StackItem::Hms(_) => 10,
+            StackItem::Vector(_) => 10,
+            StackItem::Matrix(_) => 10,
+            StackItem::List(_) => 10,
+            StackItem::Undefined => 10,
+            StackItem::String(_) => 10,
+        }
+    }
+
     pub fn to_string_opts(&self, opts: &StackItemToStringOpts, state: &RpnState) -> String {
         match self {
             StackItem::Number(n, display_base) => {
                 let base = opts.base.unwrap_or(*display_base);
 
-                if is_integer(*n) {
-                    if base == 2 {
-                        return to_string_binary(*n, opts);
-                    } else if base == 8 {
-                        return to_string_octal(*n, opts);
-                    } else if base == 16 {
-                        return to_string_hex(*n, opts);
-                    }
+                if is_integer(*n) && base != 10 {
+                    return to_string_radix(&BigInt::from(*n as i128), base, opts);
                 }
                 to_string_opts_base10(*n, opts, state)
             }
+            StackItem::Integer(n, display_base) => {
+                let base = opts.base.unwrap_or(*display_base);
+
+                if base != 10 {
+                    // negative values are shown as their two's complement in programmer mode
+                    if let Some(n) = state.integer_mode.unsigned_value(n) {
+                        return to_string_radix(&n, base, opts);
+                    }
+                    return to_string_radix(n, base, opts);
+                }
+                to_string_integer_base10(n, state)
+            }
+            StackItem::Rational(n) => {
+                match opts.rational_display.unwrap_or(state.rational_display) {
+                    RationalDisplay::Fraction => format!(
+                        "{}/{}",
+                        to_string_integer_base10(n.numer(), state),
+                        to_string_integer_base10(n.denom(), state)
+                    ),
+                    RationalDisplay::Decimal => {
+                        to_string_opts_base10(n.to_f64().unwrap_or(f64::NAN), opts, state)
+                    }
+                }
+            }
+            StackItem::Complex(n) => match state.complex_display {
+                ComplexDisplay::Rectangular => {
+                    let re = to_string_opts_base10(n.re, opts, state);
+                    let im = to_string_opts_base10(n.im.abs(), opts, state);
+                    let sign = if n.im.is_sign_negative() { "-" } else { "+" };
+                    format!("{re}{sign}{im}i")
+                }
+                ComplexDisplay::Polar => {
+                    let r = to_string_opts_base10(n.norm(), opts, state);
+                    let theta = radians_to_angle_mode(n.arg(), state.angle_mode);
+                    let theta = to_string_opts_base10(theta, opts, state);
+                    format!("{r}∠{theta}")
+                }
+            },
+            StackItem::Decimal(n) => to_string_decimal(n, state),
+            StackItem::Quantity(n, unit) => {
+                format!("{} {unit}", to_string_opts_base10(*n, opts, state))
+            }
+            StackItem::DateTime(v) => format_date_time(v),
+            StackItem::Duration(v) => v.to_string(),
+            StackItem::Dms(v) => format_dms(*v),
+            StackItem::Hms(v) => format_hms(*v),
+            StackItem::Vector(v) => format!(
+                "[{}]",
+                v.iter()
+                    .map(|v| v.to_string_opts(opts, state))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            StackItem::Matrix(m) => to_string_matrix(m, opts, state),
+            StackItem::List(v) => format!(
+                "{{{}}}",
+                v.iter()
+                    .map(|v| v.to_string_opts(opts, state))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
             StackItem::Undefined => "Undefined".to_string(),
             StackItem::String(s) => format!("'{s}'"),
         }
@@ -253,24 +1033,55 @@ impl StackItem {
 
 impl Display for StackItem {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let opts = StackItemToStringOpts {
+            base: None,
+            precision: None,
+            rational_display: None,
+            left_pad_with_zeros: true,
+            include_base_prefix: true,
+        };
         match self {
             StackItem::Number(value, display_base) => {
-                let opts = StackItemToStringOpts {
-                    base: None,
-                    precision: None,
-                    left_pad_with_zeros: true,
-                    include_base_prefix: true,
-                };
-                if *display_base == 2 {
-                    write!(f, "{}", to_string_binary(*value, &opts))
-                } else if *display_base == 8 {
-                    write!(f, "{}", to_string_octal(*value, &opts))
-                } else if *display_base == 16 {
-                    write!(f, "{}", to_string_hex(*value, &opts))
+                if *display_base != 10 && is_integer(*value) {
+                    let n = BigInt::from(*value as i128);
+                    write!(f, "{}", to_string_radix(&n, *display_base, &opts))
                 } else {
                     write!(f, "{}", value)
                 }
             }
+            StackItem::Integer(value, display_base) => {
+                if *display_base != 10 {
+                    write!(f, "{}", to_string_radix(value, *display_base, &opts))
+                } else {
+                    write!(f, "{}", value)
+                }
+            }
+            StackItem::Rational(value) => write!(f, "{}/{}", value.numer(), value.denom()),
+            StackItem::Complex(value) => {
+                let sign = if value.im.is_sign_negative() {
+                    "-"
+                } else {
+                    "+"
+                };
+                write!(f, "{}{sign}{}i", value.re, value.im.abs())
+            }
+            StackItem::Decimal(value) => write!(f, "{}", value.to_plain_string()),
+            StackItem::Quantity(value, unit) => write!(f, "{value} {unit}"),
+            StackItem::DateTime(value) => write!(f, "{}", format_date_time(value)),
+            StackItem::Duration(value) => write!(f, "{value}"),
+            StackItem::Dms(value) => write!(f, "{}", format_dms(*value)),
+            StackItem::Hms(value) => write!(f, "{}", format_hms(*value)),
+            StackItem::Vector(value) => write!(f, "[{}]", join_items(value)),
+            StackItem::Matrix(value) => write!(
+                f,
+                "[{}]",
+                value
+                    .iter()
+                    .map(|row| format!("[{}]", join_items(row)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            StackItem::List(value) => write!(f, "{{{}}}", join_items(value)),
             StackItem::Undefined => write!(f, "Undefined"),
             StackItem::String(s) => write!(f, "'{s}'"),
         }
@@ -279,24 +1090,31 @@ impl Display for StackItem {
 
 impl PartialEq for StackItem {
     fn eq(&self, other: &Self) -> bool {
-        match self {
-            StackItem::Number(value, display_base) => match other {
-                StackItem::Number(other_value, other_display_base) => {
-                    value == other_value && display_base == other_display_base
-                }
-                StackItem::Undefined => false,
-                StackItem::String(_) => false,
-            },
-            StackItem::Undefined => match other {
-                StackItem::Number(_, _) => false,
-                StackItem::Undefined => true,
-                StackItem::String(_) => false,
-            },
-            StackItem::String(s) => match other {
-                StackItem::Number(_, _) => false,
-                StackItem::Undefined => false,
-                StackItem::String(other_s) => s == other_s,
-            },
+        match (self, other) {
+            (
+                StackItem::Number(value, display_base),
+                StackItem::Number(other_value, other_display_base),
+            ) => value == other_value && display_base == other_display_base,
+            (
+                StackItem::Integer(value, display_base),
+                StackItem::Integer(other_value, other_display_base),
+            ) => value == other_value && display_base == other_display_base,
+            (StackItem::Rational(value), StackItem::Rational(other_value)) => value == other_value,
+            (StackItem::Complex(value), StackItem::Complex(other_value)) => value == other_value,
+            (StackItem::Decimal(value), StackItem::Decimal(other_value)) => value == other_value,
+            (StackItem::Quantity(value, unit), StackItem::Quantity(other_value, other_unit)) => {
+                value == other_value && unit == other_unit
+            }
+            (StackItem::DateTime(value), StackItem::DateTime(other_value)) => value == other_value,
+            (StackItem::Duration(value), StackItem::Duration(other_value)) => value == other_value,
+            (StackItem::Dms(value), StackItem::Dms(other_value)) => value == other_value,
+            (StackItem::Hms(value), StackItem::Hms(other_value)) => value == other_value,
+            (StackItem::Vector(value), StackItem::Vector(other_value)) => value == other_value,
+            (StackItem::Matrix(value), StackItem::Matrix(other_value)) => value == other_value,
+            (StackItem::List(value), StackItem::List(other_value)) => value == other_value,
+            (StackItem::String(s), StackItem::String(other_s)) => s == other_s,
+            (StackItem::Undefined, StackItem::Undefined) => true,
+            _ => false,
         }
     }
 }
@@ -304,50 +1122,308 @@ impl PartialEq for StackItem {
 pub struct StackItemToStringOpts {
     pub base: Option<u8>,
     pub precision: Option<usize>,
+    pub rational_display: Option<RationalDisplay>,
     pub left_pad_with_zeros: bool,
     pub include_base_prefix: bool,
 }
 
-fn to_string_binary(n: f64, opts: &StackItemToStringOpts) -> String {
-    if !is_integer(n) {
-        return format!("{}", n);
+/// Applies an exact operation to two integer or rational items, producing
+/// `None` when either operand has no exact value.
+fn exact_op<F>(a: &StackItem, b: &StackItem, display_base: u8, op: F) -> Option<StackItem>
+where
+    F: FnOnce(BigRational, BigRational) -> BigRational,
+{
+    match (a.to_rational(), b.to_rational()) {
+        (Some(a), Some(b)) => Some(StackItem::from_rational(op(a, b), display_base)),
+        _ => None,
     }
-    let whole_part = n as i128;
-    let abs_whole_part = whole_part.abs();
-    let sign = if n < 0.0 { "-" } else { "" };
-    let base_prefix = if opts.include_base_prefix { "0b" } else { "" };
-    format!(
-        "{sign}{base_prefix}{}",
-        group_digits(format!("{:b}", abs_whole_part), 4, opts.left_pad_with_zeros)
-    )
 }
 
-fn to_string_octal(n: f64, opts: &StackItemToStringOpts) -> String {
-    if !is_integer(n) {
-        return format!("{}", n);
+/// Applies a complex operation when either operand is complex, producing
+/// `None` when neither is complex or either operand is not a number.
+fn complex_op<F>(a: &StackItem, b: &StackItem, op: F) -> Option<StackItem>
+where
+    F: FnOnce(Complex64, Complex64) -> Complex64,
+{
+    if !matches!(a, StackItem::Complex(_)) && !matches!(b, StackItem::Complex(_)) {
+        return None;
     }
-    let whole_part = n as i128;
-    let abs_whole_part = whole_part.abs();
-    let sign = if n < 0.0 { "-" } else { "" };
-    let base_prefix = if opts.include_base_prefix { "0o" } else { "" };
-    format!(
-        "{sign}{base_prefix}{}",
-        group_digits(format!("{:o}", abs_whole_part), 4, opts.left_pad_with_zeros)
-    )
+    match (a.to_complex(), b.to_complex()) {
+        (Some(a), Some(b)) => Some(StackItem::from_complex(op(a, b))),
+        _ => None,
+    }
+}
+
+fn has_date_time(a: &StackItem, b: &StackItem) -> bool {
+    matches!(a, StackItem::DateTime(_) | StackItem::Duration(_))
+        || matches!(b, StackItem::DateTime(_) | StackItem::Duration(_))
 }
 
-fn to_string_hex(n: f64, opts: &StackItemToStringOpts) -> String {
-    if !is_integer(n) {
-        return format!("{}", n);
+fn date_time_overflow() -> anyhow::Error {
+    anyhow!("date is out of range")
+}
+
+/// Hours, minutes and seconds values such as `14:30:15` act as durations next to
+/// dates and durations.
+fn hms_as_duration(item: &StackItem) -> Result<StackItem> {
+    match item {
+        StackItem::Hms(hours) => TimeDelta::try_milliseconds((hours * 3_600_000.0).round() as i64)
+            .filter(|_| hours.is_finite())
+            .map(|delta| StackItem::Duration(CalendarDuration::new(0, delta)))
+            .ok_or_else(date_time_overflow),
+        _ => Ok(item.clone()),
+    }
+}
+
+fn date_time_add(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    let (a, b) = (&hms_as_duration(a)?, &hms_as_duration(b)?);
+    match (a, b) {
+        (StackItem::DateTime(date_time), StackItem::Duration(duration))
+        | (StackItem::Duration(duration), StackItem::DateTime(date_time)) => duration
+            .add_to(date_time)
+            .map(StackItem::DateTime)
+            .ok_or_else(date_time_overflow),
+        (StackItem::Duration(a), StackItem::Duration(b)) => a
+            .checked_add(b)
+            .map(StackItem::Duration)
+            .ok_or_else(date_time_overflow),
+        _ => Err(anyhow!("cannot add {a} and {b}")),
+    }
+}
+
+fn date_time_subtract(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    let (a, b) = (&hms_as_duration(a)?, &hms_as_duration(b)?);
+    match (a, b) {
+        (StackItem::DateTime(date_time), StackItem::Duration(duration)) => duration
+            .negate()
+            .add_to(date_time)
+            .map(StackItem::DateTime)
+            .ok_or_else(date_time_overflow),
+        (StackItem::DateTime(a), StackItem::DateTime(b)) => Ok(StackItem::Duration(
+            CalendarDuration::new(0, a.signed_duration_since(*b)),
+        )),
+        (StackItem::Duration(a), StackItem::Duration(b)) => a
+            .checked_add(&b.negate())
+            .map(StackItem::Duration)
+            .ok_or_else(date_time_overflow),
+        _ => Err(anyhow!("cannot subtract {b} from {a}")),
+    }
+}
+
+fn date_time_multiply(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    match (a, b) {
+        (StackItem::Duration(duration), n) | (n, StackItem::Duration(duration)) => {
+            match n.to_f64() {
+                Some(n) => Ok(StackItem::Duration(duration.scale(n)?)),
+                None => Err(anyhow!("cannot multiply {a} and {b}")),
+            }
+        }
+        _ => Err(anyhow!("cannot multiply {a} and {b}")),
+    }
+}
+
+fn date_time_divide(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    match (a, b) {
+        (StackItem::Duration(a), StackItem::Duration(b)) => Ok(StackItem::Number(a.ratio(b)?, 10)),
+        (StackItem::Duration(duration), n) => match n.to_f64() {
+            Some(n) => Ok(StackItem::Duration(duration.scale(1.0 / n)?)),
+            None => Err(anyhow!("cannot divide {a} by {b}")),
+        },
+        _ => Err(anyhow!("cannot divide {a} by {b}")),
+    }
+}
+
+fn has_unit(a: &StackItem, b: &StackItem) -> bool {
+    matches!(a, StackItem::Quantity(_, _)) || matches!(b, StackItem::Quantity(_, _))
+}
+
+/// Rejects quantities passed to functions that only take dimensionless values.
+fn reject_units(a: &StackItem, op_name: &str) -> Result<()> {
+    match a {
+        StackItem::Quantity(_, _) => Err(anyhow!(
+            "incompatible units for {op_name}, expected a dimensionless value but found {a}"
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Splits a numeric item into a value and unit, plain numbers having no unit.
+fn quantity_parts(item: &StackItem) -> Option<(f64, Unit)> {
+    match item {
+        StackItem::Quantity(v, unit) => Some((*v, unit.clone())),
+        _ => item.to_f64().map(|v| (v, Unit::none())),
+    }
+}
+
+/// Applies an operation whose operands must have the same dimensions, converting the
+/// second operand to the unit of the first.
+fn quantity_additive_op<F>(a: &StackItem, b: &StackItem, op_name: &str, op: F) -> Result<StackItem>
+where
+    F: FnOnce(f64, f64) -> f64,
+{
+    let (Some((a, a_unit)), Some((b, b_unit))) = (quantity_parts(a), quantity_parts(b)) else {
+        return Err(anyhow!("cannot {op_name} {a} and {b}"));
+    };
+    if a_unit.dimensions() != b_unit.dimensions() {
+        return Err(anyhow!(
+            "cannot {op_name} {a} {a_unit} and {b} {b_unit}, dimensions {} and {} differ",
+            a_unit.dimensions_string(),
+            b_unit.dimensions_string()
+        ));
+    }
+    Ok(StackItem::from_quantity(
+        op(a, b * b_unit.factor() / a_unit.factor()),
+        a_unit,
+    ))
+}
+
+/// Applies an operation that combines the units of its operands, producing
+/// `Undefined` when either operand is not a number.
+fn quantity_op<F, U>(a: &StackItem, b: &StackItem, op: F, unit_op: U) -> StackItem
+where
+    F: FnOnce(f64, f64) -> f64,
+    U: FnOnce(&Unit, &Unit) -> Unit,
+{
+    match (quantity_parts(a), quantity_parts(b)) {
+        (Some((a, a_unit)), Some((b, b_unit))) => {
+            StackItem::from_quantity(op(a, b), unit_op(&a_unit, &b_unit))
+        }
+        _ => StackItem::Undefined,
+    }
+}
+
+/// Converts the result of a complex inverse trig function from radians to the angle mode.
+/// Applies `real` to real values inside `real_domain` and `complex` to everything else,
+/// the way `sqrt` gives complex results for negative values.
+fn real_or_complex_op<R, D, C>(
+    a: &StackItem,
+    real_domain: D,
+    real: R,
+    complex: C,
+) -> Result<StackItem>
+where
+    D: FnOnce(f64) -> bool,
+    R: FnOnce(f64) -> f64,
+    C: FnOnce(Complex64) -> Complex64,
+{
+    match a {
+        StackItem::Complex(v) => Ok(StackItem::from_complex(complex(*v))),
+        StackItem::Undefined | StackItem::String(_) => Ok(StackItem::Undefined),
+        _ => match a.to_f64() {
+            Some(v) if real_domain(v) => Ok(StackItem::Number(real(v), 10)),
+            Some(v) => Ok(StackItem::from_complex(complex(Complex64::new(v, 0.0)))),
+            None => Err(anyhow!("expected a number but found {a}")),
+        },
+    }
+}
+
+fn complex_angle(v: Complex64, angle_mode: AngleMode) -> StackItem {
+    StackItem::from_complex(v.scale(radians_to_angle_mode(1.0, angle_mode)))
+}
+
+/// Applies a floating point operation to two numeric items, producing
+/// `Undefined` when either operand is not a number.
+fn float_op<F>(a: &StackItem, b: &StackItem, display_base: u8, op: F) -> StackItem
+where
+    F: FnOnce(f64, f64) -> f64,
+{
+    match (a.to_f64(), b.to_f64()) {
+        (Some(a), Some(b)) => StackItem::Number(op(a, b), display_base),
+        _ => StackItem::Undefined,
+    }
+}
+
+fn to_string_radix(n: &BigInt, base: u8, opts: &StackItemToStringOpts) -> String {
+    let sign = if n.is_negative() { "-" } else { "" };
+    let base_prefix = if !opts.include_base_prefix {
+        "".to_string()
+    } else if base == 2 {
+        "0b".to_string()
+    } else if base == 8 {
+        "0o".to_string()
+    } else if base == 16 {
+        "0x".to_string()
+    } else {
+        // any other radix is written like the 36r1z literal
+        format!("{base}r")
+    };
+    let digits = n.magnitude().to_str_radix(base as u32);
+    // groups of digits only line up with bits in the power of two radixes
+    let digits = if matches!(base, 2 | 8 | 16) {
+        group_digits(digits, 4, opts.left_pad_with_zeros)
+    } else {
+        digits
+    };
+    format!("{sign}{base_prefix}{digits}")
+}
+
+/// Formats a matrix one row per line with the columns right aligned.
+fn to_string_matrix(
+    m: &[Vec<StackItem>],
+    opts: &StackItemToStringOpts,
+    state: &RpnState,
+) -> String {
+    let cells = m
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|v| v.to_string_opts(opts, state))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let widths = (0..cells[0].len())
+        .map(|col| {
+            cells
+                .iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    cells
+        .iter()
+        .map(|row| {
+            let row = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:>width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            format!("[ {row} ]")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn join_items(items: &[StackItem]) -> String {
+    items
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn to_string_integer_base10(n: &BigInt, state: &RpnState) -> String {
+    let sign = if n.is_negative() { "-" } else { "" };
+    if let Some(v) = n.magnitude().to_u128() {
+        return format!("{sign}{}", v.to_formatted_string(&state.locale));
+    }
+    let digits = group_digits(n.magnitude().to_string(), 3, false);
+    format!("{sign}{}", digits.replace(' ', state.locale.separator()))
+}
+
+fn to_string_decimal(n: &BigDecimal, state: &RpnState) -> String {
+    let s = n.abs().to_plain_string();
+    let (whole, fraction) = match s.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (s.as_str(), None),
+    };
+    let sign = if n.is_negative() { "-" } else { "" };
+    let whole = to_string_integer_base10(&whole.parse::<BigInt>().unwrap_or_default(), state);
+    match fraction {
+        Some(fraction) => format!("{sign}{whole}.{fraction}"),
+        None => format!("{sign}{whole}"),
     }
-    let whole_part = n as i128;
-    let abs_whole_part = whole_part.abs();
-    let sign = if n < 0.0 { "-" } else { "" };
-    let base_prefix = if opts.include_base_prefix { "0x" } else { "" };
-    format!(
-        "{sign}{base_prefix}{}",
-        group_digits(format!("{:x}", abs_whole_part), 4, opts.left_pad_with_zeros)
-    )
 }
 
 fn to_string_opts_base10(n: f64, opts: &StackItemToStringOpts, state: &RpnState) -> String {
@@ -409,18 +1485,61 @@ fn is_integer(n: f64) -> bool {
     ((whole_part as f64) - n).abs() < f64::EPSILON * 1000.0
 }
 
-fn degrees_to_radians(v: f64) -> f64 {
-    v * f64::consts::PI / 180.0
+/// Extra digits carried through inexact decimal operations before rounding to the working precision.
+const DECIMAL_GUARD_DIGITS: usize = 5;
+
+fn decimal_context(precision: usize) -> Context {
+    Context::default()
+        .with_prec(precision.max(1))
+        .unwrap_or_default()
+}
+
+fn decimal_to_rational(v: &BigDecimal) -> BigRational {
+    let (digits, scale) = v.as_bigint_and_exponent();
+    let ten = BigInt::from(10);
+    if scale >= 0 {
+        BigRational::new(digits, ten.pow(scale as u32))
+    } else {
+        BigRational::from_integer(digits * ten.pow(scale.unsigned_abs() as u32))
+    }
+}
+
+/// Natural logarithm of a positive decimal, refining the floating point estimate with
+/// Halley's method on exp(y) = a.
+fn decimal_ln(a: &BigDecimal, ctx: &Context) -> BigDecimal {
+    let mut y = BigDecimal::try_from(a.to_f64().unwrap_or(1.0).ln()).unwrap_or_default();
+    let tolerance = BigDecimal::new(BigInt::one(), ctx.precision().get() as i64);
+    for _ in 0..100 {
+        let exp_y = y.exp_with_context(ctx);
+        let step = ctx.multiply(&(a - &exp_y).double(), &ctx.invert(&(a + &exp_y)));
+        y = ctx.round_decimal(y + &step);
+        if step.abs() < tolerance {
+            break;
+        }
+    }
+    y
+}
+
+fn angle_mode_to_radians(v: f64, angle_mode: AngleMode) -> f64 {
+    match angle_mode {
+        AngleMode::Degrees => v * f64::consts::PI / 180.0,
+        AngleMode::Radians => v,
+        AngleMode::Gradians => v * f64::consts::PI / 200.0,
+        AngleMode::Turns => v * f64::consts::TAU,
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use num_bigint::BigInt;
+    use num_complex::Complex64;
     use num_format::SystemLocale;
+    use num_rational::BigRational;
 
     use crate::{
         init_logger,
         stack::item::{StackItem, StackItemToStringOpts},
-        state::RpnState,
+        state::{complex_display::ComplexDisplay, rational_display::RationalDisplay, RpnState},
     };
 
     #[macro_export]
@@ -447,6 +1566,7 @@ mod test {
         let opts = StackItemToStringOpts {
             base: Some(10),
             precision: None,
+            rational_display: None,
             left_pad_with_zeros: false,
             include_base_prefix: false,
         };
@@ -478,6 +1598,7 @@ mod test {
         let opts = StackItemToStringOpts {
             base: Some(16),
             precision: None,
+            rational_display: None,
             left_pad_with_zeros: false,
             include_base_prefix: true,
         };
@@ -507,6 +1628,7 @@ mod test {
         let opts = StackItemToStringOpts {
             base: Some(8),
             precision: None,
+            rational_display: None,
             left_pad_with_zeros: false,
             include_base_prefix: true,
         };
@@ -536,6 +1658,7 @@ mod test {
         let opts = StackItemToStringOpts {
             base: Some(2),
             precision: None,
+            rational_display: None,
             left_pad_with_zeros: true,
             include_base_prefix: true,
         };
@@ -558,6 +1681,26 @@ mod test {
         assert_to_string_opts!("1.123e100", 1.123e100, &opts, &state);
     }
 
+    #[test]
+    pub fn to_string_opts_base36() {
+        let opts = StackItemToStringOpts {
+            base: Some(36),
+            precision: None,
+            rational_display: None,
+            left_pad_with_zeros: true,
+            include_base_prefix: true,
+        };
+        let state = RpnState::new().unwrap();
+
+        assert_to_string_opts!("36r1z", 71.0, &opts, &state);
+        assert_to_string_opts!("-36r1z", -71.0, &opts, &state);
+        assert_to_string_opts!("36r0", 0.0, &opts, &state);
+        assert_eq!(
+            "36r1qae8ggyq4ngmo",
+            StackItem::Integer(BigInt::from(u64::MAX) << 4, 36).to_string_opts(&opts, &state)
+        );
+    }
+
     #[test]
     pub fn to_add_number_to_number() {
         let v = StackItem::Number(42.0, 10)
@@ -566,6 +1709,100 @@ mod test {
         assert_eq!(StackItem::Number(42.0 + 13.0, 16), v);
     }
 
+    #[test]
+    pub fn to_add_integer_to_integer() {
+        let a = BigInt::from(u64::MAX);
+        let v = StackItem::Integer(a.clone(), 16)
+            .add(&StackItem::Integer(BigInt::from(1), 16))
+            .unwrap();
+        assert_eq!(StackItem::Integer(a + 1, 16), v);
+    }
+
+    #[test]
+    pub fn to_multiply_integer_exact() {
+        let v = StackItem::Integer(BigInt::from(0xffff_ffff_ffff_ffffu64), 10)
+            .multiply(&StackItem::Integer(BigInt::from(31), 10))
+            .unwrap();
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0xffff_ffff_ffff_ffffu128 * 31), 10),
+            v
+        );
+    }
+
+    #[test]
+    pub fn to_pow_integer_exact() {
+        let v = StackItem::Integer(BigInt::from(2), 10)
+            .pow(&StackItem::Integer(BigInt::from(130), 10))
+            .unwrap();
+        assert_eq!(StackItem::Integer(BigInt::from(1) << 130, 10), v);
+    }
+
+    #[test]
+    pub fn to_string_opts_integer_base16() {
+        let opts = StackItemToStringOpts {
+            base: Some(16),
+            precision: None,
+            rational_display: None,
+            left_pad_with_zeros: true,
+            include_base_prefix: false,
+        };
+        let state = RpnState::new().unwrap();
+
+        let v = StackItem::Integer((BigInt::from(1) << 132) - 1, 10);
+        assert_eq!(
+            "000f ffff ffff ffff ffff ffff ffff ffff ffff",
+            v.to_string_opts(&opts, &state)
+        );
+    }
+
+    #[test]
+    pub fn to_add_rational_to_integer() {
+        let v = StackItem::Rational(BigRational::new(1.into(), 3.into()))
+            .add(&StackItem::Integer(1.into(), 10))
+            .unwrap();
+        assert_eq!(StackItem::Rational(BigRational::new(4.into(), 3.into())), v);
+    }
+
+    #[test]
+    pub fn to_string_opts_rational() {
+        let mut opts = StackItemToStringOpts {
+            base: None,
+            precision: None,
+            rational_display: None,
+            left_pad_with_zeros: false,
+            include_base_prefix: false,
+        };
+        let mut state = RpnState::new().unwrap();
+        let v = StackItem::Rational(BigRational::new((-2).into(), 3.into()));
+
+        assert_eq!("-0.6666666667", v.to_string_opts(&opts, &state));
+
+        state.rational_display = RationalDisplay::Fraction;
+        assert_eq!("-2/3", v.to_string_opts(&opts, &state));
+
+        opts.rational_display = Some(RationalDisplay::Decimal);
+        assert_eq!("-0.6666666667", v.to_string_opts(&opts, &state));
+    }
+
+    #[test]
+    pub fn to_string_opts_complex() {
+        let opts = StackItemToStringOpts {
+            base: None,
+            precision: None,
+            rational_display: None,
+            left_pad_with_zeros: false,
+            include_base_prefix: false,
+        };
+        let mut state = RpnState::new().unwrap();
+        let v = StackItem::Complex(Complex64::new(3.0, -4.0));
+
+        assert_eq!("3-4i", v.to_string_opts(&opts, &state));
+        assert_eq!("3-4i", v.to_string());
+
+        state.complex_display = ComplexDisplay::Polar;
+        assert_eq!("5∠-53.1301023542", v.to_string_opts(&opts, &state));
+    }
+
     #[test]
     pub fn to_add_string_to_string() {
         let v = StackItem::String("te".to_string())
@@ -579,5 +1816,7 @@ pub fn radians_to_angle_mode(v: f64, angle_mode: AngleMode) -> f64 {
     match angle_mode {
         AngleMode::Degrees => v * 180.0 / f64::consts::PI,
         AngleMode::Radians => v,
+        AngleMode::Gradians => v * 200.0 / f64::consts::PI,
+        AngleMode::Turns => v / f64::consts::TAU,
     }
 }
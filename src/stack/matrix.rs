@@ -0,0 +1,321 @@
+use anyhow::{anyhow, Result};
+use num_bigint::BigInt;
+
+use super::item::StackItem;
+
+/// Rows of a matrix, always rectangular with at least one row and column.
+pub type Rows = Vec<Vec<StackItem>>;
+
+pub fn is_array(item: &StackItem) -> bool {
+    matches!(item, StackItem::Vector(_) | StackItem::Matrix(_))
+}
+
+/// Builds a vector from scalars or a matrix from vectors of the same length.
+pub fn build(items: Vec<StackItem>) -> Result<StackItem> {
+    if items.is_empty() {
+        return Err(anyhow!("vectors must have at least one element"));
+    }
+    if items.iter().all(|item| !is_array(item)) {
+        return Ok(StackItem::Vector(items));
+    }
+    let rows = items
+        .into_iter()
+        .map(|item| match item {
+            StackItem::Vector(row) => Ok(row),
+            _ => Err(anyhow!("matrix rows must all be vectors, found {item}")),
+        })
+        .collect::<Result<Rows>>()?;
+    if rows.iter().any(|row| row.len() != rows[0].len()) {
+        return Err(anyhow!("matrix rows must all have the same length"));
+    }
+    Ok(StackItem::Matrix(rows))
+}
+
+pub fn add(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    element_wise(a, b, "add", |a, b| a.add(b))
+}
+
+pub fn subtract(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    element_wise(a, b, "subtract", |a, b| a.subtract(b))
+}
+
+pub fn multiply(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    match (a, b) {
+        (StackItem::Matrix(a), StackItem::Matrix(b)) => {
+            Ok(StackItem::Matrix(matrix_multiply(a, b)?))
+        }
+        (StackItem::Matrix(a), StackItem::Vector(b)) => {
+            let column = b.iter().map(|v| vec![v.clone()]).collect::<Rows>();
+            let result = matrix_multiply(a, &column)?;
+            Ok(StackItem::Vector(result.into_iter().flatten().collect()))
+        }
+        (StackItem::Vector(a), StackItem::Matrix(b)) => {
+            let result = matrix_multiply(&vec![a.clone()], b)?;
+            Ok(StackItem::Vector(result.into_iter().flatten().collect()))
+        }
+        (StackItem::Vector(_), StackItem::Vector(_)) => Err(anyhow!(
+            "cannot multiply two vectors, use dot or cross instead"
+        )),
+        (array, scalar) if !is_array(scalar) => map(array, |v| v.multiply(scalar)),
+        (scalar, array) => map(array, |v| scalar.multiply(v)),
+    }
+}
+
+pub fn divide(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    match (a, b) {
+        (_, StackItem::Matrix(b)) => multiply(a, &StackItem::Matrix(inverse(b)?)),
+        (_, StackItem::Vector(_)) => Err(anyhow!("cannot divide by a vector")),
+        (array, scalar) => map(array, |v| v.divide(scalar)),
+    }
+}
+
+pub fn negate(a: &StackItem) -> Result<StackItem> {
+    map(a, |v| v.negate())
+}
+
+pub fn transpose(a: &StackItem) -> Result<StackItem> {
+    match a {
+        StackItem::Vector(v) => Ok(StackItem::Matrix(
+            v.iter().map(|v| vec![v.clone()]).collect(),
+        )),
+        StackItem::Matrix(m) => Ok(StackItem::Matrix(
+            (0..m[0].len())
+                .map(|col| m.iter().map(|row| row[col].clone()).collect())
+                .collect(),
+        )),
+        _ => Err(anyhow!("expected a vector or matrix but found {a}")),
+    }
+}
+
+pub fn dot(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    let (StackItem::Vector(a), StackItem::Vector(b)) = (a, b) else {
+        return Err(anyhow!("dot product requires two vectors"));
+    };
+    if a.len() != b.len() {
+        return Err(anyhow!(
+            "cannot take the dot product of vectors of length {} and {}",
+            a.len(),
+            b.len()
+        ));
+    }
+    let mut sum = integer(0);
+    for (a, b) in a.iter().zip(b) {
+        sum = sum.add(&a.multiply(b)?)?;
+    }
+    Ok(sum)
+}
+
+pub fn cross(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    match (a, b) {
+        (StackItem::Vector(a), StackItem::Vector(b)) if a.len() == 3 && b.len() == 3 => {
+            let component = |i: usize, j: usize| -> Result<StackItem> {
+                a[i].multiply(&b[j])?.subtract(&a[j].multiply(&b[i])?)
+            };
+            Ok(StackItem::Vector(vec![
+                component(1, 2)?,
+                component(2, 0)?,
+                component(0, 1)?,
+            ]))
+        }
+        _ => Err(anyhow!("cross product requires two vectors of length 3")),
+    }
+}
+
+pub fn determinant(a: &StackItem) -> Result<StackItem> {
+    let StackItem::Matrix(m) = a else {
+        return Err(anyhow!("expected a matrix but found {a}"));
+    };
+    require_square(m)?;
+    let mut m = m.clone();
+    let n = m.len();
+    let mut det = integer(1);
+    for col in 0..n {
+        let Some(pivot) = find_pivot(&m, col) else {
+            return Ok(integer(0));
+        };
+        if pivot != col {
+            m.swap(pivot, col);
+            det = det.negate()?;
+        }
+        det = det.multiply(&m[col][col])?;
+        let pivot_row = m[col].clone();
+        for row in m.iter_mut().skip(col + 1) {
+            let factor = row[col].divide(&pivot_row[col])?;
+            for (v, p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *v = v.subtract(&factor.multiply(p)?)?;
+            }
+        }
+    }
+    Ok(det)
+}
+
+/// Solves `a x = b` where `b` is a vector or a matrix of right hand sides.
+pub fn solve(a: &StackItem, b: &StackItem) -> Result<StackItem> {
+    let StackItem::Matrix(a) = a else {
+        return Err(anyhow!("expected a matrix of coefficients but found {a}"));
+    };
+    match b {
+        StackItem::Vector(b) => {
+            let b = b.iter().map(|v| vec![v.clone()]).collect::<Rows>();
+            let x = gauss_jordan(a, &b)?;
+            Ok(StackItem::Vector(x.into_iter().flatten().collect()))
+        }
+        StackItem::Matrix(b) => Ok(StackItem::Matrix(gauss_jordan(a, b)?)),
+        _ => Err(anyhow!(
+            "expected a vector or matrix of constants but found {b}"
+        )),
+    }
+}
+
+fn inverse(m: &Rows) -> Result<Rows> {
+    require_square(m)?;
+    let identity = (0..m.len())
+        .map(|row| {
+            (0..m.len())
+                .map(|col| integer(if row == col { 1 } else { 0 }))
+                .collect()
+        })
+        .collect::<Rows>();
+    gauss_jordan(m, &identity)
+}
+
+/// Reduces `[a | b]` until `a` is the identity, returning what `b` became.
+fn gauss_jordan(a: &Rows, b: &Rows) -> Result<Rows> {
+    require_square(a)?;
+    if a.len() != b.len() {
+        return Err(anyhow!(
+            "cannot solve a {} system with {} constants",
+            shape(a),
+            b.len()
+        ));
+    }
+    let n = a.len();
+    let mut m = a
+        .iter()
+        .zip(b)
+        .map(|(a, b)| a.iter().chain(b).cloned().collect::<Vec<StackItem>>())
+        .collect::<Rows>();
+    for col in 0..n {
+        let pivot = find_pivot(&m, col).ok_or_else(|| anyhow!("matrix is singular"))?;
+        m.swap(pivot, col);
+        let p = m[col][col].clone();
+        for v in m[col].iter_mut() {
+            *v = v.divide(&p)?;
+        }
+        let pivot_row = m[col].clone();
+        for (i, row) in m.iter_mut().enumerate() {
+            if i == col || row[col].is_zero() {
+                continue;
+            }
+            let factor = row[col].clone();
+            for (v, p) in row.iter_mut().zip(&pivot_row) {
+                *v = v.subtract(&factor.multiply(p)?)?;
+            }
+        }
+    }
+    Ok(m.into_iter().map(|row| row[n..].to_vec()).collect())
+}
+
+/// Returns the row at or below `col` with the largest value in that column, or `None`
+/// if the column is all zeros.
+fn find_pivot(m: &Rows, col: usize) -> Option<usize> {
+    (col..m.len())
+        .filter(|row| !m[*row][col].is_zero())
+        .max_by(|a, b| magnitude(&m[*a][col]).total_cmp(&magnitude(&m[*b][col])))
+}
+
+fn magnitude(item: &StackItem) -> f64 {
+    item.to_complex().map(|v| v.norm()).unwrap_or(0.0)
+}
+
+fn matrix_multiply(a: &Rows, b: &Rows) -> Result<Rows> {
+    if a[0].len() != b.len() {
+        return Err(anyhow!(
+            "cannot multiply a {} matrix by a {} matrix, columns must match rows",
+            shape(a),
+            shape(b)
+        ));
+    }
+    let mut result = vec![];
+    for row in a {
+        let mut result_row = vec![integer(0); b[0].len()];
+        for (v, b_row) in row.iter().zip(b) {
+            for (sum, w) in result_row.iter_mut().zip(b_row) {
+                *sum = sum.add(&v.multiply(w)?)?;
+            }
+        }
+        result.push(result_row);
+    }
+    Ok(result)
+}
+
+fn element_wise<F>(a: &StackItem, b: &StackItem, op_name: &str, op: F) -> Result<StackItem>
+where
+    F: Fn(&StackItem, &StackItem) -> Result<StackItem>,
+{
+    match (a, b) {
+        (StackItem::Vector(a), StackItem::Vector(b)) if a.len() == b.len() => {
+            Ok(StackItem::Vector(
+                a.iter()
+                    .zip(b)
+                    .map(|(a, b)| op(a, b))
+                    .collect::<Result<_>>()?,
+            ))
+        }
+        (StackItem::Matrix(a), StackItem::Matrix(b)) if shape(a) == shape(b) => {
+            Ok(StackItem::Matrix(
+                a.iter()
+                    .zip(b)
+                    .map(|(a, b)| a.iter().zip(b).map(|(a, b)| op(a, b)).collect())
+                    .collect::<Result<_>>()?,
+            ))
+        }
+        _ => Err(anyhow!(
+            "cannot {op_name} {} and {}",
+            describe(a),
+            describe(b)
+        )),
+    }
+}
+
+/// Applies `op` to every element of a vector or matrix, or to `a` itself if it is a scalar.
+pub fn map<F>(a: &StackItem, op: F) -> Result<StackItem>
+where
+    F: Fn(&StackItem) -> Result<StackItem>,
+{
+    match a {
+        StackItem::Vector(v) => Ok(StackItem::Vector(v.iter().map(&op).collect::<Result<_>>()?)),
+        StackItem::Matrix(m) => Ok(StackItem::Matrix(
+            m.iter()
+                .map(|row| row.iter().map(&op).collect())
+                .collect::<Result<_>>()?,
+        )),
+        _ => op(a),
+    }
+}
+
+fn require_square(m: &Rows) -> Result<()> {
+    if m.len() != m[0].len() {
+        return Err(anyhow!(
+            "expected a square matrix but found a {} matrix",
+            shape(m)
+        ));
+    }
+    Ok(())
+}
+
+fn shape(m: &Rows) -> String {
+    format!("{}x{}", m.len(), m[0].len())
+}
+
+fn describe(item: &StackItem) -> String {
+    match item {
+        StackItem::Vector(v) => format!("a vector of length {}", v.len()),
+        StackItem::Matrix(m) => format!("a {} matrix", shape(m)),
+        _ => format!("the scalar {item}"),
+    }
+}
+
+fn integer(v: i32) -> StackItem {
+    StackItem::Integer(BigInt::from(v), 10)
+}
@@ -3,7 +3,12 @@ use core::slice::Iter;
 use anyhow::{anyhow, Result};
 use item::StackItem;
 
+pub mod datetime;
+pub mod float_bits;
 pub mod item;
+pub mod matrix;
+pub mod sexagesimal;
+pub mod unit;
 
 #[derive(Debug)]
 pub struct Stack {
@@ -0,0 +1,124 @@
+use anyhow::{anyhow, Result};
+
+/// Seconds are rounded to this many decimal places when displayed.
+const SECOND_PLACES: u32 = 6;
+
+/// Splits a value in degrees or hours into its sign, whole units, minutes and
+/// seconds, with the seconds scaled by 10^SECOND_PLACES.
+fn split(v: f64) -> (bool, u128, u128, u128) {
+    let scale = 10u128.pow(SECOND_PLACES);
+    let total = (v.abs() * 3600.0 * scale as f64).round() as u128;
+    let whole = total / (3600 * scale);
+    let minutes = total / (60 * scale) % 60;
+    let seconds = total % (60 * scale);
+    (v < 0.0 && total != 0, whole, minutes, seconds)
+}
+
+/// Formats scaled seconds, padding the whole seconds to `width` digits and leaving
+/// off trailing zeros of the fraction.
+fn format_seconds(seconds: u128, width: usize) -> String {
+    let scale = 10u128.pow(SECOND_PLACES);
+    let frac = seconds % scale;
+    let mut s = format!("{:0width$}", seconds / scale);
+    if frac != 0 {
+        let digits = format!("{frac:0places$}", places = SECOND_PLACES as usize);
+        s += ".";
+        s += digits.trim_end_matches('0');
+    }
+    s
+}
+
+/// Formats degrees as degrees, minutes and seconds such as `12°34'56.7"`.
+pub fn format_dms(degrees: f64) -> String {
+    if !degrees.is_finite() {
+        return format!("{degrees}°");
+    }
+    let (negative, whole, minutes, seconds) = split(degrees);
+    let sign = if negative { "-" } else { "" };
+    format!("{sign}{whole}°{minutes}'{}\"", format_seconds(seconds, 1))
+}
+
+/// Formats hours as hours, minutes and seconds such as `1:02:03.5`.
+pub fn format_hms(hours: f64) -> String {
+    if !hours.is_finite() {
+        return format!("{hours}h");
+    }
+    let (negative, whole, minutes, seconds) = split(hours);
+    let sign = if negative { "-" } else { "" };
+    format!("{sign}{whole}:{minutes:02}:{}", format_seconds(seconds, 2))
+}
+
+/// Parses degrees, minutes and seconds such as `12°34'56.7"`, `12°30'` or `45°`
+/// into degrees.
+pub fn parse_dms(s: &str) -> Result<f64> {
+    let err = || anyhow!("invalid degrees, minutes and seconds {s}");
+    let (degrees, rest) = s.split_once('°').ok_or_else(err)?;
+    let (minutes, rest) = rest.split_once('\'').unwrap_or(("0", rest));
+    let seconds = match rest {
+        "" => "0",
+        _ => rest.strip_suffix('"').ok_or_else(err)?,
+    };
+    let parse = |part: &str| part.parse::<f64>().map_err(|_| err());
+    combine(parse(degrees)?, parse(minutes)?, parse(seconds)?).ok_or_else(err)
+}
+
+/// Parses hours, minutes and seconds such as `1:02:03.5` into hours.
+pub fn parse_hms(s: &str) -> Result<f64> {
+    let err = || anyhow!("invalid hours, minutes and seconds {s}");
+    let parts = s
+        .split(':')
+        .map(|part| part.parse::<f64>().map_err(|_| err()))
+        .collect::<Result<Vec<_>>>()?;
+    match parts.as_slice() {
+        [hours, minutes, seconds] => combine(*hours, *minutes, *seconds).ok_or_else(err),
+        _ => Err(err()),
+    }
+}
+
+/// Combines whole units, minutes and seconds, which must each be less than 60.
+fn combine(whole: f64, minutes: f64, seconds: f64) -> Option<f64> {
+    (minutes < 60.0 && seconds < 60.0).then(|| whole + minutes / 60.0 + seconds / 3600.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{format_dms, format_hms, parse_dms, parse_hms};
+
+    #[test]
+    fn test_format_dms() {
+        assert_eq!(
+            "12°34'56.7\"",
+            format_dms(12.0 + 34.0 / 60.0 + 56.7 / 3600.0)
+        );
+        assert_eq!("-45°0'0\"", format_dms(-45.0));
+        assert_eq!("1°0'0\"", format_dms(59.0 / 60.0 + 59.9999999 / 3600.0));
+    }
+
+    #[test]
+    fn test_format_hms() {
+        assert_eq!("1:02:03.5", format_hms(1.0 + 2.0 / 60.0 + 3.5 / 3600.0));
+        assert_eq!("-0:30:00", format_hms(-0.5));
+        assert_eq!("100:00:00", format_hms(100.0));
+    }
+
+    #[test]
+    fn test_parse_dms() {
+        assert_eq!(12.5, parse_dms("12°30'").unwrap());
+        assert_eq!(45.0, parse_dms("45°").unwrap());
+        assert_eq!(
+            "12°34'56.7\"",
+            format_dms(parse_dms("12°34'56.7\"").unwrap())
+        );
+        assert!(parse_dms("12°34'56").is_err());
+        assert!(parse_dms("0°59'60\"").is_err());
+        assert!(parse_dms("0°60'").is_err());
+    }
+
+    #[test]
+    fn test_parse_hms() {
+        assert_eq!(1.5, parse_hms("1:30:00").unwrap());
+        assert_eq!("1:02:03.5", format_hms(parse_hms("1:02:03.5").unwrap()));
+        assert!(parse_hms("1:60:00").is_err());
+        assert!(parse_hms("1:00:60").is_err());
+    }
+}
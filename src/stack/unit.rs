@@ -0,0 +1,358 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+
+/// Exponents of the SI base dimensions: length, mass, time, current, temperature,
+/// amount of substance and luminous intensity.
+pub type Dimensions = [i32; 7];
+
+const DIMENSION_NAMES: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+const LENGTH: Dimensions = [1, 0, 0, 0, 0, 0, 0];
+const MASS: Dimensions = [0, 1, 0, 0, 0, 0, 0];
+const TIME: Dimensions = [0, 0, 1, 0, 0, 0, 0];
+const CURRENT: Dimensions = [0, 0, 0, 1, 0, 0, 0];
+const TEMPERATURE: Dimensions = [0, 0, 0, 0, 1, 0, 0];
+const AMOUNT: Dimensions = [0, 0, 0, 0, 0, 1, 0];
+const LUMINOUS_INTENSITY: Dimensions = [0, 0, 0, 0, 0, 0, 1];
+const AREA: Dimensions = [2, 0, 0, 0, 0, 0, 0];
+const VOLUME: Dimensions = [3, 0, 0, 0, 0, 0, 0];
+const VELOCITY: Dimensions = [1, 0, -1, 0, 0, 0, 0];
+const FREQUENCY: Dimensions = [0, 0, -1, 0, 0, 0, 0];
+const FORCE: Dimensions = [1, 1, -2, 0, 0, 0, 0];
+const ENERGY: Dimensions = [2, 1, -2, 0, 0, 0, 0];
+const POWER: Dimensions = [2, 1, -3, 0, 0, 0, 0];
+const PRESSURE: Dimensions = [-1, 1, -2, 0, 0, 0, 0];
+const CHARGE: Dimensions = [0, 0, 1, 1, 0, 0, 0];
+const VOLTAGE: Dimensions = [2, 1, -3, -1, 0, 0, 0];
+const RESISTANCE: Dimensions = [2, 1, -3, -2, 0, 0, 0];
+const CAPACITANCE: Dimensions = [-2, -1, 4, 2, 0, 0, 0];
+
+pub struct UnitDefinition {
+    /// multiply by this to convert a value in this unit to SI base units
+    pub factor: f64,
+    pub dimensions: Dimensions,
+}
+
+lazy_static! {
+    static ref UNITS: HashMap<&'static str, UnitDefinition> = {
+        let units: Vec<(&str, f64, Dimensions)> = vec![
+            // length
+            ("m", 1.0, LENGTH),
+            ("km", 1000.0, LENGTH),
+            ("cm", 0.01, LENGTH),
+            ("mm", 0.001, LENGTH),
+            ("um", 1e-6, LENGTH),
+            ("µm", 1e-6, LENGTH),
+            ("nm", 1e-9, LENGTH),
+            ("in", 0.0254, LENGTH),
+            ("ft", 0.3048, LENGTH),
+            ("yd", 0.9144, LENGTH),
+            ("mi", 1609.344, LENGTH),
+            ("nmi", 1852.0, LENGTH),
+            // mass
+            ("kg", 1.0, MASS),
+            ("g", 0.001, MASS),
+            ("mg", 1e-6, MASS),
+            ("t", 1000.0, MASS),
+            ("lb", 0.45359237, MASS),
+            ("oz", 0.028349523125, MASS),
+            ("slug", 14.593902937, MASS),
+            // time
+            ("s", 1.0, TIME),
+            ("ms", 0.001, TIME),
+            ("us", 1e-6, TIME),
+            ("µs", 1e-6, TIME),
+            ("ns", 1e-9, TIME),
+            ("min", 60.0, TIME),
+            ("h", 3600.0, TIME),
+            ("day", 86400.0, TIME),
+            ("wk", 604800.0, TIME),
+            ("yr", 31557600.0, TIME),
+            // current
+            ("A", 1.0, CURRENT),
+            ("mA", 0.001, CURRENT),
+            // temperature, intervals only as offsets cannot be multiplied
+            ("K", 1.0, TEMPERATURE),
+            ("R", 5.0 / 9.0, TEMPERATURE),
+            // amount of substance
+            ("mol", 1.0, AMOUNT),
+            ("mmol", 0.001, AMOUNT),
+            // luminous intensity
+            ("cd", 1.0, LUMINOUS_INTENSITY),
+            // area
+            ("ha", 10000.0, AREA),
+            ("acre", 4046.8564224, AREA),
+            // volume
+            ("L", 0.001, VOLUME),
+            ("mL", 1e-6, VOLUME),
+            ("gal", 0.003785411784, VOLUME),
+            ("qt", 0.000946352946, VOLUME),
+            ("floz", 2.95735295625e-5, VOLUME),
+            // velocity
+            ("mph", 0.44704, VELOCITY),
+            ("kph", 1000.0 / 3600.0, VELOCITY),
+            ("kn", 1852.0 / 3600.0, VELOCITY),
+            // frequency
+            ("Hz", 1.0, FREQUENCY),
+            ("kHz", 1e3, FREQUENCY),
+            ("MHz", 1e6, FREQUENCY),
+            ("GHz", 1e9, FREQUENCY),
+            ("rpm", 1.0 / 60.0, FREQUENCY),
+            // force
+            ("N", 1.0, FORCE),
+            ("kN", 1000.0, FORCE),
+            ("lbf", 4.4482216152605, FORCE),
+            ("dyn", 1e-5, FORCE),
+            // energy
+            ("J", 1.0, ENERGY),
+            ("kJ", 1000.0, ENERGY),
+            ("MJ", 1e6, ENERGY),
+            ("cal", 4.184, ENERGY),
+            ("kcal", 4184.0, ENERGY),
+            ("Wh", 3600.0, ENERGY),
+            ("kWh", 3.6e6, ENERGY),
+            ("BTU", 1055.05585262, ENERGY),
+            ("eV", 1.602176634e-19, ENERGY),
+            // power
+            ("W", 1.0, POWER),
+            ("kW", 1000.0, POWER),
+            ("MW", 1e6, POWER),
+            ("hp", 745.699_871_582_270_2, POWER),
+            // pressure
+            ("Pa", 1.0, PRESSURE),
+            ("kPa", 1000.0, PRESSURE),
+            ("MPa", 1e6, PRESSURE),
+            ("bar", 100000.0, PRESSURE),
+            ("atm", 101325.0, PRESSURE),
+            ("psi", 6894.757293168, PRESSURE),
+            ("mmHg", 133.322387415, PRESSURE),
+            // electrical
+            ("C", 1.0, CHARGE),
+            ("Ah", 3600.0, CHARGE),
+            ("mAh", 3.6, CHARGE),
+            ("V", 1.0, VOLTAGE),
+            ("mV", 0.001, VOLTAGE),
+            ("kV", 1000.0, VOLTAGE),
+            ("ohm", 1.0, RESISTANCE),
+            ("Ω", 1.0, RESISTANCE),
+            ("kohm", 1000.0, RESISTANCE),
+            ("F", 1.0, CAPACITANCE),
+            ("uF", 1e-6, CAPACITANCE),
+            ("µF", 1e-6, CAPACITANCE),
+            ("nF", 1e-9, CAPACITANCE),
+            ("pF", 1e-12, CAPACITANCE),
+        ];
+        units
+            .into_iter()
+            .map(|(symbol, factor, dimensions)| (symbol, UnitDefinition { factor, dimensions }))
+            .collect()
+    };
+}
+
+/// A product of unit symbols raised to integer powers, for example `m*s^-2`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unit {
+    terms: Vec<(String, i32)>,
+}
+
+impl Unit {
+    /// The unit of a plain number.
+    pub fn none() -> Self {
+        Self { terms: vec![] }
+    }
+
+    /// Parses a unit expression such as `m`, `ft/s` or `kg*m/s^2`. Each `*` or `/`
+    /// applies to the single term that follows it.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut unit = Unit::none();
+        let mut sign = 1;
+        let mut rest = s.trim();
+        loop {
+            let end = rest.find(['*', '/']).unwrap_or(rest.len());
+            let term = rest[..end].trim();
+            let (symbol, exponent) = match term.split_once('^') {
+                Some((symbol, exponent)) => (
+                    symbol,
+                    exponent
+                        .parse::<i32>()
+                        .map_err(|_| anyhow!("invalid exponent in unit {s}"))?,
+                ),
+                None => (term, 1),
+            };
+            if !UNITS.contains_key(symbol) {
+                return Err(anyhow!("unknown unit {symbol}"));
+            }
+            unit = unit.multiply(&Unit {
+                terms: vec![(symbol.to_string(), sign * exponent)],
+            });
+
+            if end == rest.len() {
+                return Ok(unit);
+            }
+            sign = if rest[end..].starts_with('/') { -1 } else { 1 };
+            rest = &rest[end + 1..];
+        }
+    }
+
+    pub fn multiply(&self, other: &Unit) -> Unit {
+        let mut terms = self.terms.clone();
+        for (symbol, exponent) in &other.terms {
+            match terms.iter_mut().find(|(s, _)| s == symbol) {
+                Some(term) => term.1 += exponent,
+                None => terms.push((symbol.clone(), *exponent)),
+            }
+        }
+        terms.retain(|(_, exponent)| *exponent != 0);
+        Unit { terms }
+    }
+
+    pub fn divide(&self, other: &Unit) -> Unit {
+        self.multiply(&other.powi(-1))
+    }
+
+    pub fn powi(&self, n: i32) -> Unit {
+        Unit {
+            terms: self
+                .terms
+                .iter()
+                .filter(|_| n != 0)
+                .map(|(symbol, exponent)| (symbol.clone(), exponent * n))
+                .collect(),
+        }
+    }
+
+    /// Returns the square root of the unit, or `None` if any exponent is odd.
+    pub fn sqrt(&self) -> Option<Unit> {
+        if self.terms.iter().any(|(_, exponent)| exponent % 2 != 0) {
+            return None;
+        }
+        Some(Unit {
+            terms: self
+                .terms
+                .iter()
+                .map(|(symbol, exponent)| (symbol.clone(), exponent / 2))
+                .collect(),
+        })
+    }
+
+    /// Returns the factor that converts a value in this unit to SI base units.
+    pub fn factor(&self) -> f64 {
+        self.terms
+            .iter()
+            .map(|(symbol, exponent)| UNITS[symbol.as_str()].factor.powi(*exponent))
+            .product()
+    }
+
+    pub fn dimensions(&self) -> Dimensions {
+        let mut dimensions = [0; 7];
+        for (symbol, exponent) in &self.terms {
+            for (d, unit_d) in dimensions.iter_mut().zip(UNITS[symbol.as_str()].dimensions) {
+                *d += unit_d * exponent;
+            }
+        }
+        dimensions
+    }
+
+    pub fn is_dimensionless(&self) -> bool {
+        self.dimensions() == [0; 7]
+    }
+
+    /// Describes the dimensions in SI base units for error messages, for example `m*s^-1`.
+    pub fn dimensions_string(&self) -> String {
+        let s = self
+            .dimensions()
+            .iter()
+            .zip(DIMENSION_NAMES)
+            .filter(|(exponent, _)| **exponent != 0)
+            .map(|(exponent, name)| format_term(name, *exponent))
+            .collect::<Vec<String>>()
+            .join("*");
+        if s.is_empty() {
+            "dimensionless".to_string()
+        } else {
+            s
+        }
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numerator = self
+            .terms
+            .iter()
+            .filter(|(_, exponent)| *exponent > 0)
+            .map(|(symbol, exponent)| format_term(symbol, *exponent))
+            .collect::<Vec<String>>();
+        let denominator = self
+            .terms
+            .iter()
+            .filter(|(_, exponent)| *exponent < 0)
+            .map(|(symbol, exponent)| format_term(symbol, -exponent))
+            .collect::<Vec<String>>();
+
+        if numerator.is_empty() {
+            // "/s" would not parse, so write the exponents out
+            let terms = self
+                .terms
+                .iter()
+                .map(|(symbol, exponent)| format_term(symbol, *exponent))
+                .collect::<Vec<String>>();
+            return write!(f, "{}", terms.join("*"));
+        }
+        write!(f, "{}", numerator.join("*"))?;
+        for term in denominator {
+            write!(f, "/{term}")?;
+        }
+        Ok(())
+    }
+}
+
+fn format_term(symbol: &str, exponent: i32) -> String {
+    if exponent == 1 {
+        symbol.to_string()
+    } else {
+        format!("{symbol}^{exponent}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Unit;
+
+    #[test]
+    fn test_parse() {
+        let unit = Unit::parse("kg*m/s^2").unwrap();
+        assert_eq!("kg*m/s^2", unit.to_string());
+        assert_eq!([1, 1, -2, 0, 0, 0, 0], unit.dimensions());
+        assert_eq!(Unit::parse("N").unwrap().dimensions(), unit.dimensions());
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert!(Unit::parse("furlong").is_err());
+    }
+
+    #[test]
+    fn test_multiply_cancels() {
+        let unit = Unit::parse("m/s")
+            .unwrap()
+            .multiply(&Unit::parse("s").unwrap());
+        assert_eq!("m", unit.to_string());
+    }
+
+    #[test]
+    fn test_factor() {
+        let unit = Unit::parse("ft/s").unwrap();
+        assert!((unit.factor() - 0.3048).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_display_inverse() {
+        assert_eq!("s^-1", Unit::parse("s").unwrap().powi(-1).to_string());
+    }
+}
@@ -2,4 +2,8 @@
 pub enum AngleMode {
     Degrees,
     Radians,
+    // 400 to a full circle
+    Gradians,
+    // 1 to a full circle
+    Turns,
 }
@@ -0,0 +1,98 @@
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::One;
+
+use crate::stack::item::StackItem;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerMode {
+    /// number of bits integers wrap at, or `None` for unbounded integers
+    pub word_size: Option<u32>,
+    pub signed: bool,
+}
+
+impl IntegerMode {
+    pub fn new() -> Self {
+        Self {
+            word_size: None,
+            signed: true,
+        }
+    }
+
+    /// Wraps integer results to the current word size using two's complement. Rational
+    /// results are truncated to integers shown in `display_base`, as division is integer
+    /// division in this mode.
+    pub fn wrap(&self, item: StackItem, display_base: u8) -> StackItem {
+        let Some(word_size) = self.word_size else {
+            return item;
+        };
+        match item {
+            StackItem::Integer(v, display_base) => {
+                StackItem::Integer(self.wrap_integer(&v, word_size), display_base)
+            }
+            StackItem::Rational(v) => {
+                StackItem::Integer(self.wrap_integer(&v.to_integer(), word_size), display_base)
+            }
+            _ => item,
+        }
+    }
+
+    /// Returns the two's complement bit pattern of a value in the current word size.
+    pub fn unsigned_value(&self, v: &BigInt) -> Option<BigInt> {
+        self.word_size
+            .map(|word_size| v.mod_floor(&(BigInt::one() << word_size)))
+    }
+
+    fn wrap_integer(&self, v: &BigInt, word_size: u32) -> BigInt {
+        let modulus = BigInt::one() << word_size;
+        let v = v.mod_floor(&modulus);
+        if self.signed && v.bit(word_size as u64 - 1) {
+            v - modulus
+        } else {
+            v
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+
+    use crate::stack::item::StackItem;
+
+    use super::IntegerMode;
+
+    #[test]
+    fn test_wrap() {
+        let mut mode = IntegerMode {
+            word_size: Some(8),
+            signed: false,
+        };
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0xff), 16),
+            mode.wrap(StackItem::Integer(BigInt::from(-1), 16), 10)
+        );
+        assert_eq!(
+            StackItem::Integer(BigInt::from(0x34), 16),
+            mode.wrap(StackItem::Integer(BigInt::from(0x1234), 16), 10)
+        );
+
+        mode.signed = true;
+        assert_eq!(
+            StackItem::Integer(BigInt::from(-128), 10),
+            mode.wrap(StackItem::Integer(BigInt::from(128), 10), 10)
+        );
+        assert_eq!(
+            StackItem::Integer(BigInt::from(127), 10),
+            mode.wrap(StackItem::Integer(BigInt::from(127), 10), 10)
+        );
+        assert_eq!(
+            StackItem::Integer(BigInt::from(5), 16),
+            mode.wrap(
+                StackItem::Rational(BigRational::new(16.into(), 3.into())),
+                16
+            )
+        );
+    }
+}
@@ -3,10 +3,17 @@ use std::{collections::HashMap, sync::Arc};
 
 use angle_mode::AngleMode;
 use anyhow::{anyhow, Error, Result};
+use complex_display::ComplexDisplay;
 use constant::Constant;
 use input::Input;
+use integer_mode::IntegerMode;
 use num_format::SystemLocale;
+use number_mode::NumberMode;
+use percent_key::PercentKey;
+use rand::{rngs::StdRng, SeedableRng};
 use ratatui::widgets::ListState;
+use rational_display::RationalDisplay;
+use tvm::Tvm;
 
 use crate::{
     expr::run::run_expression,
@@ -17,14 +24,29 @@ use crate::{
 };
 
 pub mod angle_mode;
+pub mod complex_display;
 pub mod constant;
 pub mod input;
+pub mod integer_mode;
+pub mod number_mode;
+pub mod percent_key;
+pub mod rational_display;
+pub mod tvm;
 
 pub struct RpnState {
     pub locale: SystemLocale,
     pub precision: usize,
     pub scientific_notation_limit: f64,
     pub angle_mode: AngleMode,
+    pub rational_display: RationalDisplay,
+    pub complex_display: ComplexDisplay,
+    pub integer_mode: IntegerMode,
+    // radixes shown in the info pane, in order
+    pub info_bases: Vec<u8>,
+    pub number_mode: NumberMode,
+    pub percent_key: PercentKey,
+    pub tvm: Tvm,
+    pub rng: StdRng,
     pub stack: Stack,
     pub functions: HashMap<String, Arc<Box<dyn Func>>>,
     pub constants: HashMap<String, Arc<Constant>>,
@@ -42,6 +64,14 @@ impl RpnState {
             locale: SystemLocale::default()?,
             stack: Stack::new(),
             angle_mode: AngleMode::Degrees,
+            rational_display: RationalDisplay::Decimal,
+            complex_display: ComplexDisplay::Rectangular,
+            integer_mode: IntegerMode::new(),
+            info_bases: vec![16, 10, 8, 2],
+            number_mode: NumberMode::Float,
+            percent_key: PercentKey::Modulus,
+            tvm: Tvm::new(),
+            rng: StdRng::from_os_rng(),
             functions: HashMap::new(),
             constants: HashMap::new(),
             variables: HashMap::default(),
@@ -99,6 +129,40 @@ impl RpnState {
         }
     }
 
+    /// Applies the integer and number modes to a value about to be pushed on the stack.
+    pub fn apply_modes(&self, stack_item: StackItem) -> StackItem {
+        self.apply_modes_in_base(stack_item, 10)
+    }
+
+    /// Like `apply_modes`, showing rationals truncated in programmer mode in
+    /// `display_base`, usually that of the operands.
+    pub fn apply_modes_in_base(&self, stack_item: StackItem, display_base: u8) -> StackItem {
+        let apply = |v| self.apply_modes_in_base(v, display_base);
+        match stack_item {
+            StackItem::Vector(v) => return StackItem::Vector(v.into_iter().map(apply).collect()),
+            StackItem::List(v) => return StackItem::List(v.into_iter().map(apply).collect()),
+            StackItem::Matrix(m) => {
+                return StackItem::Matrix(
+                    m.into_iter()
+                        .map(|row| row.into_iter().map(&apply).collect())
+                        .collect(),
+                )
+            }
+            _ => {}
+        }
+        let stack_item = self.integer_mode.wrap(stack_item, display_base);
+        match (self.number_mode, &stack_item) {
+            (NumberMode::Float, StackItem::Decimal(_)) => {
+                stack_item.to_float().unwrap_or(stack_item)
+            }
+            (NumberMode::Decimal, StackItem::Rational(_) | StackItem::Decimal(_)) => stack_item
+                .to_decimal(self.precision)
+                .map(StackItem::Decimal)
+                .unwrap_or(stack_item),
+            _ => stack_item,
+        }
+    }
+
     pub fn register_function(&mut self, func: Box<dyn Func>) {
         let func = Arc::new(func);
         self.functions.insert(func.name().to_string(), func.clone());
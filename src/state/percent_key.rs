@@ -0,0 +1,6 @@
+/// What the `%` key does when the input line is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentKey {
+    Modulus,
+    Percent,
+}
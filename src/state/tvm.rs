@@ -0,0 +1,151 @@
+use anyhow::{anyhow, Result};
+
+/// Time value of money registers. Money received is positive and money paid out is
+/// negative, so a loan has a positive present value and negative payments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tvm {
+    /// number of payment periods
+    pub n: f64,
+    /// annual interest rate as a percentage, spread over the payments in a year
+    pub i: f64,
+    pub pv: f64,
+    pub pmt: f64,
+    pub fv: f64,
+    pub payments_per_year: f64,
+    /// payments are made at the start of each period rather than the end
+    pub begin: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TvmRegister {
+    N,
+    I,
+    Pv,
+    Pmt,
+    Fv,
+}
+
+const SOLVE_MAX_ITERATIONS: usize = 200;
+
+impl Tvm {
+    pub fn new() -> Self {
+        Self {
+            n: 0.0,
+            i: 0.0,
+            pv: 0.0,
+            pmt: 0.0,
+            fv: 0.0,
+            payments_per_year: 1.0,
+            begin: false,
+        }
+    }
+
+    pub fn set(&mut self, register: TvmRegister, v: f64) {
+        match register {
+            TvmRegister::N => self.n = v,
+            TvmRegister::I => self.i = v,
+            TvmRegister::Pv => self.pv = v,
+            TvmRegister::Pmt => self.pmt = v,
+            TvmRegister::Fv => self.fv = v,
+        }
+    }
+
+    /// Solves for one register from the other four, which satisfy
+    /// `pv (1 + r)^n + pmt (1 + r b) ((1 + r)^n - 1) / r + fv = 0` where `r` is the
+    /// periodic rate and `b` is 1 when payments are at the start of each period.
+    pub fn solve(&self, register: TvmRegister) -> Result<f64> {
+        let r = self.periodic_rate();
+        let growth = (1.0 + r).powf(self.n);
+        let annuity = annuity_factor(r, self.n) * self.timing_factor(r);
+        let v = match register {
+            TvmRegister::Fv => -(self.pv * growth + self.pmt * annuity),
+            TvmRegister::Pv => -(self.fv + self.pmt * annuity) / growth,
+            TvmRegister::Pmt => {
+                if annuity == 0.0 {
+                    return Err(anyhow!("cannot solve for the payment with no periods"));
+                }
+                -(self.pv * growth + self.fv) / annuity
+            }
+            TvmRegister::N => self.solve_n(r)?,
+            TvmRegister::I => self.solve_rate()? * 100.0 * self.payments_per_year,
+        };
+        if !v.is_finite() {
+            return Err(anyhow!("no solution for {}", register_name(register)));
+        }
+        Ok(v)
+    }
+
+    /// Balance still owed after `periods` payments, with the same sign as the present value.
+    pub fn balance(&self, periods: f64) -> f64 {
+        let r = self.periodic_rate();
+        self.pv * (1.0 + r).powf(periods)
+            + self.pmt * self.timing_factor(r) * annuity_factor(r, periods)
+    }
+
+    fn periodic_rate(&self) -> f64 {
+        self.i / 100.0 / self.payments_per_year
+    }
+
+    fn timing_factor(&self, r: f64) -> f64 {
+        if self.begin {
+            1.0 + r
+        } else {
+            1.0
+        }
+    }
+
+    fn solve_n(&self, r: f64) -> Result<f64> {
+        let pmt = self.pmt * self.timing_factor(r);
+        if r == 0.0 {
+            return Ok(-(self.pv + self.fv) / pmt);
+        }
+        let ratio = (pmt - self.fv * r) / (pmt + self.pv * r);
+        if ratio <= 0.0 {
+            return Err(anyhow!("no solution for n, check the signs of the values"));
+        }
+        Ok(ratio.ln() / (1.0 + r).ln())
+    }
+
+    /// Finds the periodic rate with Newton's method, there is no closed form.
+    fn solve_rate(&self) -> Result<f64> {
+        let residual = |r: f64| {
+            self.pv * (1.0 + r).powf(self.n)
+                + self.pmt * self.timing_factor(r) * annuity_factor(r, self.n)
+                + self.fv
+        };
+        let mut r: f64 = 0.01;
+        for _ in 0..SOLVE_MAX_ITERATIONS {
+            let h = 1e-7 * r.abs().max(1e-4);
+            let slope = (residual(r + h) - residual(r - h)) / (2.0 * h);
+            if slope == 0.0 || !slope.is_finite() {
+                break;
+            }
+            // rates at or below -100% are meaningless, so step at most halfway there
+            let next = (r - residual(r) / slope).max((r - 1.0) / 2.0);
+            if (next - r).abs() <= 1e-15 * next.abs().max(1.0) {
+                return Ok(next);
+            }
+            r = next;
+        }
+        Err(anyhow!("no solution for i, check the signs of the values"))
+    }
+}
+
+/// Value after `n` periods of paying 1 each period, `((1 + r)^n - 1) / r`.
+fn annuity_factor(r: f64, n: f64) -> f64 {
+    if r.abs() < 1e-12 {
+        n
+    } else {
+        (n * r.ln_1p()).exp_m1() / r
+    }
+}
+
+pub fn register_name(register: TvmRegister) -> &'static str {
+    match register {
+        TvmRegister::N => "tvmn",
+        TvmRegister::I => "tvmi",
+        TvmRegister::Pv => "pv",
+        TvmRegister::Pmt => "pmt",
+        TvmRegister::Fv => "fv",
+    }
+}
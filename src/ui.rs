@@ -12,8 +12,10 @@ use ratatui::{layout::Layout, widgets::Block, Frame};
 use crate::expr::run::run_expression;
 use crate::expr::ExprError;
 use crate::func::Func;
+use crate::stack::float_bits::{exact_decimal, ulp_gaps, FloatParts};
 use crate::stack::item::{StackItem, StackItemToStringOpts};
 use crate::state::angle_mode::AngleMode;
+use crate::state::rational_display::RationalDisplay;
 use crate::state::RpnState;
 
 pub fn draw(frame: &mut Frame, state: &mut RpnState) {
@@ -43,14 +45,17 @@ pub fn draw(frame: &mut Frame, state: &mut RpnState) {
                 &StackItemToStringOpts {
                     base: None,
                     precision: None,
+                    rational_display: None,
                     left_pad_with_zeros: true,
                     include_base_prefix: true,
                 },
                 state,
             );
             // add a space to the right so that when a user double clicks
-            // a value it doesn't select the right border as well
-            let text = Text::from(format!("{v} ")).alignment(Alignment::Right);
+            // a value it doesn't select the right border as well, matrices
+            // span several lines so every line needs one
+            let v = v.lines().map(|line| format!("{line} ")).collect::<Vec<_>>();
+            let text = Text::from(v.join("\n")).alignment(Alignment::Right);
             ListItem::new(text)
         })
         .collect();
@@ -142,74 +147,134 @@ fn get_info_text(state: &mut RpnState, width: usize) -> String {
     }
 
     if let Some(n) = n {
-        let hex = if n.is_integer() {
-            n.to_string_opts(
-                &StackItemToStringOpts {
-                    base: Some(16),
-                    precision: None,
-                    left_pad_with_zeros: true,
-                    include_base_prefix: false,
-                },
-                state,
-            )
-        } else {
-            "".to_string()
+        let dec_opts = StackItemToStringOpts {
+            base: Some(10),
+            precision: None,
+            rational_display: Some(RationalDisplay::Decimal),
+            left_pad_with_zeros: false,
+            include_base_prefix: false,
         };
-
         let dec = match n {
-            StackItem::Number(_, _) => n.to_string_opts(
-                &StackItemToStringOpts {
-                    base: Some(10),
-                    precision: None,
-                    left_pad_with_zeros: false,
-                    include_base_prefix: false,
-                },
-                state,
-            ),
+            StackItem::Number(_, _)
+            | StackItem::Integer(_, _)
+            | StackItem::Rational(_)
+            | StackItem::Complex(_)
+            | StackItem::Decimal(_)
+            | StackItem::Quantity(_, _) => n.to_string_opts(&dec_opts, state),
+            // shown as decimal degrees or hours
+            StackItem::Dms(v) | StackItem::Hms(v) => {
+                StackItem::Number(v, 10).to_string_opts(&dec_opts, state)
+            }
             StackItem::String(_) => "".to_string(),
+            StackItem::DateTime(_) => "".to_string(),
+            StackItem::Duration(_) => "".to_string(),
+            StackItem::Vector(_) | StackItem::Matrix(_) | StackItem::List(_) => "".to_string(),
             StackItem::Undefined => "".to_string(),
         };
 
-        let oct = if n.is_integer() {
-            n.to_string_opts(
+        let frac = match n {
+            StackItem::Rational(_) => n.to_string_opts(
                 &StackItemToStringOpts {
-                    base: Some(8),
+                    base: Some(10),
                     precision: None,
-                    left_pad_with_zeros: true,
+                    rational_display: Some(RationalDisplay::Fraction),
+                    left_pad_with_zeros: false,
                     include_base_prefix: false,
                 },
                 state,
-            )
-        } else {
-            "".to_string()
+            ),
+            _ => "".to_string(),
         };
 
-        let bin = if n.is_integer() {
-            n.to_string_opts(
-                &StackItemToStringOpts {
-                    base: Some(2),
-                    precision: None,
-                    left_pad_with_zeros: true,
-                    include_base_prefix: false,
-                },
-                state,
-            )
-        } else {
-            "".to_string()
-        };
+        let mut rows = vec![];
+        for base in &state.info_bases {
+            if *base == 10 {
+                rows.push(format!("Dec: {dec}"));
+                if !frac.is_empty() {
+                    rows.push(format!("Frac: {frac}"));
+                }
+                continue;
+            }
+            let value = if n.is_integer() {
+                n.to_string_opts(
+                    &StackItemToStringOpts {
+                        base: Some(*base),
+                        precision: None,
+                        rational_display: None,
+                        left_pad_with_zeros: true,
+                        include_base_prefix: false,
+                    },
+                    state,
+                )
+            } else {
+                "".to_string()
+            };
+            rows.push(format!("{}: {value}", base_label(*base)));
+        }
 
-        format!("Hex: {hex}\nDec: {dec}\nOct: {oct}\nBin: {bin}\n{help}")
+        let float = get_float_info(&n);
+
+        format!("{}\n{float}\n{help}", rows.join("\n"))
     } else {
-        format!("Hex:\nDec:\nOct:\nBin:\n{help}")
+        let rows = state
+            .info_bases
+            .iter()
+            .map(|base| format!("{}:", base_label(*base)))
+            .collect::<Vec<_>>();
+        format!("{}\nFloat:\nExact:\nULP:\n{help}", rows.join("\n"))
     }
 }
 
+fn base_label(base: u8) -> String {
+    match base {
+        2 => "Bin".to_string(),
+        8 => "Oct".to_string(),
+        10 => "Dec".to_string(),
+        16 => "Hex".to_string(),
+        _ => format!("Base {base}"),
+    }
+}
+
+/// The top value as the nearest IEEE-754 double, split into its fields.
+fn get_float_info(n: &StackItem) -> String {
+    let v = match n {
+        StackItem::Complex(_) => None,
+        _ => n.to_f64(),
+    };
+    let Some(v) = v else {
+        return "Float:\nExact:\nULP:".to_string();
+    };
+
+    let parts = FloatParts::new(v);
+    let float = format!(
+        "sign {}, exponent {} ({}), mantissa 0x{:013x}",
+        parts.sign,
+        parts.exponent,
+        parts.describe_exponent(),
+        parts.mantissa
+    );
+    let exact = exact_decimal(v).unwrap_or_default();
+    let ulp = ulp_gaps(v)
+        .map(|(below, above)| format!("-{below:e} / +{above:e}"))
+        .unwrap_or_default();
+    format!("Float: {float}\nExact: {exact}\nULP: {ulp}")
+}
+
 fn get_status_right_text(state: &RpnState) -> String {
     let angle_mode = match state.angle_mode {
         AngleMode::Degrees => "DEG",
         AngleMode::Radians => "RAD",
+        AngleMode::Gradians => "GRAD",
+        AngleMode::Turns => "TURN",
     };
-    format!(" {angle_mode} ")
+    let payment_timing = if state.tvm.begin { " BEGIN" } else { "" };
+    match state.integer_mode.word_size {
+        Some(word_size) => {
+            let signedness = if state.integer_mode.signed { "i" } else { "u" };
+            format!(" {angle_mode}{payment_timing} {signedness}{word_size} ")
+        }
+        None => format!(" {angle_mode}{payment_timing} "),
+    }
 }
 
 fn get_status_left_text(state: &RpnState, width: usize) -> String {
@@ -8,6 +8,7 @@ pub mod binary;
 pub mod multi;
 pub mod pop;
 pub mod push;
+pub mod replace;
 pub mod unary;
 
 pub trait UndoEvent: Debug + Send + Sync {
@@ -40,7 +40,7 @@ mod test {
         let mut state = RpnState::new().unwrap();
         state.push_str("2 + 3 * 4").unwrap();
         assert_eq!(
-            &StackItem::Number(2.0 + 3.0 * 4.0, 10),
+            &StackItem::Integer((2 + 3 * 4).into(), 10),
             state.stack.peek(0).unwrap()
         );
 
@@ -51,7 +51,7 @@ mod test {
         // redo
         state.redo().unwrap();
         assert_eq!(
-            &StackItem::Number(2.0 + 3.0 * 4.0, 10),
+            &StackItem::Integer((2 + 3 * 4).into(), 10),
             state.stack.peek(0).unwrap()
         );
     }
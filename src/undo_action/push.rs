@@ -57,6 +57,6 @@ mod test {
         state.redo().unwrap();
         assert_eq!(state.stack.len(), 1);
         let answer = state.stack.peek(0).unwrap();
-        assert_eq!(*answer, StackItem::Number(1.0, 10));
+        assert_eq!(*answer, StackItem::Integer(1.into(), 10));
     }
 }
@@ -0,0 +1,56 @@
+use anyhow::{anyhow, Result};
+
+use crate::{stack::item::StackItem, state::RpnState};
+
+use super::UndoEvent;
+
+/// Replaces items on the top of the stack with other items, both kept in
+/// stack order (bottom first).
+#[derive(Debug)]
+pub struct ReplaceUndoEvent {
+    removed: Vec<StackItem>,
+    added: Vec<StackItem>,
+}
+
+impl ReplaceUndoEvent {
+    pub fn new(removed: Vec<StackItem>, added: Vec<StackItem>) -> Self {
+        Self { removed, added }
+    }
+}
+
+impl UndoEvent for ReplaceUndoEvent {
+    fn undo(&self, state: &mut RpnState) -> Result<()> {
+        swap_top(state, &self.added, &self.removed, "undo")
+    }
+
+    fn redo(&self, state: &mut RpnState) -> Result<()> {
+        swap_top(state, &self.removed, &self.added, "redo")
+    }
+}
+
+fn swap_top(
+    state: &mut RpnState,
+    expected: &[StackItem],
+    replacement: &[StackItem],
+    action: &str,
+) -> Result<()> {
+    if state.stack.len() < expected.len() {
+        return Err(anyhow!(
+            "unexpected state for {action}, expected at least {} items on the stack",
+            expected.len()
+        ));
+    }
+    for (i, item) in expected.iter().rev().enumerate() {
+        if state.stack.peek(i) != Some(item) {
+            return Err(anyhow!(
+                "unexpected state for {action}, expected item {i} on the stack to be {item}"
+            ));
+        }
+    }
+
+    state.stack.pop_n(expected.len())?;
+    for item in replacement {
+        state.stack.push(item.clone());
+    }
+    Ok(())
+}